@@ -0,0 +1,50 @@
+use std::fmt;
+
+use crate::{eval, Backend, Bump, Diagnostic, JobSettings, ParseResult, Parser};
+
+/// The output of a successful [`convert`], along with any recoverable
+/// diagnostics reported while parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conversion<T> {
+  pub output: T,
+  pub warnings: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConvertError<E> {
+  /// the document could not be parsed, or `strict` turned a warning fatal
+  Parse(Vec<Diagnostic>),
+  Backend(E),
+}
+
+/// Parses `src` and converts the document with `backend`, after letting the
+/// backend set its job attributes, e.g. `backend` and `basebackend`.
+pub fn convert<B: Backend>(
+  src: &str,
+  mut settings: JobSettings,
+  backend: B,
+) -> Result<Conversion<B::Output>, ConvertError<B::Error>> {
+  B::set_job_attrs(&mut settings.job_attrs);
+  let bump = &Bump::with_capacity(src.len() * 2);
+  let parser = Parser::builder(bump)
+    .source_str(src)
+    .settings(settings)
+    .build();
+  let ParseResult { document, warnings, .. } = parser.parse().map_err(ConvertError::Parse)?;
+  let output = eval(&document, backend).map_err(ConvertError::Backend)?;
+  Ok(Conversion { output, warnings })
+}
+
+impl<E: fmt::Display> fmt::Display for ConvertError<E> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ConvertError::Parse(diagnostics) => {
+        let messages = diagnostics.iter().map(Diagnostic::plain_text);
+        write!(f, "{}", messages.collect::<Vec<_>>().join("\n"))
+      }
+      ConvertError::Backend(err) => write!(f, "{err}"),
+    }
+  }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ConvertError<E> {}
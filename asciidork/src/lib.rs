@@ -0,0 +1,56 @@
+//! Parse Asciidoc and convert it with one of the bundled backends.
+//!
+//! This crate is the supported way to embed asciidork. Everything exported
+//! here follows semver, while the `asciidork-*` crates behind it are
+//! implementation details whose module paths may change in any release.
+//!
+//! For a one-off conversion, [`convert`] parses the source and runs a backend
+//! over it. To configure includes, timestamps or diagnostics as they happen,
+//! build a [`Parser`] with [`Parser::builder`] and hand the parsed document
+//! to [`eval`].
+
+mod convert;
+
+pub use convert::{convert, Conversion, ConvertError};
+
+pub use asciidork_ast as ast;
+pub use asciidork_backend::{AdmonitionKind, Backend, NestedContext};
+pub use asciidork_core::{
+  AttrValue, ConversionProfile, DocType, DocumentMeta, JobAttr, JobAttrs, JobSettings, SafeMode,
+  SectionLevelPolicy,
+};
+pub use asciidork_eval::{eval, visit};
+pub use asciidork_parser::includes::{
+  ConstResolver, ErrorResolver, IncludeBuffer, IncludeResolver, IncludeTarget, MemoryResolver,
+  ResolveError,
+};
+pub use asciidork_parser::parser::{ParseResult, SourceFile};
+pub use asciidork_parser::{
+  Diagnostic, DiagnosticColor, DiagnosticSink, Parser, ParserBuilder, Severity,
+};
+pub use bumpalo::Bump;
+
+/// The backends bundled with asciidork, each behind a feature of its name.
+pub mod backends {
+  #[cfg(feature = "html")]
+  pub mod html {
+    #[cfg(feature = "minify")]
+    pub use asciidork_dr_html_backend::minify;
+    pub use asciidork_dr_html_backend::{AsciidoctorHtml, VERSION};
+  }
+
+  #[cfg(feature = "stats")]
+  pub mod stats {
+    pub use asciidork_stats_backend::{
+      DocumentStats, ImageStats, LinkStats, SectionStats, XrefStats, VERSION,
+    };
+  }
+}
+
+pub mod prelude {
+  #[cfg(feature = "html")]
+  pub use crate::backends::html::AsciidoctorHtml;
+  pub use crate::{
+    convert, Backend, Bump, Diagnostic, JobSettings, Parser, SafeMode, Severity, SourceFile,
+  };
+}
@@ -0,0 +1,59 @@
+use asciidork::prelude::*;
+use asciidork::{ConvertError, JobAttr};
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn converts_to_html() {
+  let conversion = convert(
+    "Hello, _world_!",
+    JobSettings::embedded(),
+    AsciidoctorHtml::new(),
+  );
+  let conversion = conversion.unwrap();
+  assert_eq!(
+    conversion.output,
+    r#"<div class="paragraph"><p>Hello, <em>world</em>!</p></div>"#
+  );
+  assert!(conversion.warnings.is_empty());
+}
+
+#[test]
+fn sets_backend_job_attrs() {
+  let mut settings = JobSettings::embedded();
+  settings
+    .job_attrs
+    .insert_unchecked("greeting", JobAttr::readonly("hi"));
+  let src = "{greeting} from {backend}";
+  let conversion = convert(src, settings, AsciidoctorHtml::new()).unwrap();
+  assert_eq!(
+    conversion.output,
+    r#"<div class="paragraph"><p>hi from html5</p></div>"#
+  );
+}
+
+#[test]
+fn reports_parse_errors() {
+  let src = indoc! {"
+    ----
+    never closed
+  "};
+  let Err(ConvertError::Parse(diagnostics)) =
+    convert(src, JobSettings::embedded(), AsciidoctorHtml::new())
+  else {
+    panic!("expected a parse error");
+  };
+  assert_eq!(diagnostics.len(), 1);
+  assert_eq!(diagnostics[0].severity, Severity::Error);
+  assert_eq!(diagnostics[0].message, "This delimiter was never closed");
+}
+
+#[test]
+fn warnings_with_lenient_settings() {
+  let settings = JobSettings {
+    strict: false,
+    ..JobSettings::embedded()
+  };
+  let conversion = convert("See <<nope>>.", settings, AsciidoctorHtml::new()).unwrap();
+  assert_eq!(conversion.warnings.len(), 1);
+}
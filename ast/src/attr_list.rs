@@ -21,6 +21,8 @@ pub trait AttrData {
   fn has_role(&self, role: &str) -> bool;
   fn named(&self, key: &str) -> Option<&str>;
   fn named_with_loc(&self, key: &str) -> Option<(&str, SourceLocation)>;
+  /// every named attribute with a plain text value, in source order
+  fn named_entries(&self) -> impl Iterator<Item = (&str, &str)>;
   fn ordered_list_custom_number_style(&self) -> Option<&'static str>;
   fn unordered_list_custom_marker_style(&self) -> Option<&'static str>;
   fn block_style(&self) -> Option<BlockContext>;
@@ -87,6 +89,13 @@ impl AttrData for AttrList<'_> {
       .and_then(|s| if s.is_empty() { Some("") } else { s.single_text() })
   }
 
+  fn named_entries(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.named.iter().filter_map(|(key, value)| {
+      let text = if value.is_empty() { Some("") } else { value.single_text() };
+      text.map(|text| (key.src.as_str(), text))
+    })
+  }
+
   fn named_with_loc(&self, key: &str) -> Option<(&str, SourceLocation)> {
     self.named.get_with_src(key).and_then(|(src, nodes)| {
       if nodes.is_empty() {
@@ -134,6 +143,8 @@ impl AttrData for AttrList<'_> {
       Some("lowerroman") => Some("lowerroman"),
       Some("upperroman") => Some("upperroman"),
       Some("lowergreek") => Some("lowergreek"), // html only
+      Some("unstyled") => Some("unstyled"),
+      Some("no-bullet") => Some("no-bullet"),
       _ => None,
     }
   }
@@ -188,6 +199,10 @@ impl<'arena> Named<'arena> {
       .find_map(|(k, v)| if k == key { Some(v) } else { None })
   }
 
+  pub fn iter(&self) -> impl Iterator<Item = &(SourceString<'arena>, InlineNodes<'arena>)> {
+    self.0.iter()
+  }
+
   pub fn get_with_src(&self, key: &str) -> Option<(SourceString<'arena>, &InlineNodes<'arena>)> {
     self
       .0
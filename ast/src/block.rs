@@ -14,6 +14,7 @@ impl Block<'_> {
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
 pub enum BlockContent<'arena> {
   Compound(BumpVec<'arena, Block<'arena>>),
   Simple(InlineNodes<'arena>),
@@ -50,6 +51,7 @@ pub enum EmptyMetadata<'arena> {
 }
 
 #[derive(Copy, Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
 pub enum BlockContext {
   AdmonitionCaution,
   AdmonitionImportant,
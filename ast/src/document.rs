@@ -13,6 +13,14 @@ pub struct Document<'arena> {
   pub toc: Option<TableOfContents<'arena>>,
   pub anchors: Rc<RefCell<HashMap<BumpString<'arena>, Anchor<'arena>>>>,
   pub source_filenames: Vec<String>,
+  /// only populated when parsing in full fidelity mode
+  pub trivia: Option<BumpVec<'arena, Trivia<'arena>>>,
+  /// every attribute entry, in source order, including duplicates and
+  /// unsets, unlike `meta`, which only holds the effective values
+  pub attr_decls: Vec<AttrDecl>,
+  /// the text of the primary source, then each include in the order it was
+  /// resolved, indexed by `SourceLocation::include_depth`
+  pub sources: Vec<&'arena str>,
 }
 
 impl<'arena> Document<'arena> {
@@ -29,8 +37,38 @@ impl<'arena> Document<'arena> {
       anchors: Rc::new(RefCell::new(HashMap::new())),
       meta: DocumentMeta::default(),
       source_filenames: Vec::new(),
+      trivia: None,
+      attr_decls: Vec::new(),
+      sources: Vec::new(),
     }
   }
+
+  /// The document title rendered as plain text, e.g. for a window title or
+  /// search index. Reflects a `doctitle` set by attribute or job settings.
+  pub fn plain_title(&self) -> Option<String> {
+    self.title.as_ref().map(|title| title.main.to_plain_text())
+  }
+
+  /// The raw source text covered by `loc`, from whichever file it was
+  /// lexed, or an empty string if `loc` lies outside of the known sources.
+  pub fn source_of(&self, loc: SourceLocation) -> &'arena str {
+    self
+      .sources
+      .get(loc.include_depth as usize)
+      .and_then(|src| src.get(loc.start as usize..loc.end as usize))
+      .unwrap_or("")
+  }
+}
+
+/// A single attribute entry, like `:foo: bar` or `:!foo:`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AttrDecl {
+  pub name: String,
+  /// the value after attribute references were replaced, `false` if unset
+  pub value: AttrValue,
+  /// the first line of the entry, a wrapped value may continue past it
+  pub loc: SourceLocation,
+  pub in_header: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -14,6 +14,7 @@ impl<'arena> InlineNode<'arena> {
 
 // https://docs.asciidoctor.org/asciidoc/latest/key-concepts/#elements
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
 pub enum Inline<'arena> {
   Bold(InlineNodes<'arena>),
   CurlyQuote(CurlyKind),
@@ -10,52 +10,6 @@ impl<'arena> InlineNodes<'arena> {
     Self(BumpVec::new_in(bump))
   }
 
-  pub fn plain_text(&self) -> Vec<&str> {
-    let mut text = Vec::new();
-    self.iter().for_each(|node| match &node.content {
-      Inline::Bold(nodes) => text.extend(nodes.plain_text()),
-      Inline::CurlyQuote(RightDouble) => text.push("”"),
-      Inline::CurlyQuote(LeftDouble) => text.push("“"),
-      Inline::CurlyQuote(LeftSingle) => text.push("‘"),
-      Inline::CurlyQuote(RightSingle) => text.push("’"),
-      Inline::CurlyQuote(LegacyImplicitApostrophe) => text.push("'"),
-      Inline::Discarded => {}
-      Inline::Highlight(nodes) => text.extend(nodes.plain_text()),
-      Inline::Macro(_) => {}
-      Inline::Italic(nodes) => text.extend(nodes.plain_text()),
-      Inline::InlinePassthru(nodes) => text.extend(nodes.plain_text()),
-      Inline::Newline => text.push(" "),
-      Inline::InlineAnchor(_) => {}
-      Inline::BiblioAnchor(..) => {}
-      Inline::LineBreak => {}
-      Inline::LineComment(_) => {}
-      Inline::CalloutNum(_) => {}
-      Inline::LitMono(string) => text.push(string),
-      Inline::Mono(nodes) => text.extend(nodes.plain_text()),
-      Inline::MultiCharWhitespace(_) => text.push(" "),
-      Inline::Quote(_, nodes) => text.extend(nodes.plain_text()),
-      Inline::SpecialChar(SpecialCharKind::Ampersand) => text.push("&"),
-      Inline::SpecialChar(SpecialCharKind::LessThan) => text.push("<"),
-      Inline::SpecialChar(SpecialCharKind::GreaterThan) => text.push(">"),
-      Inline::Symbol(SymbolKind::Copyright) => text.push("(C)"),
-      Inline::Symbol(SymbolKind::Trademark) => text.push("(TM)"),
-      Inline::Symbol(SymbolKind::Registered) => text.push("(R)"),
-      Inline::Symbol(SymbolKind::EmDash) => text.push("—"),
-      Inline::Symbol(SymbolKind::SpacedEmDash(_)) => text.push(" — "),
-      Inline::Symbol(SymbolKind::Ellipsis) => text.push("..."),
-      Inline::Symbol(SymbolKind::SingleRightArrow) => text.push("->"),
-      Inline::Symbol(SymbolKind::DoubleRightArrow) => text.push("=>"),
-      Inline::Symbol(SymbolKind::SingleLeftArrow) => text.push("<-"),
-      Inline::Symbol(SymbolKind::DoubleLeftArrow) => text.push("<="),
-      Inline::Superscript(nodes) => text.extend(nodes.plain_text()),
-      Inline::Subscript(nodes) => text.extend(nodes.plain_text()),
-      Inline::Text(s) => text.push(s.as_str()),
-      Inline::TextSpan(_, nodes) => text.extend(nodes.plain_text()),
-      Inline::CalloutTuck(_) => {}
-    });
-    text
-  }
-
   pub fn last_loc(&self) -> Option<SourceLocation> {
     self.last().map(|node| node.loc)
   }
@@ -150,6 +104,6 @@ mod tests {
       node!(" "; 9..10),
       node!(Inline::Italic(just!("title", 12..18)), 11..19),
     ];
-    expect_eq!(heading.plain_text(), vec!["Document", " ", "title"]);
+    expect_eq!(heading.to_plain_text(), "Document title");
   }
 }
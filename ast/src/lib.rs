@@ -10,10 +10,13 @@ mod list;
 mod r#macro;
 mod multi_attr_list;
 mod node;
+mod plain_text;
 mod source_location;
 mod source_string;
+mod split;
 mod table;
 mod toc;
+mod trivia;
 
 pub use internal::types::*;
 
@@ -24,18 +27,19 @@ mod internal {
     pub use crate::chunk_meta::ChunkMeta;
     pub use crate::col_widths::*;
     pub use crate::doc_content::DocContent;
-    pub use crate::document::{DocTitle, Document};
+    pub use crate::document::{AttrDecl, DocTitle, Document};
     pub use crate::inline::{AdjacentNewline, CurlyKind::*, QuoteKind::*, SymbolKind};
     pub use crate::inline::{CurlyKind, Inline, InlineNode, QuoteKind, SpecialCharKind};
     pub use crate::inline_nodes::InlineNodes;
     pub use crate::list::*;
     pub use crate::multi_attr_list::{MultiAttrList, NoAttrs};
     pub use crate::node::{Anchor, Callout, Section};
-    pub use crate::r#macro::{Flow, MacroNode, UrlScheme, XrefKind};
+    pub use crate::r#macro::{Flow, MacroNode, StemNotation, UrlScheme, XrefKind};
     pub use crate::source_location::SourceLocation;
     pub use crate::source_string::SourceString;
     pub use crate::table::*;
     pub use crate::toc::*;
+    pub use crate::trivia::{Trivia, TriviaKind};
     pub use asciidork_core::{AttrValue, DocumentMeta, ReadAttr};
     pub use smallvec::SmallVec;
   }
@@ -55,16 +59,17 @@ pub mod prelude {
   pub use crate::chunk_meta::ChunkMeta;
   pub use crate::col_widths::*;
   pub use crate::doc_content::DocContent;
-  pub use crate::document::{DocTitle, Document};
+  pub use crate::document::{AttrDecl, DocTitle, Document};
   pub use crate::inline::{CurlyKind, Inline, InlineNode, QuoteKind, SpecialCharKind, SymbolKind};
   pub use crate::list::{ListItem, ListItemTypeMeta, ListMarker, ListVariant};
   pub use crate::multi_attr_list::{MultiAttrList, NoAttrs};
   pub use crate::node::{Anchor, Callout, Section};
-  pub use crate::r#macro::{UrlScheme, XrefKind};
+  pub use crate::r#macro::{StemNotation, UrlScheme, XrefKind};
   pub use crate::source_location::SourceLocation;
   pub use crate::source_string::SourceString;
   pub use crate::table::*;
   pub use crate::toc::*;
+  pub use crate::trivia::{Trivia, TriviaKind};
 }
 
 pub mod short {
@@ -31,6 +31,12 @@ pub enum MacroNode<'arena> {
     target: SourceString<'arena>,
     linktext: Option<InlineNodes<'arena>>,
     kind: XrefKind,
+    /// named attrs of an xref macro, e.g. `xref:a[text,role=ref]`
+    attrs: Option<AttrList<'arena>>,
+  },
+  Stem {
+    notation: StemNotation,
+    text: SourceString<'arena>,
   },
 }
 
@@ -55,3 +61,20 @@ pub enum Flow {
   Inline,
   Block,
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StemNotation {
+  Asciimath,
+  Latexmath,
+}
+
+impl StemNotation {
+  /// Resolve the notation for a `stem:[]` macro or `[stem]` block from the
+  /// value of the `stem` document attribute, defaulting to AsciiMath
+  pub fn from_stem_attr(value: Option<&str>) -> Self {
+    match value {
+      Some("latexmath" | "latex" | "tex") => StemNotation::Latexmath,
+      _ => StemNotation::Asciimath,
+    }
+  }
+}
@@ -60,6 +60,10 @@ impl AttrData for MultiAttrList<'_> {
     self.0.iter().find_map(|attr| attr.named_with_loc(key))
   }
 
+  fn named_entries(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.0.iter().flat_map(AttrList::named_entries)
+  }
+
   fn ordered_list_custom_number_style(&self) -> Option<&'static str> {
     self
       .0
@@ -138,6 +142,10 @@ impl AttrData for NoAttrs {
     None
   }
 
+  fn named_entries(&self) -> impl Iterator<Item = (&str, &str)> {
+    std::iter::empty()
+  }
+
   fn ordered_list_custom_number_style(&self) -> Option<&'static str> {
     None
   }
@@ -10,6 +10,8 @@ pub struct Anchor<'arena> {
   /// can be used to identify the source file in which the anchor was found
   pub source_idx: u16,
   pub is_biblio: bool,
+  /// the id of a named footnote, e.g. `footnote:disclaimer[text]`
+  pub is_footnote: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -0,0 +1,160 @@
+use crate::internal::*;
+
+impl InlineNode<'_> {
+  /// Renders the node as text only, for places where markup isn't allowed,
+  /// like an html `<title>`, an attribute value or image alt text. Unlike
+  /// [`InlineNodes::plain_text`], macros contribute the text a reader would
+  /// see, e.g. the label of a `btn:[Save]` or the items of a `menu:`.
+  pub fn to_plain_text(&self) -> String {
+    let mut text = String::new();
+    self.push_plain_text(&mut text);
+    text
+  }
+
+  fn push_plain_text(&self, text: &mut String) {
+    match &self.content {
+      Inline::Bold(nodes)
+      | Inline::Highlight(nodes)
+      | Inline::Italic(nodes)
+      | Inline::InlinePassthru(nodes)
+      | Inline::Mono(nodes)
+      | Inline::Quote(_, nodes)
+      | Inline::Superscript(nodes)
+      | Inline::Subscript(nodes)
+      | Inline::TextSpan(_, nodes) => nodes.push_plain_text(text),
+      Inline::CurlyQuote(RightDouble) => text.push('”'),
+      Inline::CurlyQuote(LeftDouble) => text.push('“'),
+      Inline::CurlyQuote(LeftSingle) => text.push('‘'),
+      Inline::CurlyQuote(RightSingle) => text.push('’'),
+      Inline::CurlyQuote(LegacyImplicitApostrophe) => text.push('\''),
+      Inline::Newline | Inline::MultiCharWhitespace(_) => text.push(' '),
+      Inline::LitMono(string) => text.push_str(string),
+      Inline::Text(string) => text.push_str(string),
+      Inline::SpecialChar(SpecialCharKind::Ampersand) => text.push('&'),
+      Inline::SpecialChar(SpecialCharKind::LessThan) => text.push('<'),
+      Inline::SpecialChar(SpecialCharKind::GreaterThan) => text.push('>'),
+      Inline::Symbol(SymbolKind::Copyright) => text.push('©'),
+      Inline::Symbol(SymbolKind::Trademark) => text.push('™'),
+      Inline::Symbol(SymbolKind::Registered) => text.push('®'),
+      Inline::Symbol(SymbolKind::EmDash) => text.push('—'),
+      Inline::Symbol(SymbolKind::SpacedEmDash(_)) => text.push_str(" — "),
+      Inline::Symbol(SymbolKind::Ellipsis) => text.push('…'),
+      Inline::Symbol(SymbolKind::SingleRightArrow) => text.push('→'),
+      Inline::Symbol(SymbolKind::DoubleRightArrow) => text.push('⇒'),
+      Inline::Symbol(SymbolKind::SingleLeftArrow) => text.push('←'),
+      Inline::Symbol(SymbolKind::DoubleLeftArrow) => text.push('⇐'),
+      Inline::Macro(macro_node) => macro_node.push_plain_text(text),
+      Inline::Discarded
+      | Inline::CalloutNum(_)
+      | Inline::CalloutTuck(_)
+      | Inline::InlineAnchor(_)
+      | Inline::BiblioAnchor(_)
+      | Inline::LineBreak
+      | Inline::LineComment(_) => {}
+    }
+  }
+}
+
+impl MacroNode<'_> {
+  fn push_plain_text(&self, text: &mut String) {
+    match self {
+      MacroNode::Button(label) => text.push_str(label),
+      MacroNode::Menu(items) => {
+        for (idx, item) in items.iter().enumerate() {
+          if idx > 0 {
+            text.push_str(" › ");
+          }
+          text.push_str(item);
+        }
+      }
+      MacroNode::Keyboard { keys, .. } => {
+        for (idx, key) in keys.iter().enumerate() {
+          if idx > 0 {
+            text.push('+');
+          }
+          text.push_str(key);
+        }
+      }
+      MacroNode::Link { target, attrs, .. } => {
+        match attrs.as_ref().and_then(|attrs| attrs.positional.first()) {
+          Some(Some(nodes)) => nodes.push_plain_text(text),
+          _ => text.push_str(target),
+        }
+      }
+      MacroNode::Xref { target, linktext, .. } => match linktext {
+        Some(nodes) => nodes.push_plain_text(text),
+        None => {
+          text.push('[');
+          text.push_str(target);
+          text.push(']');
+        }
+      },
+      MacroNode::Image { target, attrs, .. } => {
+        if let Some(alt) = attrs.named("alt").or_else(|| attrs.str_positional_at(0)) {
+          text.push_str(alt);
+        } else if let Some(Some(nodes)) = attrs.positional.first() {
+          nodes.push_plain_text(text);
+        } else {
+          text.push_str(&asciidork_core::file::stem(target).replace(['-', '_'], " "));
+        }
+      }
+      MacroNode::Icon { target, .. } => {
+        text.push('[');
+        text.push_str(target);
+        text.push(']');
+      }
+      MacroNode::Stem { text: stem, .. } => text.push_str(stem),
+      // the note belongs at the bottom of the page, not in the text
+      MacroNode::Footnote { .. } => {}
+    }
+  }
+}
+
+impl InlineNodes<'_> {
+  /// see [`InlineNode::to_plain_text`]
+  pub fn to_plain_text(&self) -> String {
+    let mut text = String::new();
+    self.push_plain_text(&mut text);
+    text
+  }
+
+  fn push_plain_text(&self, text: &mut String) {
+    self.iter().for_each(|node| node.push_plain_text(text));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_utils::*;
+
+  #[test]
+  fn test_to_plain_text() {
+    let title: InlineNodes = nodes![
+      node!("Press "; 0..6),
+      node!(
+        Inline::Macro(MacroNode::Button(src!("Save", 10..14))),
+        6..15
+      ),
+      node!(" or "; 15..19),
+      node!(
+        Inline::Macro(MacroNode::Menu(vecb![
+          src!("File", 24..28),
+          src!("Save", 30..34),
+        ])),
+        19..35,
+      ),
+      node!(Inline::Bold(just!("now", 36..39)), 35..40),
+      node!(Inline::Symbol(SymbolKind::Ellipsis), 40..43),
+      node!(
+        Inline::Macro(MacroNode::Footnote {
+          id: None,
+          text: Some(just!("note", 53..57))
+        }),
+        43..58,
+      ),
+    ];
+    expect_eq!(title.to_plain_text(), "Press Save or File › Savenow…");
+    expect_eq!(title[1].to_plain_text(), "Save");
+  }
+}
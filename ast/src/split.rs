@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::internal::*;
+
+impl<'arena> Document<'arena> {
+  /// Splits the document into independent sub-documents, one per section at
+  /// `level`, for chunked output. Sections above `level` get a chunk of their
+  /// own holding only the content before their first subsection, deeper
+  /// sections stay inside their parent's chunk. The header and preamble, if
+  /// any, make up the first chunk.
+  ///
+  /// Every chunk inherits the document attributes and the full anchor
+  /// table, so xrefs to other chunks still resolve their text. See
+  /// [`Document::anchor_chunks`] for finding the chunk an xref points into.
+  pub fn split_at_level(&self, level: u8) -> Vec<Document<'arena>> {
+    let (preamble, sections) = match &self.content {
+      DocContent::Blocks(_) => return vec![self.chunk(self.content.clone(), true)],
+      DocContent::Sectioned { preamble, sections } => (preamble, sections),
+    };
+    let mut chunks = Vec::new();
+    if preamble.is_some() || self.title.is_some() {
+      let blocks = preamble
+        .clone()
+        .unwrap_or_else(|| BumpVec::new_in(sections.bump()));
+      chunks.push(self.chunk(DocContent::Blocks(blocks), true));
+    }
+    for section in sections.iter() {
+      self.push_section_chunks(section, level, &mut chunks);
+    }
+    chunks
+  }
+
+  fn push_section_chunks(
+    &self,
+    section: &Section<'arena>,
+    level: u8,
+    chunks: &mut Vec<Document<'arena>>,
+  ) {
+    let bump = section.blocks.bump();
+    if section.level >= level {
+      let sections = bvec![in bump; section.clone()];
+      chunks.push(self.chunk(DocContent::Sectioned { preamble: None, sections }, false));
+      return;
+    }
+    let mut parent = Section {
+      meta: section.meta.clone(),
+      level: section.level,
+      id: section.id.clone(),
+      heading: section.heading.clone(),
+      blocks: BumpVec::new_in(bump),
+    };
+    let mut subsections = Vec::new();
+    for block in section.blocks.iter() {
+      match &block.content {
+        BlockContent::Section(subsection) => subsections.push(subsection),
+        _ => parent.blocks.push(block.clone()),
+      }
+    }
+    let sections = bvec![in bump; parent];
+    chunks.push(self.chunk(DocContent::Sectioned { preamble: None, sections }, false));
+    for subsection in subsections {
+      self.push_section_chunks(subsection, level, chunks);
+    }
+  }
+
+  /// Maps every id defined in `chunks` (from [`Document::split_at_level`])
+  /// to the index of the chunk defining it, e.g. to rewrite the href of an
+  /// xref into another chunk to `chunk-file.html#id`.
+  pub fn anchor_chunks(chunks: &[Document<'arena>]) -> HashMap<String, usize> {
+    let mut anchor_chunks = HashMap::new();
+    for (idx, chunk) in chunks.iter().enumerate() {
+      let mut ids = HashSet::new();
+      collect_content_ids(&chunk.content, &mut ids);
+      anchor_chunks.extend(ids.into_iter().map(|id| (id.to_string(), idx)));
+    }
+    anchor_chunks
+  }
+
+  fn chunk(&self, content: DocContent<'arena>, with_header: bool) -> Document<'arena> {
+    Document {
+      meta: self.meta.clone(),
+      title: if with_header { self.title.clone() } else { None },
+      subtitle: if with_header { self.subtitle.clone() } else { None },
+      content,
+      toc: None,
+      anchors: Rc::clone(&self.anchors),
+      source_filenames: self.source_filenames.clone(),
+      trivia: None,
+      attr_decls: self.attr_decls.clone(),
+      sources: self.sources.clone(),
+    }
+  }
+}
+
+fn collect_content_ids<'a>(content: &'a DocContent, ids: &mut HashSet<&'a str>) {
+  match content {
+    DocContent::Blocks(blocks) => collect_block_ids(blocks, ids),
+    DocContent::Sectioned { preamble, sections } => {
+      if let Some(preamble) = preamble {
+        collect_block_ids(preamble, ids);
+      }
+      sections.iter().for_each(|s| collect_section_ids(s, ids));
+    }
+  }
+}
+
+fn collect_section_ids<'a>(section: &'a Section, ids: &mut HashSet<&'a str>) {
+  if let Some(id) = &section.id {
+    ids.insert(id.as_str());
+  }
+  collect_inline_ids(&section.heading, ids);
+  collect_block_ids(&section.blocks, ids);
+}
+
+fn collect_block_ids<'a>(blocks: &'a [Block], ids: &mut HashSet<&'a str>) {
+  for block in blocks {
+    if let Some(id) = block.meta.attrs.id() {
+      ids.insert(id);
+    }
+    if let Some(title) = &block.meta.title {
+      collect_inline_ids(title, ids);
+    }
+    match &block.content {
+      BlockContent::Compound(blocks) => collect_block_ids(blocks, ids),
+      BlockContent::Simple(nodes) => collect_inline_ids(nodes, ids),
+      BlockContent::Section(section) => collect_section_ids(section, ids),
+      BlockContent::QuotedParagraph { quote, .. } => collect_inline_ids(quote, ids),
+      BlockContent::Empty(EmptyMetadata::DiscreteHeading { id, content, .. }) => {
+        if let Some(id) = id {
+          ids.insert(id.as_str());
+        }
+        collect_inline_ids(content, ids);
+      }
+      BlockContent::List { items, .. } => {
+        for item in items.iter() {
+          collect_inline_ids(&item.principle, ids);
+          collect_block_ids(&item.blocks, ids);
+          if let ListItemTypeMeta::DescList { description, extra_terms } = &item.type_meta {
+            if let Some(description) = description {
+              collect_block_ids(std::slice::from_ref(description), ids);
+            }
+            extra_terms
+              .iter()
+              .for_each(|(term, _)| collect_inline_ids(term, ids));
+          }
+        }
+      }
+      BlockContent::Table(table) => {
+        let rows = table
+          .header_row
+          .iter()
+          .chain(&table.rows)
+          .chain(&table.footer_row);
+        for cell in rows.flat_map(|row| row.cells.iter()) {
+          if let Some(id) = &cell.id {
+            ids.insert(id.as_str());
+          }
+          match &cell.content {
+            CellContent::AsciiDoc(document) => collect_content_ids(&document.content, ids),
+            CellContent::Literal(nodes) => collect_inline_ids(nodes, ids),
+            CellContent::Default(paragraphs)
+            | CellContent::Emphasis(paragraphs)
+            | CellContent::Header(paragraphs)
+            | CellContent::Monospace(paragraphs)
+            | CellContent::Strong(paragraphs) => {
+              paragraphs.iter().for_each(|p| collect_inline_ids(p, ids));
+            }
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+fn collect_inline_ids<'a>(nodes: &'a [InlineNode], ids: &mut HashSet<&'a str>) {
+  for node in nodes {
+    match &node.content {
+      Inline::InlineAnchor(id) | Inline::BiblioAnchor(id) => {
+        ids.insert(id.as_str());
+      }
+      Inline::Bold(children)
+      | Inline::Highlight(children)
+      | Inline::Italic(children)
+      | Inline::InlinePassthru(children)
+      | Inline::Mono(children)
+      | Inline::Quote(_, children)
+      | Inline::Superscript(children)
+      | Inline::Subscript(children)
+      | Inline::TextSpan(_, children) => collect_inline_ids(children, ids),
+      _ => {}
+    }
+  }
+}
@@ -92,6 +92,7 @@ pub struct Cell<'arena> {
   pub row_span: u8,
   pub h_align: HorizontalAlignment,
   pub v_align: VerticalAlignment,
+  pub id: Option<BumpString<'arena>>,
 }
 
 impl<'arena> Cell<'arena> {
@@ -108,6 +109,7 @@ impl<'arena> Cell<'arena> {
       v_align: cell_spec
         .v_align
         .unwrap_or(col_spec.map_or(VerticalAlignment::Top, |cs| cs.v_align)),
+      id: None,
     }
   }
 }
@@ -0,0 +1,34 @@
+use crate::internal::*;
+
+/// A run of source bytes, recorded by the lexer only when parsing in full
+/// fidelity mode. Every byte of every source is covered by exactly one
+/// trivia, so concatenating the `raw` of a source's trivia, in order,
+/// reproduces that source exactly, letting tooling rewrite only the
+/// parts of a document that changed
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Trivia<'arena> {
+  pub kind: TriviaKind,
+  pub loc: SourceLocation,
+  pub raw: BumpString<'arena>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TriviaKind {
+  /// source lexed into tokens for the parser, whose meaning lives in the AST
+  Tokens,
+  /// a line ending, either `\n` or `\r\n`
+  Newline,
+  /// one or more empty lines separating blocks
+  BlankLines,
+  /// opening or closing delimiter line, e.g. `======`
+  Delimiter,
+  /// raw source of a block attribute list line, e.g. `[source, rust]`
+  AttrList,
+  /// raw source of a block anchor line, e.g. `[[foo,Foo]]`
+  BlockAnchor,
+  /// raw source of a block title line, e.g. `.My Title`
+  BlockTitle,
+  /// source following the first paragraph of an `inline` document,
+  /// which is never parsed
+  Unparsed,
+}
@@ -100,6 +100,10 @@ pub trait Backend {
   fn enter_list_item_blocks(&mut self, blocks: &[Block], item: &ListItem, variant: ListVariant);
   fn exit_list_item_blocks(&mut self, blocks: &[Block], item: &ListItem, variant: ListVariant);
 
+  // nested content, see `NestedContext` for what state is shared
+  fn nested_backend(&mut self, context: NestedContext) -> Self;
+  fn visit_nested_result(&mut self, nested: Self, context: NestedContext);
+
   // tables
   fn enter_table(&mut self, table: &Table, block: &Block);
   fn exit_table(&mut self, table: &Table, block: &Block);
@@ -111,8 +115,6 @@ pub trait Backend {
   fn exit_table_cell(&mut self, cell: &Cell, section: TableSection);
   fn enter_cell_paragraph(&mut self, cell: &Cell, section: TableSection);
   fn exit_cell_paragraph(&mut self, cell: &Cell, section: TableSection);
-  fn asciidoc_table_cell_backend(&mut self) -> Self;
-  fn visit_asciidoc_table_cell_result(&mut self, cell_backend: Self);
 
   // block content
   fn enter_block_title(&mut self, title: &[InlineNode], block: &Block);
@@ -123,6 +125,11 @@ pub trait Backend {
   fn exit_compound_block_content(&mut self, children: &[Block], block: &Block);
   fn visit_thematic_break(&mut self, block: &Block);
   fn visit_page_break(&mut self, block: &Block);
+  /// called for blocks whose context and content this backend has no
+  /// dedicated hook for, instead of panicking. The default drops the block.
+  fn visit_unknown_block(&mut self, block: &Block) {
+    _ = block;
+  }
 
   /// inlines
   fn visit_inline_text(&mut self, text: &str);
@@ -140,6 +147,11 @@ pub trait Backend {
     warn_unimplemented!(visit_keyboard_macro);
   }
 
+  fn visit_stem_macro(&mut self, text: &str, notation: StemNotation) {
+    _ = (text, notation);
+    warn_unimplemented!(visit_stem_macro);
+  }
+
   fn enter_link_macro(
     &mut self,
     target: &str,
@@ -195,13 +207,25 @@ pub trait Backend {
   fn exit_footnote(&mut self, id: Option<&str>, content: Option<&[InlineNode]>);
   fn enter_text_span(&mut self, attrs: &AttrList, children: &[InlineNode]);
   fn exit_text_span(&mut self, attrs: &AttrList, children: &[InlineNode]);
-  fn enter_xref(&mut self, target: &str, reftext: Option<&[InlineNode]>, kind: XrefKind);
+  fn enter_xref(
+    &mut self,
+    target: &str,
+    reftext: Option<&[InlineNode]>,
+    kind: XrefKind,
+    attrs: Option<&AttrList>,
+    anchor: Option<&Anchor>,
+  );
   fn exit_xref(&mut self, target: &str, reftext: Option<&[InlineNode]>, kind: XrefKind);
   fn visit_missing_xref(&mut self, target: &str, kind: XrefKind, doc_title: Option<&DocTitle>);
   fn visit_inline_anchor(&mut self, id: &str);
   fn visit_biblio_anchor(&mut self, id: &str, reftext: Option<&str>);
   fn visit_symbol(&mut self, kind: SymbolKind);
   fn visit_linebreak(&mut self);
+  /// called for inline nodes added to the AST after this backend was
+  /// written. The default drops the node.
+  fn visit_unknown_inline(&mut self, inline: &InlineNode) {
+    _ = inline;
+  }
   fn enter_xref_text(&mut self, text: &[InlineNode], is_biblio: bool) {
     _ = (text, is_biblio);
   }
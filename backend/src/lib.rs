@@ -3,15 +3,18 @@ extern crate asciidork_core as core;
 
 mod admonition;
 mod backend;
+mod nested;
 pub mod utils;
 
 // TODO: maybe move this into ast?
 pub use admonition::AdmonitionKind;
 
 pub use backend::Backend;
+pub use nested::NestedContext;
 
 pub mod prelude {
   pub use super::AdmonitionKind;
   pub use super::Backend;
+  pub use super::NestedContext;
   pub use core::{AttrValue, DocType};
 }
@@ -0,0 +1,20 @@
+/// Content evaluated into a separate backend instance, whose output is then
+/// handed back to the parent with [`crate::Backend::visit_nested_result`].
+///
+/// The parent decides which of its state the nested backend sees:
+///
+/// - *document attributes* are read-only for nested inline content; an
+///   AsciiDoc table cell is its own document, with attributes inherited at
+///   parse time and changes kept local to the cell
+/// - *footnotes* are shared by table cells and footnote text, so numbering
+///   continues across them, but TOC entries only get a snapshot, so that
+///   rendering a heading twice doesn't register its footnotes twice
+/// - *anchors* live on the parsed document, and are shared by all contexts
+/// - everything else (output buffers, caption and section counters, list
+///   and newline state) starts fresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NestedContext {
+  AsciidocTableCell,
+  FootnoteText,
+  TocEntry,
+}
@@ -1,4 +1,6 @@
 pub mod xref {
+  use std::borrow::Cow;
+
   use ast::{DocumentMeta, ReadAttr, XrefKind};
   use core::file;
 
@@ -23,8 +25,8 @@ pub mod xref {
 
     if xref_path_was_included(path, doc_meta) {
       href.push('#');
-      if let Some(id) = id {
-        href.push_str(id);
+      if id.is_some() {
+        href.push_str(&resolve_id(target, doc_meta));
       }
       return href;
     }
@@ -101,6 +103,39 @@ pub mod xref {
   pub fn get_id(target: &str) -> &str {
     target.split_once('#').map(|x| x.1).unwrap_or(target)
   }
+
+  /// like `get_id`, but accounts for section ids namespaced by the
+  /// included file they came from, see `file::namespaced_id`
+  pub fn resolve_id<'a>(target: &'a str, doc_meta: &DocumentMeta) -> Cow<'a, str> {
+    if let Some((path, id)) = target.split_once('#') {
+      if !path.is_empty() && !doc_meta.namespaced_ids.is_empty() {
+        let namespaced = file::namespaced_id(&file::id_namespace(path), id);
+        if doc_meta.namespaced_ids.contains(&namespaced) {
+          return Cow::Owned(namespaced);
+        }
+      }
+    }
+    Cow::Borrowed(get_id(target))
+  }
+
+  /// An unqualified `target` in the included source at `source_idx` refers
+  /// to that file's own namespaced id, if it has one, see `resolve_id`
+  pub fn resolve_target<'a>(
+    target: &'a str,
+    source_idx: u16,
+    doc_meta: &DocumentMeta,
+  ) -> Cow<'a, str> {
+    let id = remove_leading_hash(target);
+    if id.contains('#') {
+      return Cow::Borrowed(target);
+    }
+    doc_meta
+      .id_namespaces
+      .get(&source_idx)
+      .map(|namespace| file::namespaced_id(namespace, id))
+      .filter(|namespaced| doc_meta.namespaced_ids.contains(namespaced))
+      .map_or(Cow::Borrowed(target), Cow::Owned)
+  }
 }
 
 pub fn set_backend_attrs<B: crate::Backend>(doc_meta: &mut ast::DocumentMeta) {
@@ -1,4 +1,7 @@
-use asciidork_core::{DocType, JobAttr, JobAttrs, JobSettings, SafeMode};
+use asciidork_core::{
+  ConversionProfile, DocType, JobAttr, JobAttrs, JobAttrsFileFormat, JobSettings, SafeMode,
+  SectionLevelPolicy,
+};
 use clap::Parser;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -8,7 +11,18 @@ use std::str::FromStr;
 #[command(version, about = "🤓 Asciidork CLI")]
 #[command(name = "asciidork", bin_name = "asciidork")]
 pub struct Args {
-  #[clap(short, long, help = "The file path to parse - omit to read from stdin")]
+  #[command(subcommand)]
+  pub command: Option<Command>,
+
+  #[clap(help = "The file path to parse - omit or use `-` to read from stdin")]
+  #[clap(conflicts_with = "input")]
+  pub file: Option<std::path::PathBuf>,
+
+  #[clap(
+    short,
+    long,
+    help = "The file path to parse - omit or use `-` to read from stdin"
+  )]
   pub input: Option<std::path::PathBuf>,
 
   #[clap(short, long, default_value = "dr-html")]
@@ -27,21 +41,62 @@ pub struct Args {
   )]
   pub attributes: Vec<(String, JobAttr)>,
 
+  #[clap(long)]
+  #[clap(
+    help = "Load document attributes from a flat JSON or TOML file - attributes set with -a take precedence"
+  )]
+  pub attributes_file: Option<std::path::PathBuf>,
+
   #[arg(value_parser = SafeMode::from_str)]
   #[clap(short, long, default_value = "secure")]
   #[clap(help = "Set safe mode explicitly")]
   pub safe_mode: SafeMode,
 
-  #[clap(short, long, help = "Output file path - omit to write to stdout")]
+  #[clap(short, long, visible_alias = "out-file")]
+  #[clap(help = "Output file path - omit or use `-` to write to stdout")]
   pub output: Option<std::path::PathBuf>,
 
+  #[clap(short = 'D', long)]
+  #[clap(
+    help = "Destination directory for the output file - used to resolve a relative output path, or to name the output after the input file"
+  )]
+  pub destination_dir: Option<std::path::PathBuf>,
+
   #[clap(short, long, default_value = "false")]
   #[clap(help = "Supress enclosing document structure")]
   pub embedded: bool,
 
+  #[arg(value_parser = ConversionProfile::from_str)]
+  #[clap(long)]
+  #[clap(
+    help = "Apply a bundle of settings for a common use case: github-readme, standalone-article, or embedded-fragment - other flags and attributes take precedence"
+  )]
+  pub profile: Option<ConversionProfile>,
+
   #[clap(long, default_value = "false")]
   pub strict: bool,
 
+  #[clap(long, default_value = "false")]
+  #[clap(help = "Emit XHTML - self-closing void elements and numeric character references")]
+  pub xhtml: bool,
+
+  #[clap(long, default_value = "false")]
+  #[clap(
+    help = "Collapse whitespace between tags in `dr-html` output, leaving pre and code content alone"
+  )]
+  pub minify: bool,
+
+  #[clap(long)]
+  #[clap(
+    help = "Minimum severity of diagnostic that causes a nonzero exit - omit to only fail when the document can't be converted"
+  )]
+  pub failure_level: Option<FailureLevel>,
+
+  #[arg(value_parser = SectionLevelPolicy::from_str)]
+  #[clap(long, default_value = "strict")]
+  #[clap(help = "Handling of out of sequence section levels: strict, auto-adjust, or ignore")]
+  pub section_levels: SectionLevelPolicy,
+
   #[clap(
     short = 'B',
     long,
@@ -49,15 +104,111 @@ pub struct Args {
   )]
   pub base_dir: Option<std::path::PathBuf>,
 
+  #[clap(long)]
+  #[clap(
+    help = "Reuse converted output from this directory when the input, includes, and settings are unchanged"
+  )]
+  pub cache_dir: Option<std::path::PathBuf>,
+
   #[clap(short = 't', long, default_value = "false")]
   #[clap(help = "Print timing/perf info\n")]
   pub print_timings: bool,
 }
 
+/// Where converted output is written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Destination {
+  Stdout,
+  File(std::path::PathBuf),
+}
+
+impl Args {
+  /// the input file, or `None` when reading from stdin
+  pub fn input_path(&self) -> Option<&std::path::Path> {
+    self
+      .file
+      .as_ref()
+      .or(self.input.as_ref())
+      .map(|path| path.as_path())
+      .filter(|path| !is_stdio(path))
+  }
+
+  /// resolves `--out-file` and `--destination-dir` the way asciidoctor does:
+  /// a relative out file is placed in the destination dir, and with no out
+  /// file, the output is named after the input file
+  pub fn destination(&self) -> Destination {
+    match (&self.output, &self.destination_dir) {
+      (Some(output), _) if is_stdio(output) => Destination::Stdout,
+      (Some(output), Some(dir)) => Destination::File(dir.join(output)),
+      (Some(output), None) => Destination::File(output.clone()),
+      (None, Some(dir)) => match self.input_path().and_then(|path| path.file_stem()) {
+        Some(stem) => {
+          let ext = match self.format {
+            Output::Stats => "json",
+            Output::DrHtml | Output::DrHtmlPrettier => "html",
+          };
+          Destination::File(dir.join(stem).with_extension(ext))
+        }
+        None => Destination::Stdout,
+      },
+      (None, None) => Destination::Stdout,
+    }
+  }
+}
+
+fn is_stdio(path: &std::path::Path) -> bool {
+  path.as_os_str() == "-"
+}
+
+#[derive(clap::Subcommand, Debug, Clone)]
+pub enum Command {
+  /// Parse documents without converting, reporting any diagnostics
+  Check(CheckArgs),
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct CheckArgs {
+  #[clap(help = "The file paths to check - omit or use `-` to read from stdin")]
+  pub inputs: Vec<std::path::PathBuf>,
+
+  #[clap(short, long, default_value = "human")]
+  #[clap(help = "Select diagnostic output format")]
+  pub format: CheckFormat,
+
+  #[clap(long, default_value = "error")]
+  #[clap(help = "Minimum severity of diagnostic that causes a nonzero exit")]
+  pub failure_level: FailureLevel,
+
+  #[arg(value_parser = parse_attr)]
+  #[clap(short, long = "attribute")]
+  #[clap(help = "Set a document attribute (i.e., name=value, name@=value, name!, name)")]
+  pub attributes: Vec<(String, JobAttr)>,
+
+  #[arg(value_parser = SafeMode::from_str)]
+  #[clap(short, long, default_value = "secure")]
+  #[clap(help = "Set safe mode explicitly")]
+  pub safe_mode: SafeMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CheckFormat {
+  Human,
+  Json,
+  Github,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum FailureLevel {
+  Warning,
+  Error,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum Output {
   DrHtml,
   DrHtmlPrettier,
+  /// JSON summary of sections, xrefs, links, images and footnotes
+  Stats,
 }
 
 lazy_static! {
@@ -86,6 +237,61 @@ fn parse_attr(input: &str) -> Result<(String, JobAttr), &'static str> {
   }
 }
 
+#[test]
+fn test_destination() {
+  use std::path::PathBuf;
+  let cases: [(&[&str], Destination); 7] = [
+    (&[], Destination::Stdout),
+    (&["-o", "-"], Destination::Stdout),
+    (&["-o", "out.html"], Destination::File("out.html".into())),
+    (&["-D", "dist", "-"], Destination::Stdout),
+    (
+      &["-D", "dist", "-o", "out.html"],
+      Destination::File("dist/out.html".into()),
+    ),
+    (
+      &["-D", "dist", "-o", "/abs/out.html"],
+      Destination::File("/abs/out.html".into()),
+    ),
+    (
+      &["-D", "dist", "docs/guide.adoc"],
+      Destination::File(PathBuf::from("dist/guide.html")),
+    ),
+  ];
+  for (argv, expected) in cases {
+    let args = Args::parse_from(std::iter::once("asciidork").chain(argv.iter().copied()));
+    assert_eq!(args.destination(), expected, "args: {argv:?}");
+  }
+}
+
+#[test]
+fn test_profile() {
+  let parse = |argv: &[&str]| -> JobSettings {
+    let args = Args::parse_from(std::iter::once("asciidork").chain(argv.iter().copied()));
+    args.try_into().unwrap()
+  };
+  let settings = parse(&["--profile", "github-readme"]);
+  assert!(settings.embedded);
+  assert_eq!(
+    settings.job_attrs.get("idprefix"),
+    Some(&JobAttr::modifiable(""))
+  );
+
+  let settings = parse(&["--profile", "standalone-article", "-e", "-a", "toc=left"]);
+  assert!(settings.embedded);
+  assert_eq!(
+    settings.job_attrs.get("toc"),
+    Some(&JobAttr::readonly("left"))
+  );
+  assert_eq!(
+    settings.job_attrs.get("icons"),
+    Some(&JobAttr::modifiable("font"))
+  );
+
+  let args = ["asciidork", "--profile", "nope"];
+  assert!(Args::try_parse_from(args).is_err());
+}
+
 #[test]
 fn test_parse_job_attr() {
   let cases = [
@@ -109,18 +315,58 @@ fn test_parse_job_attr() {
 }
 
 impl TryFrom<Args> for JobSettings {
-  type Error = String;
+  type Error = Box<dyn std::error::Error>;
   fn try_from(args: Args) -> Result<Self, Self::Error> {
     let mut j = JobSettings {
       safe_mode: args.safe_mode,
       doctype: Some(args.doctype),
       embedded: args.embedded,
       strict: args.strict,
+      section_level_policy: args.section_levels,
+      full_fidelity: false,
+      on_progress: None,
+      xhtml: args.xhtml,
       job_attrs: JobAttrs::empty(),
     };
+    if let Some(path) = &args.attributes_file {
+      // keep the io error, so it exits with `exit_code::IO`
+      let src = std::fs::read_to_string(path).map_err(|err| {
+        std::io::Error::new(
+          err.kind(),
+          format!(
+            "Error reading attributes file `{}`: {}",
+            path.display(),
+            err
+          ),
+        )
+      })?;
+      let format = JobAttrsFileFormat::from_path(&path.to_string_lossy());
+      j.job_attrs = JobAttrs::from_file_src(&src, format)
+        .map_err(|err| format!("Error in attributes file `{}`: {}", path.display(), err))?;
+    }
     for (key, attr) in args.attributes {
       j.job_attrs.insert(key, attr)?;
     }
+    if let Some(profile) = args.profile {
+      j.apply_profile(profile);
+      j.embedded |= args.embedded;
+    }
+    Ok(j)
+  }
+}
+
+impl TryFrom<&CheckArgs> for JobSettings {
+  type Error = String;
+  fn try_from(args: &CheckArgs) -> Result<Self, Self::Error> {
+    let mut j = JobSettings {
+      safe_mode: args.safe_mode,
+      // collect every diagnostic, instead of bailing on the first error
+      strict: false,
+      ..JobSettings::default()
+    };
+    for (key, attr) in &args.attributes {
+      j.job_attrs.insert(key, attr.clone())?;
+    }
     Ok(j)
   }
 }
@@ -0,0 +1,168 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{Read, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use asciidork_core::{JobSettings, Path};
+use asciidork_dr_html_backend::*;
+use asciidork_parser::prelude::*;
+use asciidork_parser::Severity;
+use serde::Serialize;
+
+use crate::args::{CheckArgs, CheckFormat};
+use crate::exit_code;
+use crate::resolver::CliResolver;
+
+/// parses each input without converting, and reports all diagnostics,
+/// returning the exit code for those that reached the requested failure level
+pub fn run(
+  args: CheckArgs,
+  mut stdin: impl Read,
+  mut stdout: impl Write,
+  mut stderr: impl Write,
+) -> Result<i32, Box<dyn Error>> {
+  let cwd_buf = env::current_dir()?;
+  let mut diagnostics = Vec::new();
+  let stdin_only = [std::path::PathBuf::from("-")];
+  let inputs = if args.inputs.is_empty() { &stdin_only[..] } else { &args.inputs[..] };
+  for pathbuf in inputs {
+    if pathbuf.as_os_str() == "-" {
+      let mut src = String::new();
+      stdin.read_to_string(&mut src)?;
+      let cwd = Path::new(cwd_buf.to_str().unwrap_or(""));
+      let src_file = SourceFile::Stdin { cwd };
+      diagnostics.extend(check(&src, src_file, Some(cwd_buf.clone()), &args)?);
+    } else {
+      let abspath = fs::canonicalize(pathbuf)?;
+      let src = fs::read_to_string(pathbuf)?;
+      let base_dir = abspath.parent().map(|p| p.to_path_buf());
+      let src_file = SourceFile::Path(abspath.into());
+      diagnostics.extend(check(&src, src_file, base_dir, &args)?);
+    }
+  }
+
+  let cwd = cwd_buf.to_string_lossy();
+  match args.format {
+    CheckFormat::Human => {
+      if !diagnostics.is_empty() {
+        let num_errors = diagnostics
+          .iter()
+          .filter(|d| d.severity == Severity::Error)
+          .count();
+        let num_warnings = diagnostics.len() - num_errors;
+        crate::print_diagnostics(&mut stderr, diagnostics.clone());
+        writeln!(stderr, "{num_errors} error(s), {num_warnings} warning(s)")?;
+      }
+    }
+    CheckFormat::Json => {
+      let json = diagnostics
+        .iter()
+        .map(|d| JsonDiagnostic {
+          file: display_path(&d.source_file, &cwd),
+          line: d.line_num,
+          column: d.underline_start + 1,
+          severity: severity_str(d.severity),
+          message: &d.message,
+        })
+        .collect::<Vec<_>>();
+      writeln!(stdout, "{}", serde_json::to_string(&json)?)?;
+    }
+    CheckFormat::Github => {
+      for d in &diagnostics {
+        writeln!(
+          stdout,
+          "::{} file={},line={},col={}::{}",
+          severity_str(d.severity),
+          github_escape_property(&display_path(&d.source_file, &cwd)),
+          d.line_num,
+          d.underline_start + 1,
+          github_escape_data(&d.message),
+        )?;
+      }
+    }
+  }
+
+  Ok(exit_code::for_diagnostics(&diagnostics, args.failure_level))
+}
+
+fn check(
+  src: &str,
+  src_file: SourceFile,
+  base_dir: Option<std::path::PathBuf>,
+  args: &CheckArgs,
+) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+  let bump = &Bump::with_capacity(src.len() * 2);
+  let mut job_settings: JobSettings = args.try_into()?;
+  AsciidoctorHtml::set_job_attrs(&mut job_settings.job_attrs);
+  let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+  let parser = Parser::builder(bump)
+    .source_str(src)
+    .file(src_file)
+    .settings(job_settings)
+    .resolver(Box::new(CliResolver::new(base_dir)))
+    .timestamps(now, None, None)
+    .build();
+  let diagnostics = match parser.parse() {
+    Ok(result) => result.warnings,
+    Err(diagnostics) => diagnostics,
+  };
+  Ok(diagnostics)
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+  file: String,
+  line: u32,
+  column: u32,
+  severity: &'static str,
+  message: &'a str,
+}
+
+const fn severity_str(severity: Severity) -> &'static str {
+  match severity {
+    Severity::Warning => "warning",
+    Severity::Error => "error",
+  }
+}
+
+fn display_path(src_file: &SourceFile, cwd: &str) -> String {
+  match src_file {
+    SourceFile::Path(path) => {
+      let path = path.to_string();
+      match path.strip_prefix(cwd) {
+        Some(relative) => relative.trim_start_matches(['/', '\\']).to_string(),
+        None => path,
+      }
+    }
+    _ => src_file.display_name().to_string(),
+  }
+}
+
+// https://github.com/actions/toolkit/blob/main/packages/core/src/command.ts
+fn github_escape_data(s: &str) -> String {
+  s.replace('%', "%25")
+    .replace('\r', "%0D")
+    .replace('\n', "%0A")
+}
+
+fn github_escape_property(s: &str) -> String {
+  github_escape_data(s)
+    .replace(':', "%3A")
+    .replace(',', "%2C")
+}
+
+#[test]
+fn test_escapes() {
+  assert_eq!(github_escape_data("100%\nok"), "100%25%0Aok");
+  assert_eq!(github_escape_property("a:b,c"), "a%3Ab%2Cc");
+}
+
+#[test]
+fn test_display_path() {
+  let src_file = SourceFile::Path(Path::new("/repo/docs/a.adoc"));
+  assert_eq!(display_path(&src_file, "/repo"), "docs/a.adoc");
+  assert_eq!(display_path(&src_file, "/other"), "/repo/docs/a.adoc");
+  let stdin = SourceFile::Stdin { cwd: Path::new("/repo") };
+  assert_eq!(display_path(&stdin, "/repo"), "<stdin>");
+}
@@ -0,0 +1,37 @@
+use std::error::Error;
+
+use asciidork_parser::prelude::*;
+use asciidork_parser::Severity;
+
+use crate::args::FailureLevel;
+
+/// Process exit codes, kept stable so scripts can tell failures apart.
+/// NB: clap also exits with `ERRORS` for invalid arguments.
+pub const OK: i32 = 0;
+/// warnings were reported at or above the `--failure-level`
+pub const WARNINGS: i32 = 1;
+/// the document could not be converted, or errors were reported
+pub const ERRORS: i32 = 2;
+/// reading input or writing output failed
+pub const IO: i32 = 3;
+
+pub fn for_diagnostics(diagnostics: &[Diagnostic], failure_level: FailureLevel) -> i32 {
+  let failure_level = match failure_level {
+    FailureLevel::Warning => Severity::Warning,
+    FailureLevel::Error => Severity::Error,
+  };
+  let failing = diagnostics.iter().filter(|d| d.severity >= failure_level);
+  match failing.map(|d| d.severity).max() {
+    None => OK,
+    Some(Severity::Warning) => WARNINGS,
+    Some(Severity::Error) => ERRORS,
+  }
+}
+
+pub fn for_error(err: &(dyn Error + 'static)) -> i32 {
+  if err.is::<std::io::Error>() {
+    IO
+  } else {
+    ERRORS
+  }
+}
@@ -9,19 +9,41 @@ use bumpalo::Bump;
 use clap::Parser as ClapParser;
 use colored::*;
 
-use asciidork_core::{JobSettings, Path};
+use asciidork_core::{
+  references_clock, CacheKey, CachedConversion, CachedDiagnostic, ConversionCache, FileCache,
+  JobSettings, Path,
+};
 use asciidork_dr_html_backend::*;
 use asciidork_parser::prelude::*;
+use asciidork_stats_backend::DocumentStats;
 
 mod args;
+mod check;
+mod exit_code;
 mod resolver;
 
-use args::{Args, Output};
+use args::{Args, Command as Subcommand, Destination, Output};
 use resolver::CliResolver;
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn main() {
   let args = Args::parse();
-  run(args, std::io::stdin(), std::io::stdout(), std::io::stderr())
+  let result = if let Some(Subcommand::Check(check_args)) = args.command {
+    check::run(
+      check_args,
+      std::io::stdin(),
+      std::io::stdout(),
+      std::io::stderr(),
+    )
+  } else {
+    run(args, std::io::stdin(), std::io::stdout(), std::io::stderr())
+  };
+  match result {
+    Ok(code) => std::process::exit(code),
+    Err(err) => {
+      eprintln!("Error: {err}");
+      std::process::exit(exit_code::for_error(err.as_ref()));
+    }
+  }
 }
 
 fn run(
@@ -29,11 +51,11 @@ fn run(
   mut stdin: impl Read,
   mut stdout: impl Write,
   mut stderr: impl Write,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<i32, Box<dyn Error>> {
   let (src, src_file, base_dir, input_mtime) = {
-    if let Some(pathbuf) = &args.input {
+    if let Some(pathbuf) = args.input_path() {
       let abspath = fs::canonicalize(pathbuf)?;
-      let mut file = fs::File::open(pathbuf.clone())?;
+      let mut file = fs::File::open(pathbuf)?;
       let mut input_mtime = None;
       let mut src = file
         .metadata()
@@ -64,53 +86,129 @@ fn run(
   };
 
   let parse_start = Instant::now();
-  let bump = &Bump::with_capacity(src.len() * 2);
-  let mut parser = Parser::from_str(&src, src_file, bump);
   let mut job_settings: JobSettings = args.clone().try_into()?;
-  AsciidoctorHtml::set_job_attrs(&mut job_settings.job_attrs);
-  parser.apply_job_settings(job_settings);
-  parser.set_resolver(Box::new(CliResolver::new(base_dir)));
+  let backend = match args.format {
+    Output::DrHtml | Output::DrHtmlPrettier => {
+      AsciidoctorHtml::set_job_attrs(&mut job_settings.job_attrs);
+      ("dr-html", asciidork_dr_html_backend::VERSION)
+    }
+    Output::Stats => {
+      DocumentStats::set_job_attrs(&mut job_settings.job_attrs);
+      ("stats", asciidork_stats_backend::VERSION)
+    }
+  };
 
+  let mut cache = args.cache_dir.as_ref().map(FileCache::new);
+  let cache_key = CacheKey::builder()
+    .source(src.as_bytes())
+    .field("file", format!("{:?} {:?}", src_file, base_dir).as_bytes())
+    .field("mtime", &input_mtime.unwrap_or(0).to_le_bytes())
+    .job_settings(&job_settings)
+    .backend(backend.0, backend.1)
+    .build();
+  if let Some(cached) = cache
+    .as_mut()
+    .and_then(|cache| cache.get(cache_key))
+    .filter(|cached| cached.is_fresh(|path| fs::read(path).ok()))
+  {
+    let lookup_time = parse_start.elapsed();
+    let warnings = cached
+      .diagnostics
+      .into_iter()
+      .map(from_cached)
+      .collect::<Vec<_>>();
+    print_diagnostics(&mut stderr, warnings.clone());
+    write_output(&args, cached.output, &mut stdout, &mut stderr)?;
+    if args.print_timings {
+      print_timings(&mut stderr, src.len(), lookup_time, None);
+    }
+    return Ok(warnings_exit_code(&args, &warnings));
+  }
+
+  let bump = &Bump::with_capacity(src.len() * 2);
   let now = SystemTime::now()
     .duration_since(UNIX_EPOCH)
     .unwrap()
     .as_secs();
-  parser.provide_timestamps(now, input_mtime, None);
+  let parser = Parser::builder(bump)
+    .source_str(&src)
+    .file(src_file)
+    .settings(job_settings)
+    .resolver(Box::new(CliResolver::new(base_dir)))
+    .timestamps(now, input_mtime, None)
+    .build();
 
   let result = parser.parse();
   let parse_time = parse_start.elapsed();
 
   match result {
-    Ok(parse_result) => match &args.format {
-      Output::DrHtml | Output::DrHtmlPrettier => {
-        let convert_start = Instant::now();
-        let mut html = convert(parse_result.document)?;
-        let convert_time = convert_start.elapsed();
-        let prettify = args.format == Output::DrHtmlPrettier;
-        if prettify {
-          html = format_html(html);
-        }
-        if let Some(file) = &args.output {
-          fs::write(file, html)?;
-        } else {
-          if prettify {
-            writeln!(stderr)?;
-          }
-          writeln!(stdout, "{html}")?;
-        }
-        if args.print_timings {
-          if !prettify {
-            writeln!(stderr)?;
-          }
-          print_timings(&mut stderr, src.len(), parse_time, Some(convert_time));
+    Ok(parse_result) => {
+      let warnings = parse_result.warnings;
+      print_diagnostics(&mut stderr, warnings.clone());
+      let uses_clock = parse_result
+        .document
+        .sources
+        .iter()
+        .any(|src| references_clock(src, input_mtime.is_some()));
+      let convert_start = Instant::now();
+      let output = match args.format {
+        Output::DrHtml | Output::DrHtmlPrettier => convert(parse_result.document)?,
+        Output::Stats => asciidork_stats_backend::convert(parse_result.document)?,
+      };
+      let convert_time = convert_start.elapsed();
+      // output depending on the time of conversion would go stale
+      if let Some(cache) = cache.as_mut().filter(|_| !uses_clock) {
+        let conversion = CachedConversion {
+          output: output.clone(),
+          includes: parse_result.includes,
+          diagnostics: warnings.iter().map(to_cached).collect(),
+        };
+        if conversion.is_cacheable() {
+          cache.insert(cache_key, conversion);
         }
       }
-    },
+      write_output(&args, output, &mut stdout, &mut stderr)?;
+      if args.print_timings {
+        print_timings(&mut stderr, src.len(), parse_time, Some(convert_time));
+      }
+      Ok(warnings_exit_code(&args, &warnings))
+    }
     Err(diagnostics) => {
       print_diagnostics(&mut stderr, diagnostics);
-      return Err("Parse error".into());
+      Ok(exit_code::ERRORS)
     }
   }
+}
+
+fn write_output(
+  args: &Args,
+  mut output: String,
+  stdout: &mut impl Write,
+  stderr: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+  let prettify = args.format == Output::DrHtmlPrettier;
+  if prettify {
+    output = format_html(output);
+  } else if args.minify && args.format == Output::DrHtml {
+    output = minify(&output);
+  }
+  match args.destination() {
+    Destination::File(file) => {
+      if let Some(dir) = file.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+      }
+      fs::write(file, output)?;
+    }
+    Destination::Stdout => {
+      if prettify {
+        writeln!(stderr)?;
+      }
+      writeln!(stdout, "{output}")?;
+    }
+  }
+  if args.print_timings && !prettify {
+    writeln!(stderr)?;
+  }
   Ok(())
 }
 
@@ -186,6 +284,38 @@ fn print_diagnostics(dest: &mut impl Write, diagnostics: Vec<Diagnostic>) {
   }
 }
 
+fn warnings_exit_code(args: &Args, warnings: &[Diagnostic]) -> i32 {
+  match args.failure_level {
+    Some(level) => exit_code::for_diagnostics(warnings, level),
+    None => exit_code::OK,
+  }
+}
+
+fn to_cached(diagnostic: &Diagnostic) -> CachedDiagnostic {
+  CachedDiagnostic {
+    error: diagnostic.severity == Severity::Error,
+    file: diagnostic.source_file.display_name().to_string(),
+    line_num: diagnostic.line_num,
+    line: diagnostic.line.clone(),
+    message: diagnostic.message.clone(),
+    underline_start: diagnostic.underline_start,
+    underline_width: diagnostic.underline_width,
+  }
+}
+
+fn from_cached(cached: CachedDiagnostic) -> Diagnostic {
+  Diagnostic {
+    severity: if cached.error { Severity::Error } else { Severity::Warning },
+    line_num: cached.line_num,
+    line: cached.line,
+    message: cached.message,
+    underline_start: cached.underline_start,
+    underline_width: cached.underline_width,
+    // only the name is needed to print it again
+    source_file: SourceFile::Virtual { name: cached.file },
+  }
+}
+
 struct Colorizer;
 
 impl DiagnosticColor for Colorizer {
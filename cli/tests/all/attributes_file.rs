@@ -0,0 +1,23 @@
+use test_utils::*;
+
+use crate::run_cli;
+
+#[test]
+fn test_attributes_file() {
+  let stdout = run_cli(
+    &[
+      "--embedded",
+      "--attributes-file",
+      "tests/all/fixtures/attributes-file.toml",
+      "-a",
+      "version=2.0",
+    ],
+    "tests/all/fixtures/attributes-file.adoc",
+  );
+  expect_eq!(
+    stdout.trim(),
+    html! {r#"
+      <div class="paragraph"><p>Asciidork 2.0</p></div>
+    "#}
+  );
+}
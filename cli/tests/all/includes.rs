@@ -1,7 +1,7 @@
-use std::process::{Command, Stdio};
-
 use test_utils::*;
 
+use crate::{cwd, run_cli};
+
 #[cfg(unix)]
 #[test]
 fn test_cli_app_single_include() {
@@ -167,33 +167,3 @@ fn test_cli_runs_on_windows() {
     .replace("{cwd}", &cwd())
   );
 }
-
-fn run_cli(args: &[&str], input: &str) -> String {
-  let child = Command::new("cargo")
-    .arg("run")
-    .args(["--quiet", "--"])
-    .args(["--input", input])
-    .args(args)
-    .stdin(Stdio::piped())
-    .stderr(Stdio::piped())
-    .stdout(Stdio::piped())
-    .spawn()
-    .unwrap();
-
-  let output = child.wait_with_output().unwrap();
-  let stdout = String::from_utf8_lossy(&output.stdout);
-
-  if !output.status.success() {
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    println!("{stderr}");
-    panic!("\nCommand failed: {:?}", output.status);
-  }
-  stdout.to_string()
-}
-
-fn cwd() -> String {
-  std::env::current_dir()
-    .unwrap()
-    .to_string_lossy()
-    .to_string()
-}
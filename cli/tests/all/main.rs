@@ -1 +1,59 @@
+mod attributes_file;
 mod includes;
+mod stdio;
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+pub fn run_cli(args: &[&str], input: &str) -> String {
+  let child = Command::new("cargo")
+    .arg("run")
+    .args(["--quiet", "--"])
+    .args(["--input", input])
+    .args(args)
+    .stdin(Stdio::piped())
+    .stderr(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+
+  let output = child.wait_with_output().unwrap();
+  let stdout = String::from_utf8_lossy(&output.stdout);
+
+  if !output.status.success() {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    println!("{stderr}");
+    panic!("\nCommand failed: {:?}", output.status);
+  }
+  stdout.to_string()
+}
+
+pub fn cwd() -> String {
+  std::env::current_dir()
+    .unwrap()
+    .to_string_lossy()
+    .to_string()
+}
+
+/// runs the cli with `stdin` piped in, returning the exit code and stdout
+pub fn run_cli_piped(args: &[&str], stdin: &str) -> (i32, String) {
+  let mut child = Command::new("cargo")
+    .arg("run")
+    .args(["--quiet", "--"])
+    .args(args)
+    .stdin(Stdio::piped())
+    .stderr(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+
+  child
+    .stdin
+    .take()
+    .unwrap()
+    .write_all(stdin.as_bytes())
+    .unwrap();
+  let output = child.wait_with_output().unwrap();
+  let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+  (output.status.code().unwrap(), stdout)
+}
@@ -0,0 +1,82 @@
+use test_utils::*;
+
+use crate::run_cli_piped;
+
+#[test]
+fn test_dash_reads_stdin_and_writes_stdout() {
+  let (code, stdout) = run_cli_piped(&["--embedded", "-o", "-", "-"], "hello _world_");
+  assert_eq!(code, 0);
+  expect_eq!(
+    stdout.trim(),
+    html! {r#"
+      <div class="paragraph"><p>hello <em>world</em></p></div>
+    "#}
+  );
+}
+
+#[test]
+fn test_destination_dir() {
+  let dir = std::env::temp_dir().join("asciidork-cli-destination-dir");
+  _ = std::fs::remove_dir_all(&dir);
+  let (code, stdout) = run_cli_piped(
+    &[
+      "--embedded",
+      "-D",
+      dir.to_str().unwrap(),
+      "tests/all/fixtures/attributes-file.adoc",
+    ],
+    "",
+  );
+  assert_eq!(code, 0);
+  assert!(stdout.is_empty());
+  let html = std::fs::read_to_string(dir.join("attributes-file.html")).unwrap();
+  assert!(html.starts_with(r#"<div class="paragraph">"#));
+  std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_exit_codes() {
+  let unclosed = "----\nfoo\n";
+  let unresolved = "include::missing.adoc[]\n";
+  let cases: [(&[&str], &str, i32); 6] = [
+    (&["--safe-mode", "unsafe"], unresolved, 0),
+    (
+      &["--safe-mode", "unsafe", "--failure-level", "error"],
+      unresolved,
+      2,
+    ),
+    (&["--strict"], unclosed, 2),
+    (&["check", "--failure-level", "error"], unclosed, 2),
+    (&["--input", "tests/all/fixtures/missing.adoc"], "", 3),
+    (
+      &["--attributes-file", "tests/all/fixtures/missing.toml"],
+      "foo",
+      3,
+    ),
+  ];
+  for (args, stdin, expected) in cases {
+    let (code, _) = run_cli_piped(args, stdin);
+    assert_eq!(code, expected, "args: {args:?}");
+  }
+}
+
+#[test]
+fn test_exit_code_on_cache_hit() {
+  let dir = std::env::temp_dir().join("asciidork-cli-cache-exit-code");
+  _ = std::fs::remove_dir_all(&dir);
+  let args = [
+    "--cache-dir",
+    dir.to_str().unwrap(),
+    "--failure-level",
+    "warning",
+    "--embedded",
+  ];
+  // the second run is served from the cache, but must fail the same way
+  for _ in 0..2 {
+    let (code, stdout) = run_cli_piped(&args, "see <<nope>>");
+    assert_eq!(code, 2);
+    assert!(stdout.contains("[nope]"));
+  }
+  assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+  std::fs::remove_dir_all(&dir).unwrap();
+}
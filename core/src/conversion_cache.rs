@@ -0,0 +1,469 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::internal::*;
+
+/// Identifies a conversion by everything that can affect its output: the
+/// root source, job settings and attributes, and the backend version.
+/// Included files aren't known until parsing, so they are checked
+/// separately, see [`CachedConversion::is_fresh`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheKey(u64);
+
+impl CacheKey {
+  pub const fn builder() -> CacheKeyBuilder {
+    CacheKeyBuilder(Fnv64::new())
+  }
+}
+
+impl std::fmt::Display for CacheKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{:016x}", self.0)
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct CacheKeyBuilder(Fnv64);
+
+impl CacheKeyBuilder {
+  pub fn source(self, src: &[u8]) -> Self {
+    self.field("source", src)
+  }
+
+  pub fn backend(self, name: &str, version: &str) -> Self {
+    self
+      .field("backend", name.as_bytes())
+      .field("version", version.as_bytes())
+  }
+
+  pub fn job_settings(mut self, settings: &JobSettings) -> Self {
+    // destructured, so a new setting can't be left out of the key unnoticed
+    let JobSettings {
+      doctype,
+      safe_mode,
+      job_attrs,
+      embedded,
+      strict,
+      section_level_policy,
+      full_fidelity,
+      on_progress: _,
+      xhtml,
+    } = settings;
+    // strict decides whether errors are fatal, so output cached by a
+    // lenient run must not be served to a strict one
+    let flags = format!(
+      "{:?} {:?} {} {} {:?} {} {}",
+      doctype, safe_mode, embedded, strict, section_level_policy, full_fidelity, xhtml,
+    );
+    self = self.field("settings", flags.as_bytes());
+    let mut attrs = job_attrs.as_ref().iter().collect::<Vec<_>>();
+    attrs.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, attr) in attrs {
+      let value = format!("{} {:?}", attr.readonly, attr.value);
+      self = self.field(key, value.as_bytes());
+    }
+    self
+  }
+
+  /// Anything else that affects the output, like an input file's mtime
+  pub fn field(mut self, label: &str, bytes: &[u8]) -> Self {
+    // lengths are hashed too, so adjacent fields can't run together
+    self.0.write(&(label.len() as u64).to_le_bytes());
+    self.0.write(label.as_bytes());
+    self.0.write(&(bytes.len() as u64).to_le_bytes());
+    self.0.write(bytes);
+    self
+  }
+
+  pub const fn build(self) -> CacheKey {
+    CacheKey(self.0.finish())
+  }
+}
+
+/// Stable (across runs and platforms) hash of file contents
+pub fn content_hash(bytes: &[u8]) -> u64 {
+  let mut hasher = Fnv64::new();
+  hasher.write(bytes);
+  hasher.finish()
+}
+
+/// True if `src` references an attribute holding the time of conversion,
+/// so its output would go stale in a cache. The `doc*` timestamps only
+/// count without an input mtime, as they then fall back to the same clock.
+pub fn references_clock(src: &str, has_mtime: bool) -> bool {
+  const LOCAL: [&str; 4] = [
+    "{localdate}",
+    "{localtime}",
+    "{localdatetime}",
+    "{localyear}",
+  ];
+  const DOC: [&str; 4] = ["{docdate}", "{doctime}", "{docdatetime}", "{docyear}"];
+  LOCAL.iter().any(|attr| src.contains(attr))
+    || (!has_mtime && DOC.iter().any(|attr| src.contains(attr)))
+}
+
+/// A file included while parsing, and the hash of its raw contents, or
+/// `None` if it didn't exist, so creating it later invalidates the cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncludeFingerprint {
+  pub path: String,
+  pub hash: Option<u64>,
+}
+
+/// A diagnostic reported while converting, replayed on a cache hit so a
+/// cached document warns, and fails, the same as when it was converted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedDiagnostic {
+  pub error: bool,
+  pub file: String,
+  pub line_num: u32,
+  pub line: String,
+  pub message: String,
+  pub underline_start: u32,
+  pub underline_width: u32,
+}
+
+/// A converted document, along with the includes it was built from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedConversion {
+  pub output: String,
+  pub includes: Vec<IncludeFingerprint>,
+  pub diagnostics: Vec<CachedDiagnostic>,
+}
+
+impl CachedConversion {
+  /// True if every included file still has the same contents, and every
+  /// missing one is still missing. `read` returns the current contents of a
+  /// path, or `None` if it's gone. Paths of includes resolved from a URI are
+  /// the URI itself, so a `read` backed by the filesystem will always find
+  /// them stale, see [`Self::is_cacheable`].
+  pub fn is_fresh(&self, mut read: impl FnMut(&str) -> Option<Vec<u8>>) -> bool {
+    self
+      .includes
+      .iter()
+      .all(|include| read(&include.path).map(|bytes| content_hash(&bytes)) == include.hash)
+  }
+
+  /// False if any include was resolved from a URI, since checking whether a
+  /// remote resource changed would cost about as much as converting again.
+  pub fn is_cacheable(&self) -> bool {
+    !self
+      .includes
+      .iter()
+      .any(|include| Path::new(include.path.as_str()).is_uri())
+  }
+}
+
+pub trait ConversionCache {
+  fn get(&mut self, key: CacheKey) -> Option<CachedConversion>;
+  fn insert(&mut self, key: CacheKey, conversion: CachedConversion);
+}
+
+/// Keeps conversions for the life of the process, e.g. in watch mode
+#[derive(Debug, Default)]
+pub struct MemoryCache(HashMap<CacheKey, CachedConversion>);
+
+impl MemoryCache {
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+impl ConversionCache for MemoryCache {
+  fn get(&mut self, key: CacheKey) -> Option<CachedConversion> {
+    self.0.get(&key).cloned()
+  }
+
+  fn insert(&mut self, key: CacheKey, conversion: CachedConversion) {
+    self.0.insert(key, conversion);
+  }
+}
+
+/// Stores one file per conversion in a directory, named by the key. Each
+/// file starts with a line holding the number of includes and diagnostics,
+/// then a `hash path` line per include (`-` for a missing file), then per
+/// diagnostic a line of its numbers and string lengths followed by the
+/// strings themselves, then the output. Unreadable or malformed entries
+/// are cache misses.
+#[derive(Debug, Clone)]
+pub struct FileCache {
+  dir: PathBuf,
+}
+
+impl FileCache {
+  pub fn new(dir: impl Into<PathBuf>) -> Self {
+    Self { dir: dir.into() }
+  }
+
+  fn entry_path(&self, key: CacheKey) -> PathBuf {
+    self.dir.join(format!("{}.cache", key))
+  }
+}
+
+impl ConversionCache for FileCache {
+  fn get(&mut self, key: CacheKey) -> Option<CachedConversion> {
+    let contents = fs::read_to_string(self.entry_path(key)).ok()?;
+    let (counts, mut rest) = contents.split_once('\n')?;
+    let (num_includes, num_diagnostics) = counts.split_once(' ')?;
+    let num_includes = num_includes.parse::<usize>().ok()?;
+    let mut includes = Vec::with_capacity(num_includes);
+    for _ in 0..num_includes {
+      let (line, after) = rest.split_once('\n')?;
+      let (hash, path) = line.split_once(' ')?;
+      let hash = match hash {
+        "-" => None,
+        hash => Some(u64::from_str_radix(hash, 16).ok()?),
+      };
+      includes.push(IncludeFingerprint { path: path.to_string(), hash });
+      rest = after;
+    }
+    let num_diagnostics = num_diagnostics.parse::<usize>().ok()?;
+    let mut diagnostics = Vec::with_capacity(num_diagnostics);
+    for _ in 0..num_diagnostics {
+      let (line, after) = rest.split_once('\n')?;
+      let nums = line
+        .split(' ')
+        .map(|n| n.parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+      let [error, line_num, underline_start, underline_width, file_len, line_len, msg_len] =
+        nums[..]
+      else {
+        return None;
+      };
+      let (file, after) = after.split_at_checked(file_len as usize)?;
+      let (line, after) = after.split_at_checked(line_len as usize)?;
+      let (message, after) = after.split_at_checked(msg_len as usize)?;
+      diagnostics.push(CachedDiagnostic {
+        error: error == 1,
+        file: file.to_string(),
+        line_num,
+        line: line.to_string(),
+        message: message.to_string(),
+        underline_start,
+        underline_width,
+      });
+      rest = after;
+    }
+    Some(CachedConversion {
+      output: rest.to_string(),
+      includes,
+      diagnostics,
+    })
+  }
+
+  // NB: caching is best-effort, so write errors are ignored
+  fn insert(&mut self, key: CacheKey, conversion: CachedConversion) {
+    if conversion.includes.iter().any(|i| i.path.contains('\n')) {
+      return;
+    }
+    let mut contents = String::with_capacity(conversion.output.len() + 64);
+    contents.push_str(&format!(
+      "{} {}\n",
+      conversion.includes.len(),
+      conversion.diagnostics.len()
+    ));
+    for include in &conversion.includes {
+      match include.hash {
+        Some(hash) => contents.push_str(&format!("{:016x} {}\n", hash, include.path)),
+        None => contents.push_str(&format!("- {}\n", include.path)),
+      }
+    }
+    for diagnostic in &conversion.diagnostics {
+      contents.push_str(&format!(
+        "{} {} {} {} {} {} {}\n",
+        diagnostic.error as u32,
+        diagnostic.line_num,
+        diagnostic.underline_start,
+        diagnostic.underline_width,
+        diagnostic.file.len(),
+        diagnostic.line.len(),
+        diagnostic.message.len(),
+      ));
+      contents.push_str(&diagnostic.file);
+      contents.push_str(&diagnostic.line);
+      contents.push_str(&diagnostic.message);
+    }
+    contents.push_str(&conversion.output);
+    if fs::create_dir_all(&self.dir).is_ok() {
+      _ = fs::write(self.entry_path(key), contents);
+    }
+  }
+}
+
+// FNV-1a, std's hashers aren't guaranteed stable across releases
+#[derive(Debug, Clone)]
+struct Fnv64(u64);
+
+impl Fnv64 {
+  const fn new() -> Self {
+    Self(0xcbf29ce484222325)
+  }
+
+  fn write(&mut self, bytes: &[u8]) {
+    for byte in bytes {
+      self.0 ^= *byte as u64;
+      self.0 = self.0.wrapping_mul(0x100000001b3);
+    }
+  }
+
+  const fn finish(&self) -> u64 {
+    self.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn key(src: &str) -> CacheKey {
+    CacheKey::builder()
+      .source(src.as_bytes())
+      .job_settings(&JobSettings::default())
+      .backend("html5", "1.0.0")
+      .build()
+  }
+
+  #[test]
+  fn test_cache_key() {
+    assert_eq!(key("foo"), key("foo"));
+    assert_ne!(key("foo"), key("bar"));
+    let other_backend = CacheKey::builder()
+      .source(b"foo")
+      .job_settings(&JobSettings::default())
+      .backend("html5", "1.0.1")
+      .build();
+    assert_ne!(key("foo"), other_backend);
+    let mut settings = JobSettings::default();
+    settings
+      .job_attrs
+      .insert_unchecked("toc", JobAttr::readonly(true));
+    let with_attr = CacheKey::builder()
+      .source(b"foo")
+      .job_settings(&settings)
+      .backend("html5", "1.0.0")
+      .build();
+    assert_ne!(key("foo"), with_attr);
+    let lenient = CacheKey::builder()
+      .source(b"foo")
+      .job_settings(&JobSettings {
+        strict: false,
+        ..JobSettings::default()
+      })
+      .backend("html5", "1.0.0")
+      .build();
+    assert_ne!(key("foo"), lenient);
+    let xhtml = CacheKey::builder()
+      .source(b"foo")
+      .job_settings(&JobSettings {
+        xhtml: true,
+        ..JobSettings::default()
+      })
+      .backend("html5", "1.0.0")
+      .build();
+    assert_ne!(key("foo"), xhtml);
+  }
+
+  #[test]
+  fn test_cached_conversion_freshness() {
+    let conversion = CachedConversion {
+      output: "<p>foo</p>".to_string(),
+      includes: vec![IncludeFingerprint {
+        path: "/a.adoc".to_string(),
+        hash: Some(content_hash(b"a")),
+      }],
+      diagnostics: vec![],
+    };
+    assert!(conversion.is_fresh(|_| Some(b"a".to_vec())));
+    assert!(!conversion.is_fresh(|_| Some(b"changed".to_vec())));
+    assert!(!conversion.is_fresh(|_| None));
+    assert!(conversion.is_cacheable());
+    let missing = CachedConversion {
+      output: String::new(),
+      includes: vec![IncludeFingerprint {
+        path: "/a.adoc".to_string(),
+        hash: None,
+      }],
+      diagnostics: vec![],
+    };
+    assert!(missing.is_fresh(|_| None));
+    assert!(!missing.is_fresh(|_| Some(b"a".to_vec())));
+    let remote = CachedConversion {
+      output: String::new(),
+      includes: vec![IncludeFingerprint {
+        path: "https://example.com/a.adoc".to_string(),
+        hash: Some(content_hash(b"a")),
+      }],
+      diagnostics: vec![],
+    };
+    assert!(!remote.is_cacheable());
+  }
+
+  #[test]
+  fn test_references_clock() {
+    assert!(references_clock("Built {localdate}", true));
+    assert!(references_clock("at {localtime}", false));
+    assert!(!references_clock("Updated {docdate}", true));
+    assert!(references_clock("Updated {docdate}", false));
+    assert!(!references_clock("localdate", false));
+  }
+
+  #[test]
+  fn test_file_cache_roundtrip() {
+    let dir = std::env::temp_dir().join(format!("asciidork-cache-{}", std::process::id()));
+    let mut cache = FileCache::new(&dir);
+    let conversion = CachedConversion {
+      output: "<p>foo</p>\n\n<p>bar</p>".to_string(),
+      includes: vec![
+        IncludeFingerprint {
+          path: "/docs/a b.adoc".to_string(),
+          hash: Some(42),
+        },
+        IncludeFingerprint {
+          path: "/docs/missing.adoc".to_string(),
+          hash: None,
+        },
+      ],
+      diagnostics: vec![
+        CachedDiagnostic {
+          error: false,
+          file: "a b.adoc".to_string(),
+          line_num: 3,
+          line: "see <<nope>> ü".to_string(),
+          message: "Invalid cross reference, no anchor found for `nope`".to_string(),
+          underline_start: 4,
+          underline_width: 8,
+        },
+        CachedDiagnostic {
+          error: true,
+          file: String::new(),
+          line_num: 1,
+          line: "12 34\n".to_string(),
+          message: "x".to_string(),
+          underline_start: 0,
+          underline_width: 1,
+        },
+      ],
+    };
+    assert_eq!(cache.get(key("foo")), None);
+    cache.insert(key("foo"), conversion.clone());
+    assert_eq!(cache.get(key("foo")), Some(conversion));
+    _ = fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn test_file_cache_roundtrip_without_includes() {
+    let dir = std::env::temp_dir().join(format!("asciidork-cache-no-inc-{}", std::process::id()));
+    let mut cache = FileCache::new(&dir);
+    for output in ["<p>foo</p>", "\n<p>foo</p>\n\n", ""] {
+      let conversion = CachedConversion {
+        output: output.to_string(),
+        includes: vec![],
+        diagnostics: vec![],
+      };
+      cache.insert(key(output), conversion.clone());
+      assert_eq!(cache.get(key(output)), Some(conversion));
+    }
+    _ = fs::remove_dir_all(dir);
+  }
+}
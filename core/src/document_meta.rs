@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::internal::*;
 
@@ -13,6 +13,10 @@ pub struct DocumentMeta {
   pub safe_mode: SafeMode,
   pub embedded: bool,
   pub included_files: HashSet<String>,
+  /// generated section ids namespaced by the file they came from
+  pub namespaced_ids: HashSet<String>,
+  /// the namespace of ids generated in each included source, by index
+  pub id_namespaces: HashMap<u16, String>,
 }
 
 impl Default for DocumentMeta {
@@ -27,6 +31,8 @@ impl Default for DocumentMeta {
       authors: Vec::new(),
       embedded: false,
       included_files: HashSet::new(),
+      namespaced_ids: HashSet::new(),
+      id_namespaces: HashMap::new(),
     }
   }
 }
@@ -65,6 +71,8 @@ impl DocumentMeta {
       authors: Vec::new(),
       embedded: false,
       included_files: HashSet::new(),
+      namespaced_ids: HashSet::new(),
+      id_namespaces: HashMap::new(),
     }
   }
 
@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+/// Result of checking a named character reference, like `&copy;`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityLookup {
+  Known(&'static str),
+  Unknown,
+  /// built without the `html-entities` feature, so names can't be checked
+  Unchecked,
+}
+
+/// Looks up an entity name (without the `&` and `;`) in the HTML5 table
+pub fn lookup_named_entity(name: &str) -> EntityLookup {
+  #[cfg(feature = "html-entities")]
+  {
+    match crate::entity_table::NAMED_ENTITIES.binary_search_by(|(n, _)| (*n).cmp(name)) {
+      Ok(idx) => EntityLookup::Known(crate::entity_table::NAMED_ENTITIES[idx].1),
+      Err(_) => EntityLookup::Unknown,
+    }
+  }
+  #[cfg(not(feature = "html-entities"))]
+  {
+    _ = name;
+    EntityLookup::Unchecked
+  }
+}
+
+/// Converts a full entity (e.g. `&amp;`, `&#169;`, `&#x1f600;`) to the
+/// characters it represents, if it is valid and resolvable
+pub fn decode_entity(entity: &str) -> Option<Cow<'static, str>> {
+  let inner = entity.strip_prefix('&')?.strip_suffix(';')?;
+  let code_point = if let Some(hex) = inner.strip_prefix("#x") {
+    u32::from_str_radix(hex, 16).ok()?
+  } else if let Some(decimal) = inner.strip_prefix('#') {
+    decimal.parse::<u32>().ok()?
+  } else {
+    return match lookup_named_entity(inner) {
+      EntityLookup::Known(chars) => Some(Cow::Borrowed(chars)),
+      _ => None,
+    };
+  };
+  char::from_u32(code_point)
+    .filter(|c| *c != '\0')
+    .map(|c| Cow::Owned(c.to_string()))
+}
+
+#[cfg(all(test, feature = "html-entities"))]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_lookup_named_entity() {
+    assert_eq!(lookup_named_entity("copy"), EntityLookup::Known("©"));
+    assert_eq!(lookup_named_entity("AMP"), EntityLookup::Known("&"));
+    assert_eq!(
+      lookup_named_entity("CounterClockwiseContourIntegral"),
+      EntityLookup::Known("∳")
+    );
+    assert_eq!(lookup_named_entity("bogus"), EntityLookup::Unknown);
+    assert_eq!(lookup_named_entity("Copy"), EntityLookup::Unknown);
+  }
+
+  #[test]
+  fn test_decode_entity() {
+    let cases = [
+      ("&amp;", Some("&")),
+      ("&sect;", Some("§")),
+      ("&#169;", Some("©")),
+      ("&#x1f600;", Some("😀")),
+      ("&#0;", None),
+      ("&#xD800;", None),
+      ("&bogus;", None),
+      ("&amp", None),
+    ];
+    for (input, expected) in cases {
+      assert_eq!(decode_entity(input).as_deref(), expected, "input: {input}");
+    }
+  }
+}
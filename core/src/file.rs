@@ -36,6 +36,37 @@ pub fn stem(input: &str) -> &str {
   basename(input).split('.').next().unwrap_or(input)
 }
 
+/// The prefix for section ids generated in the included file at `path`,
+/// relative to the docdir, when the `sectid-namespace` attribute is set,
+/// e.g. `chapters-chapter-2` for `chapters/chapter-2.adoc`
+pub fn id_namespace(path: &str) -> String {
+  let path = path
+    .trim_start_matches("./")
+    .trim_start_matches(['/', '\\']);
+  let path = remove_ext(path);
+  let mut namespace = String::with_capacity(path.len());
+  for c in path.chars() {
+    match c {
+      '-' | '_' => namespace.push(c),
+      c if c.is_alphanumeric() => namespace.extend(c.to_lowercase()),
+      _ => namespace.push('-'),
+    }
+  }
+  namespace
+}
+
+/// A generated section `id` within a namespace from `id_namespace`,
+/// e.g. `chapter-2_intro` for `_intro` in `chapter-2.adoc`
+pub fn namespaced_id(namespace: &str, id: &str) -> String {
+  let mut namespaced = String::with_capacity(namespace.len() + id.len() + 1);
+  namespaced.push_str(namespace);
+  if !id.starts_with('_') {
+    namespaced.push('_');
+  }
+  namespaced.push_str(id);
+  namespaced
+}
+
 pub fn remove_uri_scheme(input: &str) -> &str {
   let mut split = input.splitn(2, "://");
   let first = split.next().unwrap_or("");
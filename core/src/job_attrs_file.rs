@@ -0,0 +1,211 @@
+use crate::internal::*;
+
+/// Format of a file of job attributes, see [`JobAttrs::from_file_src`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobAttrsFileFormat {
+  Json,
+  Toml,
+}
+
+impl JobAttrsFileFormat {
+  /// `.json` files are read as JSON, anything else as TOML
+  pub fn from_path(path: &str) -> Self {
+    if path.to_lowercase().ends_with(".json") {
+      Self::Json
+    } else {
+      Self::Toml
+    }
+  }
+}
+
+impl JobAttrs {
+  /// Parse a flat JSON object or TOML table of attributes. String values
+  /// are readonly unless they end in `@` (like `-a name=value@`), `true`
+  /// sets and `false` unsets an attribute, and numbers become strings.
+  /// Nested tables, arrays and `null` are rejected.
+  pub fn from_file_src(src: &str, format: JobAttrsFileFormat) -> Result<Self, String> {
+    let entries = match format {
+      JobAttrsFileFormat::Json => json_entries(src)?,
+      JobAttrsFileFormat::Toml => toml_entries(src)?,
+    };
+    let mut job_attrs = JobAttrs::empty();
+    for (key, value) in entries {
+      let job_attr = match value {
+        Value::Bool(value) => JobAttr::readonly(value),
+        Value::String(value) if value.ends_with('@') => {
+          JobAttr::modifiable(value.trim_end_matches('@'))
+        }
+        Value::String(value) => JobAttr::readonly(value),
+      };
+      job_attrs.insert(key.to_lowercase(), job_attr)?;
+    }
+    Ok(job_attrs)
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Value {
+  Bool(bool),
+  String(String),
+}
+
+fn json_entries(src: &str) -> Result<Vec<(String, Value)>, String> {
+  let json: serde_json::Value = serde_json::from_str(src).map_err(|err| err.to_string())?;
+  let serde_json::Value::Object(object) = json else {
+    return Err("Expected a JSON object of attributes".to_string());
+  };
+  let mut entries = Vec::with_capacity(object.len());
+  for (key, value) in object {
+    let value = match value {
+      serde_json::Value::Bool(value) => Value::Bool(value),
+      serde_json::Value::String(value) => Value::String(value),
+      serde_json::Value::Number(value) => Value::String(value.to_string()),
+      serde_json::Value::Null | serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+        return Err(unsupported_value(&key));
+      }
+    };
+    entries.push((key, value));
+  }
+  Ok(entries)
+}
+
+fn toml_entries(src: &str) -> Result<Vec<(String, Value)>, String> {
+  let table = src.parse::<toml::Table>().map_err(|err| {
+    let line = err
+      .span()
+      .map_or(1, |span| src[..span.start].lines().count().max(1));
+    format!(
+      "{} on line {}",
+      err.message().trim_end().replace('\n', ", "),
+      line
+    )
+  })?;
+  let mut entries = Vec::with_capacity(table.len());
+  for (key, value) in table {
+    let value = match value {
+      toml::Value::Boolean(value) => Value::Bool(value),
+      toml::Value::String(value) => Value::String(value),
+      toml::Value::Integer(value) => Value::String(value.to_string()),
+      toml::Value::Float(value) => Value::String(value.to_string()),
+      toml::Value::Datetime(value) => Value::String(value.to_string()),
+      toml::Value::Array(_) | toml::Value::Table(_) => return Err(unsupported_value(&key)),
+    };
+    entries.push((key, value));
+  }
+  Ok(entries)
+}
+
+fn unsupported_value(key: &str) -> String {
+  format!("Unsupported value for attr `{key}`, expected a string, boolean, or number")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_job_attrs_from_toml() {
+    let src = r#"
+# shared attributes
+product = "Asciidork"
+version = 1.2
+sectnums = true
+"toc" = false # unset
+"url-repo" = 'https://example.com/\repo'
+release-date = "2024-01-01@"
+"#;
+    let attrs = JobAttrs::from_file_src(src, JobAttrsFileFormat::Toml).unwrap();
+    assert_eq!(attrs.get("product"), Some(&JobAttr::readonly("Asciidork")));
+    assert_eq!(attrs.get("version"), Some(&JobAttr::readonly("1.2")));
+    assert_eq!(attrs.get("sectnums"), Some(&JobAttr::readonly(true)));
+    assert_eq!(attrs.get("toc"), Some(&JobAttr::readonly(false)));
+    assert_eq!(
+      attrs.get("url-repo"),
+      Some(&JobAttr::readonly("https://example.com/\\repo"))
+    );
+    assert_eq!(
+      attrs.get("release-date"),
+      Some(&JobAttr::modifiable("2024-01-01"))
+    );
+  }
+
+  #[test]
+  fn test_job_attrs_from_json() {
+    let src = r#"{
+  "Product": "Asciidork \"dork\"",
+  "sectnums": true,
+  "toc": false,
+  "version": 2,
+  "icons": "font@",
+  "escapes": "\b\f\ud83d\ude00"
+}"#;
+    let attrs = JobAttrs::from_file_src(src, JobAttrsFileFormat::Json).unwrap();
+    assert_eq!(
+      attrs.get("product"),
+      Some(&JobAttr::readonly("Asciidork \"dork\""))
+    );
+    assert_eq!(attrs.get("sectnums"), Some(&JobAttr::readonly(true)));
+    assert_eq!(attrs.get("toc"), Some(&JobAttr::readonly(false)));
+    assert_eq!(attrs.get("version"), Some(&JobAttr::readonly("2")));
+    assert_eq!(attrs.get("icons"), Some(&JobAttr::modifiable("font")));
+    assert_eq!(
+      attrs.get("escapes"),
+      Some(&JobAttr::readonly("\u{8}\u{c}😀"))
+    );
+  }
+
+  #[test]
+  fn test_job_attrs_file_errors() {
+    let unsupported = "Unsupported value for attr `foo`, expected a string, boolean, or number";
+    let cases = [
+      ("[foo]\nbar = 1", JobAttrsFileFormat::Toml, unsupported),
+      ("foo = [1]", JobAttrsFileFormat::Toml, unsupported),
+      (
+        "foo = bar",
+        JobAttrsFileFormat::Toml,
+        "invalid string, expected `\"`, `'` on line 1",
+      ),
+      (
+        "\nfoo = \"bar",
+        JobAttrsFileFormat::Toml,
+        "invalid basic string on line 2",
+      ),
+      ("{\"foo\": {}}", JobAttrsFileFormat::Json, unsupported),
+      ("{\"foo\": null}", JobAttrsFileFormat::Json, unsupported),
+      (
+        "{\"foo\": 1,}",
+        JobAttrsFileFormat::Json,
+        "trailing comma at line 1 column 11",
+      ),
+      (
+        "[1]",
+        JobAttrsFileFormat::Json,
+        "Expected a JSON object of attributes",
+      ),
+      (
+        "{\"showtitle\": \"yes\"}",
+        JobAttrsFileFormat::Json,
+        "Invalid value for attr `showtitle`, expected empty string",
+      ),
+    ];
+    for (src, format, expected) in cases {
+      assert_eq!(
+        JobAttrs::from_file_src(src, format).unwrap_err(),
+        expected,
+        "input: {src:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn test_job_attrs_file_format_from_path() {
+    assert_eq!(
+      JobAttrsFileFormat::from_path("attrs.JSON"),
+      JobAttrsFileFormat::Json
+    );
+    assert_eq!(
+      JobAttrsFileFormat::from_path("attrs.toml"),
+      JobAttrsFileFormat::Toml
+    );
+  }
+}
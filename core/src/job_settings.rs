@@ -7,6 +7,13 @@ pub struct JobSettings {
   pub job_attrs: JobAttrs,
   pub embedded: bool, // TODO: not needed by parser, consider making backend-only
   pub strict: bool,   // TODO: expand to log-level and failure-level
+  pub section_level_policy: SectionLevelPolicy,
+  /// record all source as trivia (blank lines, delimiters, etc.) for round-tripping
+  pub full_fidelity: bool,
+  /// receives progress events while parsing
+  pub on_progress: Option<ProgressHandler>,
+  /// emit XHTML-compliant markup, by setting `htmlsyntax=xml`
+  pub xhtml: bool,
 }
 
 impl JobSettings {
@@ -41,6 +48,48 @@ impl JobSettings {
       ..Default::default()
     }
   }
+
+  pub fn profile(profile: ConversionProfile) -> Self {
+    let mut settings = Self::default();
+    settings.apply_profile(profile);
+    settings
+  }
+
+  /// Applies the settings bundled in `profile`. Its attributes are
+  /// modifiable, so the document can still override them, and any attribute
+  /// already set for the job is left alone.
+  pub fn apply_profile(&mut self, profile: ConversionProfile) {
+    let attrs: &[(&str, AttrValue)] = match profile {
+      ConversionProfile::GithubReadme => {
+        self.embedded = true;
+        &[
+          ("showtitle", AttrValue::Bool(true)),
+          ("idprefix", AttrValue::String(String::new())),
+          ("idseparator", AttrValue::String("-".to_string())),
+        ]
+      }
+      ConversionProfile::StandaloneArticle => {
+        self.embedded = false;
+        &[
+          ("toc", AttrValue::Bool(true)),
+          ("icons", AttrValue::String("font".to_string())),
+        ]
+      }
+      ConversionProfile::EmbeddedFragment => {
+        self.embedded = true;
+        &[
+          ("showtitle", AttrValue::Bool(false)),
+          ("toc", AttrValue::Bool(false)),
+        ]
+      }
+    };
+    for (name, value) in attrs {
+      if self.job_attrs.get(name).is_none() {
+        let attr = JobAttr::modifiable(value.clone());
+        self.job_attrs.insert_unchecked(*name, attr);
+      }
+    }
+  }
 }
 
 impl Default for JobSettings {
@@ -51,6 +100,10 @@ impl Default for JobSettings {
       job_attrs: JobAttrs::default(),
       embedded: false,
       strict: true,
+      section_level_policy: SectionLevelPolicy::default(),
+      full_fidelity: false,
+      on_progress: None,
+      xhtml: false,
     }
   }
 }
@@ -65,6 +118,9 @@ impl From<JobSettings> for DocumentMeta {
       // ensures it can never be overwritten
       job_attrs.insert_unchecked("doctype", JobAttr::readonly(doctype.to_str()));
     }
+    if settings.xhtml {
+      job_attrs.insert_unchecked("htmlsyntax", JobAttr::readonly("xml"));
+    }
     let mut meta = DocumentMeta::new(safe_mode, job_attrs);
     meta.embedded = settings.embedded;
     if let Some(doctype) = doctype {
@@ -73,3 +129,33 @@ impl From<JobSettings> for DocumentMeta {
     meta
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_apply_profile() {
+    let settings = JobSettings::profile(ConversionProfile::GithubReadme);
+    assert!(settings.embedded);
+    assert_eq!(
+      settings.job_attrs.get("idseparator"),
+      Some(&JobAttr::modifiable("-"))
+    );
+
+    let mut settings = JobSettings::embedded();
+    settings
+      .job_attrs
+      .insert_unchecked("toc", JobAttr::readonly("left"));
+    settings.apply_profile(ConversionProfile::StandaloneArticle);
+    assert!(!settings.embedded);
+    assert_eq!(
+      settings.job_attrs.get("toc"),
+      Some(&JobAttr::readonly("left"))
+    );
+    assert_eq!(
+      settings.job_attrs.get("icons"),
+      Some(&JobAttr::modifiable("font"))
+    );
+  }
+}
@@ -1,23 +1,38 @@
 mod attrs;
+mod conversion_cache;
 mod doctype;
 mod document_meta;
+mod entities;
+#[cfg(feature = "html-entities")]
+mod entity_table;
 pub mod file;
 mod job_attrs;
+#[cfg(feature = "attrs-file")]
+mod job_attrs_file;
 mod job_settings;
 mod path;
+mod progress;
+mod trace;
 mod types;
 mod validate;
 
 pub use internal::types::*;
+#[doc(hidden)]
+pub use trace::NoSpan;
 
 mod internal {
   pub(crate) mod types {
     pub use crate::attrs::*;
+    pub use crate::conversion_cache::*;
     pub use crate::doctype::*;
     pub use crate::document_meta::*;
+    pub use crate::entities::*;
     pub use crate::job_attrs::*;
+    #[cfg(feature = "attrs-file")]
+    pub use crate::job_attrs_file::*;
     pub use crate::job_settings::*;
     pub use crate::path::*;
+    pub use crate::progress::*;
     pub use crate::types::*;
   }
   pub use types::*;
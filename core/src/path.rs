@@ -165,6 +165,53 @@ impl Path {
   pub fn is_uri(&self) -> bool {
     matches!(self.components.first(), Some(Component::UriScheme(_)))
   }
+
+  /// Lexically resolves `.` and `..` components, without touching the
+  /// filesystem. `..` can't climb above the root of an absolute path.
+  pub fn normalize(&self) -> Path {
+    let mut components = Vec::with_capacity(self.components.len());
+    for component in &self.components {
+      match component {
+        Component::CurrentDir => {}
+        Component::ParentDir => match components.last() {
+          Some(Component::Normal(_)) => {
+            components.pop();
+          }
+          Some(Component::Root) => {}
+          _ => components.push(Component::ParentDir),
+        },
+        other => components.push(other.clone()),
+      }
+    }
+    Path {
+      separator: self.separator,
+      components,
+    }
+  }
+
+  /// True if `base` is a (lexical) ancestor of, or equal to, this path
+  pub fn starts_with(&self, base: &Path) -> bool {
+    let path = self.normalize();
+    let base = base.normalize();
+    path.components.len() >= base.components.len()
+      && path
+        .components
+        .iter()
+        .zip(&base.components)
+        .all(|(a, b)| a == b)
+  }
+
+  /// This path relative to `base`, or `None` if it's not within `base`
+  pub fn relative_to(&self, base: &Path) -> Option<Path> {
+    if !self.starts_with(base) {
+      return None;
+    }
+    let base_len = base.normalize().components.len();
+    Some(Path {
+      separator: self.separator,
+      components: self.normalize().components.split_off(base_len),
+    })
+  }
 }
 
 impl From<std::path::PathBuf> for Path {
@@ -358,6 +405,45 @@ mod tests {
     assert_eq!("foo.tar", path("foo.tar.gz").file_stem());
   }
 
+  #[test]
+  fn path_normalize() {
+    let cases = [
+      ("/a/./b/../c.adoc", "/a/c.adoc"),
+      ("/docs/../../etc/passwd", "/etc/passwd"),
+      ("a/../../b", "../b"),
+      ("./a/b/..", "a"),
+      (r#"c:\docs\..\win.ini"#, r#"c:\win.ini"#),
+    ];
+    for (input, expected) in cases {
+      assert_eq!(
+        path(input).normalize().to_string(),
+        expected,
+        "input: {input}"
+      );
+    }
+  }
+
+  #[test]
+  fn path_starts_with() {
+    assert!(path("/docs/a.adoc").starts_with(&path("/docs")));
+    assert!(path("/docs/sub/../a.adoc").starts_with(&path("/docs/")));
+    assert!(path("/docs").starts_with(&path("/docs")));
+    assert!(!path("/docs/../etc/passwd").starts_with(&path("/docs")));
+    assert!(!path("/docsx/a.adoc").starts_with(&path("/docs")));
+  }
+
+  #[test]
+  fn path_relative_to() {
+    let rel = |p: &str, base: &str| path(p).relative_to(&path(base)).map(|p| p.to_string());
+    assert_eq!(rel("/docs/a/b.adoc", "/docs"), Some("a/b.adoc".to_string()));
+    assert_eq!(
+      rel("/docs/./a/../b.adoc", "/docs/"),
+      Some("b.adoc".to_string())
+    );
+    assert_eq!(rel("/docs", "/docs"), Some(String::new()));
+    assert_eq!(rel("/etc/passwd", "/docs"), None);
+  }
+
   #[test]
   fn join_uri_relative() {
     let src = Path::new("https://example.com/foo/bar");
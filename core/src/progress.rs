@@ -0,0 +1,49 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// Events emitted while parsing, for rendering progress of long conversions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressEvent {
+  /// an include directive was resolved, and parsing moved into the file
+  EnterInclude { file: String, bytes: usize },
+  /// a block (or section) finished parsing, `bytes_consumed` is
+  /// the position within the primary document
+  BlockParsed {
+    bytes_consumed: usize,
+    total_bytes: usize,
+    blocks_parsed: usize,
+  },
+  /// the whole document finished parsing
+  Finished {
+    total_bytes: usize,
+    blocks_parsed: usize,
+  },
+}
+
+/// Callback receiving [`ProgressEvent`]s, set on [`crate::JobSettings`]
+#[derive(Clone)]
+pub struct ProgressHandler(Arc<dyn Fn(&ProgressEvent) + Send + Sync>);
+
+impl ProgressHandler {
+  pub fn new(handler: impl Fn(&ProgressEvent) + Send + Sync + 'static) -> Self {
+    Self(Arc::new(handler))
+  }
+
+  pub fn emit(&self, event: &ProgressEvent) {
+    (self.0)(event)
+  }
+}
+
+impl fmt::Debug for ProgressHandler {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "ProgressHandler")
+  }
+}
+
+impl PartialEq for ProgressHandler {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for ProgressHandler {}
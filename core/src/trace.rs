@@ -0,0 +1,19 @@
+/// Enters a `tracing` span, returning a guard that exits it when dropped.
+/// The `tracing` feature of the crate invoking the macro decides whether
+/// anything is recorded; without it, the span fields are never evaluated.
+/// That crate must have an optional `tracing` dependency behind a feature
+/// of the same name.
+#[macro_export]
+macro_rules! span {
+  ($level:ident, $name:expr $(, $($fields:tt)*)?) => {{
+    #[cfg(feature = "tracing")]
+    let guard = tracing::span!(tracing::Level::$level, $name $(, $($fields)*)?).entered();
+    #[cfg(not(feature = "tracing"))]
+    let guard = $crate::NoSpan;
+    guard
+  }};
+}
+
+/// Stands in for a span guard when tracing is compiled out
+#[doc(hidden)]
+pub struct NoSpan;
@@ -22,6 +22,58 @@ impl FromStr for SafeMode {
   }
 }
 
+/// How the parser handles a section title whose level skips over
+/// one or more levels, e.g. a `====` directly beneath a `==`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SectionLevelPolicy {
+  /// report an error (or a warning, when not in strict mode)
+  #[default]
+  Strict,
+  /// clamp the level to one below the parent section, with a warning
+  AutoAdjust,
+  /// accept the level as written, without reporting anything
+  Ignore,
+}
+
+impl FromStr for SectionLevelPolicy {
+  type Err = &'static str;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "strict" => Ok(SectionLevelPolicy::Strict),
+      "auto-adjust" => Ok(SectionLevelPolicy::AutoAdjust),
+      "ignore" => Ok(SectionLevelPolicy::Ignore),
+      _ => Err("Invalid section level policy: expected `strict`, `auto-adjust`, or `ignore`"),
+    }
+  }
+}
+
+/// A named bundle of settings for a common conversion use case, applied
+/// with [`JobSettings::apply_profile`](crate::JobSettings::apply_profile)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionProfile {
+  /// embedded output with GitHub-style section ids, for a project README
+  GithubReadme,
+  /// a complete html page with a table of contents and font icons
+  StandaloneArticle,
+  /// embedded output without a title or table of contents, for
+  /// inserting into a page rendered by some other tool
+  EmbeddedFragment,
+}
+
+impl FromStr for ConversionProfile {
+  type Err = &'static str;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "github-readme" => Ok(ConversionProfile::GithubReadme),
+      "standalone-article" => Ok(ConversionProfile::StandaloneArticle),
+      "embedded-fragment" => Ok(ConversionProfile::EmbeddedFragment),
+      _ => Err(
+        "Invalid conversion profile: expected `github-readme`, `standalone-article`, or `embedded-fragment`",
+      ),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum IconMode {
   #[default]
@@ -17,9 +17,12 @@ pub struct AsciidoctorHtml {
   pub(crate) alt_html: String,
   #[allow(clippy::type_complexity)]
   pub(crate) footnotes: Rc<RefCell<Vec<(Option<String>, String)>>>,
+  /// number of footnotes already rendered, when placed at section ends
+  pub(crate) footnotes_rendered: usize,
   pub(crate) doc_meta: DocumentMeta,
   pub(crate) fig_caption_num: usize,
   pub(crate) table_caption_num: usize,
+  pub(crate) example_caption_num: usize,
   pub(crate) list_stack: Vec<bool>,
   pub(crate) default_newlines: Newlines,
   pub(crate) newlines: Newlines,
@@ -29,6 +32,9 @@ pub struct AsciidoctorHtml {
   pub(crate) in_asciidoc_table_cell: bool,
   pub(crate) section_nums: [u16; 5],
   pub(crate) section_num_levels: isize,
+  pub(crate) role_classes: Option<RoleClasses>,
+  pub(crate) data_attrs: Option<DataAttrs>,
+  pub(crate) xhtml: bool,
 }
 
 impl Backend for AsciidoctorHtml {
@@ -51,35 +57,54 @@ impl Backend for AsciidoctorHtml {
     self.doc_meta = document.meta.clone();
     set_backend_attrs::<Self>(&mut self.doc_meta);
     self.section_num_levels = document.meta.isize("sectnumlevels").unwrap_or(3);
+    self.role_classes = RoleClasses::from_meta(&document.meta);
+    self.data_attrs = DataAttrs::from_meta(&document.meta);
+    self.xhtml = document.meta.str("htmlsyntax") == Some("xml");
     if document.meta.is_true("hardbreaks-option") {
-      self.default_newlines = Newlines::JoinWithBreak
+      self.default_newlines = Newlines::JoinWithBreak;
+      self.newlines = Newlines::JoinWithBreak;
     }
 
     if !self.standalone() {
       return;
     }
     self.push_str(r#"<!DOCTYPE html><html"#);
+    if self.xhtml {
+      self.push_str(r#" xmlns="http://www.w3.org/1999/xhtml""#);
+    }
     if !document.meta.is_true("nolang") {
       self.push([r#" lang=""#, document.meta.str_or("lang", "en"), "\""]);
     }
     let encoding = document.meta.str_or("encoding", "UTF-8");
-    self.push([r#"><head><meta charset=""#, encoding, r#"">"#]);
-    self.push_str(r#"<meta http-equiv="X-UA-Compatible" content="IE=edge">"#);
-    self.push_str(r#"<meta name="viewport" content="width=device-width, initial-scale=1.0">"#);
+    let end = self.void_end();
+    self.push([r#"><head><meta charset=""#, encoding, "\"", end]);
+    self.push([
+      r#"<meta http-equiv="X-UA-Compatible" content="IE=edge""#,
+      end,
+    ]);
+    self.push([
+      r#"<meta name="viewport" content="width=device-width, initial-scale=1.0""#,
+      end,
+    ]);
     if !document.meta.is_true("reproducible") {
-      self.push_str(r#"<meta name="generator" content="Asciidork">"#);
+      self.push([r#"<meta name="generator" content="Asciidork""#, end]);
     }
     if let Some(appname) = document.meta.str("app-name") {
-      self.push([r#"<meta name="application-name" content=""#, appname, "\">"]);
+      self.push([
+        r#"<meta name="application-name" content=""#,
+        appname,
+        "\"",
+        end,
+      ]);
     }
     if let Some(desc) = document.meta.str("description") {
-      self.push([r#"<meta name="description" content=""#, desc, "\">"]);
+      self.push([r#"<meta name="description" content=""#, desc, "\"", end]);
     }
     if let Some(keywords) = document.meta.str("keywords") {
-      self.push([r#"<meta name="keywords" content=""#, keywords, "\">"]);
+      self.push([r#"<meta name="keywords" content=""#, keywords, "\"", end]);
     }
     if let Some(copyright) = document.meta.str("copyright") {
-      self.push([r#"<meta name="copyright" content=""#, copyright, "\">"]);
+      self.push([r#"<meta name="copyright" content=""#, copyright, "\"", end]);
     }
     self.render_favicon(&document.meta);
     self.render_authors(document.meta.authors());
@@ -99,8 +124,8 @@ impl Backend for AsciidoctorHtml {
 
   #[instrument(skip_all)]
   fn exit_document(&mut self, _document: &Document) {
-    if !self.footnotes.borrow().is_empty() && !self.in_asciidoc_table_cell {
-      self.render_footnotes();
+    if self.has_unrendered_footnotes() && !self.in_asciidoc_table_cell {
+      self.render_footnotes(r#"<div id="footnotes">"#);
     }
     if self.standalone() {
       self.push_str("</body></html>");
@@ -220,6 +245,7 @@ impl Backend for AsciidoctorHtml {
 
   #[instrument(skip_all)]
   fn exit_preamble(&mut self, _blocks: &[Block]) {
+    self.render_section_footnotes();
     self.push_str("</div></div>");
   }
 
@@ -236,6 +262,7 @@ impl Backend for AsciidoctorHtml {
   #[instrument(skip_all)]
   fn exit_section(&mut self, section: &Section) {
     if section.level == 1 {
+      self.render_section_footnotes();
       self.push_str("</div>");
     }
     self.push_str("</div>");
@@ -250,6 +277,7 @@ impl Backend for AsciidoctorHtml {
     } else {
       self.push(["<h", &level_str, ">"]);
     }
+    self.push_heading_permalink(section.id.as_deref(), true);
     if self.should_number_section(section) {
       let prefix = section::number_prefix(section.level, &mut self.section_nums);
       self.push_str(&prefix);
@@ -258,6 +286,7 @@ impl Backend for AsciidoctorHtml {
 
   #[instrument(skip_all)]
   fn exit_section_heading(&mut self, section: &Section) {
+    self.push_heading_permalink(section.id.as_deref(), false);
     let level_str = num_str!(section.level + 1);
     self.push(["</h", &level_str, ">"]);
     if section.level == 1 {
@@ -298,6 +327,7 @@ impl Backend for AsciidoctorHtml {
   fn enter_sidebar_block(&mut self, block: &Block, _content: &BlockContent) {
     self.open_element("div", &["sidebarblock"], &block.meta.attrs);
     self.push_str(r#"<div class="content">"#);
+    self.render_block_title(&block.meta);
   }
 
   #[instrument(skip_all)]
@@ -308,18 +338,19 @@ impl Backend for AsciidoctorHtml {
   #[instrument(skip_all)]
   fn enter_listing_block(&mut self, block: &Block, _content: &BlockContent) {
     self.open_element("div", &["listingblock"], &block.meta.attrs);
-    self.push_str(r#"<div class="content"><pre"#);
-    if let Some(lang) = self.source_lang(block) {
+    self.render_block_title(&block.meta);
+    self.push_str(r#"<div class="content">"#);
+    let lang = self.source_lang(block);
+    self.open_verbatim_pre(block, lang.is_some());
+    if let Some(lang) = lang {
       self.push([
-        r#" class="highlight"><code class="language-"#,
+        r#"<code class="language-"#,
         &lang,
         r#"" data-lang=""#,
         &lang,
         r#"">"#,
       ]);
       self.state.insert(IsSourceBlock);
-    } else {
-      self.push_ch('>');
     }
     self.newlines = Newlines::Preserve;
   }
@@ -336,7 +367,9 @@ impl Backend for AsciidoctorHtml {
   #[instrument(skip_all)]
   fn enter_literal_block(&mut self, block: &Block, _content: &BlockContent) {
     self.open_element("div", &["literalblock"], &block.meta.attrs);
-    self.push_str(r#"<div class="content"><pre>"#);
+    self.render_block_title(&block.meta);
+    self.push_str(r#"<div class="content">"#);
+    self.open_verbatim_pre(block, false);
     self.newlines = Newlines::Preserve;
   }
 
@@ -347,9 +380,14 @@ impl Backend for AsciidoctorHtml {
   }
 
   #[instrument(skip_all)]
-  fn enter_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.newlines = Newlines::Preserve;
+  }
+
   #[instrument(skip_all)]
-  fn exit_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.newlines = self.default_newlines;
+  }
 
   #[instrument(skip_all)]
   fn enter_quoted_paragraph(&mut self, block: &Block, _attr: &str, _cite: Option<&str>) {
@@ -408,6 +446,16 @@ impl Backend for AsciidoctorHtml {
       self.push_str("</summary>");
     } else {
       self.open_element("div", &["exampleblock"], &block.meta.attrs);
+      let prefix = if block.meta.title.is_none() || self.doc_meta.is_false("example-caption") {
+        None
+      } else {
+        self.example_caption_num += 1;
+        Some(Cow::Owned(format!(
+          "Example {}. ",
+          self.example_caption_num
+        )))
+      };
+      self.render_prefixed_block_title(&block.meta, prefix);
     }
     self.push_str(r#"<div class="content">"#);
   }
@@ -424,6 +472,7 @@ impl Backend for AsciidoctorHtml {
   #[instrument(skip_all)]
   fn enter_open_block(&mut self, block: &Block, _content: &BlockContent) {
     self.open_element("div", &["openblock"], &block.meta.attrs);
+    self.render_block_title(&block.meta);
     self.push_str(r#"<div class="content">"#);
   }
 
@@ -441,15 +490,14 @@ impl Backend for AsciidoctorHtml {
       self.push(["<h", &level_str]);
     }
     self.push_str(r#" class="discrete"#);
-    for role in block.meta.attrs.roles() {
-      self.push_ch(' ');
-      self.push_str(role);
-    }
+    self.push_roles(block.meta.attrs.roles());
     self.push_str("\">");
+    self.push_heading_permalink(id, true);
   }
 
   #[instrument(skip_all)]
-  fn exit_discrete_heading(&mut self, level: u8, _id: Option<&str>, _block: &Block) {
+  fn exit_discrete_heading(&mut self, level: u8, id: Option<&str>, _block: &Block) {
+    self.push_heading_permalink(id, false);
     self.push(["</h", &num_str!(level + 1), ">"]);
   }
 
@@ -475,6 +523,9 @@ impl Backend for AsciidoctorHtml {
       div.push_class("checklist");
       ul.push_class("checklist");
     }
+    if block.meta.attrs.has_option("compact") {
+      ul.push_class("compact");
+    }
     self.push_open_tag(div);
     self.render_block_title(&block.meta);
     self.push_open_tag(ul);
@@ -506,7 +557,11 @@ impl Backend for AsciidoctorHtml {
   fn enter_description_list(&mut self, block: &Block, _items: &[ListItem], _depth: u8) {
     self.open_element("div", &["dlist"], &block.meta.attrs);
     self.render_block_title(&block.meta);
-    self.push_str("<dl>");
+    if block.meta.attrs.has_option("compact") {
+      self.push_str(r#"<dl class="compact">"#);
+    } else {
+      self.push_str("<dl>");
+    }
   }
 
   #[instrument(skip_all)]
@@ -535,8 +590,11 @@ impl Backend for AsciidoctorHtml {
   }
 
   #[instrument(skip_all)]
-  fn enter_description_list_description_text(&mut self, _text: &Block, _item: &ListItem) {
-    self.state.insert(VisitingSimpleTermDescription);
+  fn enter_description_list_description_text(&mut self, text: &Block, _item: &ListItem) {
+    // attached compound blocks (e.g. an open block) keep their wrappers
+    if text.context == BlockContext::Paragraph {
+      self.state.insert(VisitingSimpleTermDescription);
+    }
   }
 
   #[instrument(skip_all)]
@@ -554,16 +612,21 @@ impl Backend for AsciidoctorHtml {
   fn enter_ordered_list(&mut self, block: &Block, items: &[ListItem], depth: u8) {
     self.list_stack.push(false);
     let custom = block.meta.attrs.ordered_list_custom_number_style();
-    let list_type = custom
-      .and_then(list_type_from_class)
-      .unwrap_or_else(|| list_type_from_depth(depth));
+    let list_type = match custom {
+      Some(class) => list_type_from_class(class),
+      None => Some(list_type_from_depth(depth)),
+    };
     let class = custom.unwrap_or_else(|| list_class_from_depth(depth));
     let classes = &["olist", class];
     self.open_element("div", classes, &block.meta.attrs);
     self.render_block_title(&block.meta);
-    self.push([r#"<ol class=""#, class, "\""]);
+    self.push([r#"<ol class=""#, class]);
+    if block.meta.attrs.has_option("compact") {
+      self.push_str(" compact");
+    }
+    self.push_str("\"");
 
-    if list_type != "1" {
+    if let Some(list_type) = list_type.filter(|t| *t != "1") {
       self.push([" type=\"", list_type, "\""]);
     }
 
@@ -674,7 +737,7 @@ impl Backend for AsciidoctorHtml {
           }
         }
       }
-      self.push_ch('>');
+      self.push_str(self.void_end());
     }
     self.push_str("</colgroup>");
   }
@@ -683,18 +746,28 @@ impl Backend for AsciidoctorHtml {
     self.push_str("</table>");
   }
 
-  fn asciidoc_table_cell_backend(&mut self) -> Self {
-    Self {
-      in_asciidoc_table_cell: true,
-      footnotes: Rc::clone(&self.footnotes),
-      ..Self::default()
+  fn nested_backend(&mut self, context: NestedContext) -> Self {
+    match context {
+      // the cell is its own document, setting up its own meta on enter
+      NestedContext::AsciidocTableCell => Self {
+        in_asciidoc_table_cell: true,
+        footnotes: Rc::clone(&self.footnotes),
+        ..Self::default()
+      },
+      NestedContext::FootnoteText => Self {
+        footnotes: Rc::clone(&self.footnotes),
+        ..self.inline_backend()
+      },
+      NestedContext::TocEntry => Self {
+        footnotes: Rc::new(RefCell::new(self.footnotes.borrow().clone())),
+        ..self.inline_backend()
+      },
     }
   }
 
   #[instrument(skip_all)]
-  fn visit_asciidoc_table_cell_result(&mut self, cell_backend: Self) {
-    self.in_asciidoc_table_cell = false;
-    self.html.push_str(&cell_backend.into_result().unwrap());
+  fn visit_nested_result(&mut self, nested: Self, _context: NestedContext) {
+    self.html.push_str(&nested.into_result().unwrap());
   }
 
   #[instrument(skip_all)]
@@ -758,6 +831,10 @@ impl Backend for AsciidoctorHtml {
   #[instrument(skip_all)]
   fn visit_thematic_break(&mut self, block: &Block) {
     self.open_element("hr", &[], &block.meta.attrs);
+    if self.xhtml {
+      self.html.pop();
+      self.push_str("/>");
+    }
   }
 
   #[instrument(skip_all)]
@@ -767,14 +844,18 @@ impl Backend for AsciidoctorHtml {
 
   #[instrument(skip_all)]
   fn visit_inline_text(&mut self, text: &str) {
-    self.push_str(text);
+    if self.xhtml {
+      self.push_str(&xhtml_compliant(text));
+    } else {
+      self.push_str(text);
+    }
   }
 
   #[instrument(skip_all)]
   fn visit_joining_newline(&mut self) {
     match self.newlines {
       Newlines::JoinWithSpace => self.push_ch(' '),
-      Newlines::JoinWithBreak => self.push_str("<br> "),
+      Newlines::JoinWithBreak => self.push_br(),
       Newlines::Preserve => self.push_str("\n"),
     }
   }
@@ -790,15 +871,35 @@ impl Backend for AsciidoctorHtml {
   }
 
   #[instrument(skip_all)]
-  fn enter_xref(&mut self, target: &str, _reftext: Option<&[InlineNode]>, kind: XrefKind) {
+  fn enter_xref(
+    &mut self,
+    target: &str,
+    _reftext: Option<&[InlineNode]>,
+    kind: XrefKind,
+    attrs: Option<&AttrList>,
+    anchor: Option<&Anchor>,
+  ) {
     self.xref_depth += 1;
-    if self.xref_depth == 1 {
-      self.push([
-        "<a href=\"",
-        &utils::xref::href(target, &self.doc_meta, kind, true),
-        "\">",
-      ]);
+    if self.xref_depth != 1 {
+      return;
     }
+    let href = match anchor {
+      Some(anchor) if anchor.is_footnote => {
+        format!(
+          "#_footnote_{}",
+          utils::xref::resolve_id(target, &self.doc_meta)
+        )
+      }
+      _ => utils::xref::href(target, &self.doc_meta, kind, true),
+    };
+    let Some(attrs) = attrs else {
+      self.push(["<a href=\"", &href, "\">"]);
+      return;
+    };
+    let mut tag = OpenTag::new("a", attrs);
+    tag.push_html_attr("href", &href);
+    tag.push_link_constraint_attrs(attrs, true, false);
+    self.push_open_tag(tag);
   }
 
   #[instrument(skip_all)]
@@ -876,7 +977,7 @@ impl Backend for AsciidoctorHtml {
 
   #[instrument(skip_all)]
   fn visit_linebreak(&mut self) {
-    self.push_str("<br> ");
+    self.push_br();
   }
 
   #[instrument(skip_all)]
@@ -909,17 +1010,20 @@ impl Backend for AsciidoctorHtml {
     self.push([r#"<b class="button">"#, text, "</b>"])
   }
 
+  #[instrument(skip_all)]
+  fn visit_stem_macro(&mut self, text: &str, notation: StemNotation) {
+    self.push_inline_stem(text, notation);
+  }
+
   #[instrument(skip_all)]
   fn visit_icon_macro(&mut self, target: &str, attrs: &AttrList) {
     self.push_str(r#"<span class="icon"#);
-    attrs.roles.iter().for_each(|role| {
-      self.push_str(" ");
-      self.push_str(role);
-    });
+    self.push_roles(attrs.roles.iter());
     self.push_str(r#"">"#);
     let has_link = if let Some(link) = attrs.named("link") {
       self.push_str(r#"<a class="image""#);
-      self.push_html_attr("href", link);
+      let href = encode_href(link, HrefEncoding::from_meta(&self.doc_meta));
+      self.push_html_attr("href", &href);
       if let Some(window) = attrs.named("window") {
         self.push_html_attr("target", window);
       }
@@ -945,7 +1049,7 @@ impl Backend for AsciidoctorHtml {
         if let Some(title) = attrs.named("title") {
           self.push([r#"" title=""#, title]);
         }
-        self.push_str(r#"">"#);
+        self.push(["\"", self.void_end()]);
       }
       IconMode::Font => {
         self.push_str(r#"<i class="fa fa-"#);
@@ -977,7 +1081,7 @@ impl Backend for AsciidoctorHtml {
     open_tag.push_class("image");
     open_tag.push_opt_class(attrs.named("float"));
     open_tag.push_opt_prefixed_class(attrs.named("align"), Some("text-"));
-    open_tag.push_classes(attrs.roles.iter());
+    open_tag.push_classes(attrs.roles.iter().map(|role| self.role_class(role)));
     self.push_open_tag(open_tag);
 
     let with_link = if let Some(link_href) = attrs.named("link") {
@@ -987,11 +1091,12 @@ impl Backend for AsciidoctorHtml {
       if link_href == "self" {
         push_img_path(a_tag.htmlbuf(), target, &self.doc_meta);
       } else {
-        a_tag.push_str_attr_escaped(link_href);
+        let encoding = HrefEncoding::from_meta(&self.doc_meta);
+        a_tag.push_str_attr_escaped(&encode_href(link_href, encoding));
       }
       a_tag.push_ch('"');
       a_tag.opened_classes = false;
-      a_tag.push_link_attrs(attrs, true, false);
+      a_tag.push_link_constraint_attrs(attrs, true, false);
       self.push_open_tag(a_tag);
       true
     } else {
@@ -1043,9 +1148,25 @@ impl Backend for AsciidoctorHtml {
     if matches!(scheme, Some(UrlScheme::Mailto)) {
       tag.push_str("mailto:");
     }
-    tag.push_str(target);
+    tag.push_str(&encode_href(
+      target,
+      HrefEncoding::from_meta(&self.doc_meta),
+    ));
     tag.push_ch('"');
 
+    let author_constrained = blank_window_shorthand
+      || attrs.is_some_and(|attrs| {
+        attrs.named("window").is_some()
+          || attrs.has_option("nofollow")
+          || attrs.has_option("noopener")
+      });
+    if !author_constrained
+      && self.doc_meta.is_true("external-links-blank")
+      && is_external_link(target, self.doc_meta.str("site-url"))
+    {
+      tag.push_str(r#" target="_blank" rel="nofollow noopener""#);
+    }
+
     if let Some(attrs) = attrs {
       tag.push_link_attrs(attrs, has_link_text, blank_window_shorthand);
     }
@@ -1184,7 +1305,9 @@ impl Backend for AsciidoctorHtml {
 
   #[instrument(skip_all)]
   fn visit_inline_lit_mono(&mut self, text: &str) {
-    self.push(["<code>", text, "</code>"]);
+    self.push_str("<code>");
+    self.push_str_escaped(text);
+    self.push_str("</code>");
   }
 
   #[instrument(skip_all)]
@@ -1235,7 +1358,8 @@ impl Backend for AsciidoctorHtml {
       .named("link")
       .or_else(|| img_attrs.named("link"))
     {
-      self.push([r#"<a class="image" href=""#, *href, r#"">"#]);
+      let href = encode_href(href, HrefEncoding::from_meta(&self.doc_meta));
+      self.push([r#"<a class="image" href=""#, &href, r#"">"#]);
       has_link = true;
     }
     self.render_image(img_target, img_attrs);
@@ -1344,16 +1468,52 @@ impl AsciidoctorHtml {
     self.html
   }
 
+  // a backend for rendering inline content with the current document's
+  // attributes, but none of the block-level state
+  fn inline_backend(&self) -> Self {
+    Self {
+      doc_meta: self.doc_meta.clone(),
+      role_classes: self.role_classes.clone(),
+      data_attrs: self.data_attrs.clone(),
+      xhtml: self.xhtml,
+      default_newlines: self.default_newlines,
+      newlines: self.newlines,
+      ..Self::default()
+    }
+  }
+
   pub(crate) fn push_buffered(&mut self) {
     let mut buffer = String::new();
     mem::swap(&mut buffer, &mut self.alt_html);
     self.push_str(&buffer);
   }
 
-  pub(crate) fn push_open_tag(&mut self, tag: OpenTag) {
+  pub(crate) fn push_open_tag(&mut self, mut tag: OpenTag) {
+    if let Some(role_classes) = &self.role_classes {
+      tag.map_roles(role_classes);
+    }
+    tag.retain_data_attrs(self.data_attrs.as_ref());
     self.push_str(&tag.finish());
   }
 
+  /// pushes each role, preceded by a space, as an html class
+  pub(crate) fn push_roles(&mut self, roles: impl Iterator<Item = impl AsRef<str>>) {
+    for role in roles {
+      self.html.push(' ');
+      match &self.role_classes {
+        Some(role_classes) => role_classes.push_class(role.as_ref(), &mut self.html),
+        None => self.html.push_str(role.as_ref()),
+      }
+    }
+  }
+
+  pub(crate) fn role_class<'a>(&'a self, role: &'a str) -> Cow<'a, str> {
+    match &self.role_classes {
+      Some(role_classes) => role_classes.class(role),
+      None => Cow::Borrowed(role),
+    }
+  }
+
   fn source_lang<'a>(&self, block: &'a Block) -> Option<Cow<'a, str>> {
     match (
       block.meta.attrs.str_positional_at(0),
@@ -1392,28 +1552,74 @@ impl AsciidoctorHtml {
     self.push_open_tag(open_tag);
   }
 
-  fn render_footnotes(&mut self) {
-    self.push_str(r#"<div id="footnotes"><hr>"#);
+  fn has_unrendered_footnotes(&self) -> bool {
+    self.footnotes.borrow().len() > self.footnotes_rendered
+  }
+
+  /// with `:footnote-placement: section`, footnotes are rendered at the end
+  /// of the preamble and each top-level section, instead of the document
+  fn render_section_footnotes(&mut self) {
+    if self.doc_meta.str("footnote-placement") == Some("section")
+      && self.has_unrendered_footnotes()
+      && !self.in_asciidoc_table_cell
+    {
+      self.render_footnotes(r#"<div class="footnotes">"#);
+    }
+  }
+
+  fn render_footnotes(&mut self, open_tag: &str) {
+    self.push([open_tag, "<hr", self.void_end()]);
     let footnotes = mem::take(&mut self.footnotes);
-    for (i, (_, footnote)) in footnotes.borrow().iter().enumerate() {
+    let skip = self.footnotes_rendered;
+    for (i, (_, footnote)) in footnotes.borrow().iter().enumerate().skip(skip) {
       let num = (i + 1).to_string();
       self.push_str(r#"<div class="footnote" id="_footnotedef_"#);
       self.push([&num, r##""><a href="#_footnoteref_"##, &num, "\">"]);
       self.push([&num, "</a>. ", footnote, "</div>"]);
     }
     self.push_str(r#"</div>"#);
+    self.footnotes_rendered = footnotes.borrow().len();
     self.footnotes = footnotes;
   }
 
+  /// long lines wrap unless the block has `%nowrap` or the document unsets
+  /// `prewrap`, in which case the pre gets the `nowrap-class` (or `nowrap`)
+  fn open_verbatim_pre(&mut self, block: &Block, highlight: bool) {
+    let nowrap = block.meta.attrs.has_option("nowrap") || self.doc_meta.is_false("prewrap");
+    let nowrap_class = self.doc_meta.string_or("nowrap-class", "nowrap");
+    match (highlight, nowrap) {
+      (true, true) => {
+        self.push_str(r#"<pre class="highlight "#);
+        self.push_str_attr_escaped(&nowrap_class);
+        self.push_ch('"');
+      }
+      (true, false) => self.push_str(r#"<pre class="highlight""#),
+      (false, true) => {
+        self.push_str("<pre");
+        self.push_html_attr("class", &nowrap_class);
+      }
+      (false, false) => self.push_str("<pre"),
+    }
+    if let Some(max_height) = block.meta.attrs.named("max-height") {
+      self.push_str(r#" style="max-height: "#);
+      self.push_str_attr_escaped(max_height);
+      self.push_str(r#"; overflow-y: auto;""#);
+    }
+    self.push_ch('>');
+  }
+
   fn render_favicon(&mut self, meta: &DocumentMeta) {
     match meta.get("favicon") {
       Some(AttrValue::String(path)) => {
         let ext = helpers::file_ext(path).unwrap_or("ico");
         self.push_str(r#"<link rel="icon" type="image/"#);
-        self.push([ext, r#"" href=""#, path, "\">"]);
+        self.push([ext, r#"" href=""#, path, "\"", self.void_end()]);
       }
       Some(AttrValue::Bool(true)) => {
-        self.push_str(r#"<link rel="icon" type="image/x-icon" href="favicon.ico">"#);
+        self.push([
+          r#"<link rel="icon" type="image/x-icon" href="favicon.ico""#,
+          self.void_end(),
+        ]);
       }
       _ => {}
     }
@@ -1431,17 +1637,15 @@ impl AsciidoctorHtml {
       // TODO: escape/sanitize, w/ tests, see asciidoctor
       self.push_str(&author.fullname());
     }
-    self.push_str(r#"">"#);
+    self.push(["\"", self.void_end()]);
   }
 
   fn render_title(&mut self, document: &Document, attrs: &DocumentMeta) {
     self.push_str(r#"<title>"#);
     if let Some(title) = attrs.str("title") {
-      self.push_str(title);
+      self.push_str_attr_escaped(title);
     } else if let Some(title) = document.title.as_ref() {
-      for s in title.main.plain_text() {
-        self.push_str(s);
-      }
+      self.push_str_attr_escaped(&title.main.to_plain_text());
     } else {
       self.push_str("Untitled");
     }
@@ -1463,7 +1667,7 @@ impl AsciidoctorHtml {
       self.push_str(r#"<div class="attribution">&#8212; "#);
       self.push_str(attribution);
       if let Some(cite) = cite {
-        self.push_str(r#"<br><cite>"#);
+        self.push(["<br", self.void_end(), "<cite>"]);
         self.push([cite, "</cite>"]);
       }
       self.push_str("</div>");
@@ -1479,8 +1683,14 @@ impl AsciidoctorHtml {
       match (self.list_stack.last() == Some(&true), checked) {
         (false, true) => self.push_str("&#10003;"),
         (false, false) => self.push_str("&#10063;"),
-        (true, true) => self.push_str(r#"<input type="checkbox" data-item-complete="1" checked>"#),
-        (true, false) => self.push_str(r#"<input type="checkbox" data-item-complete="0">"#),
+        (true, true) => self.push([
+          r#"<input type="checkbox" data-item-complete="1" checked"#,
+          if self.xhtml { r#"="checked"/>"# } else { ">" },
+        ]),
+        (true, false) => self.push([
+          r#"<input type="checkbox" data-item-complete="0""#,
+          self.void_end(),
+        ]),
       }
     }
   }
@@ -1513,14 +1723,14 @@ impl AsciidoctorHtml {
   fn push_admonition_img(&mut self, kind: AdmonitionKind) {
     self.push_str(r#"<img src=""#);
     self.push_icon_uri(kind.lowercase_str(), None);
-    self.push([r#"" alt=""#, kind.str(), r#"">"#]);
+    self.push([r#"" alt=""#, kind.str(), "\"", self.void_end()]);
   }
 
   fn push_callout_number_img(&mut self, num: u8) {
     let n_str = &num_str!(num);
     self.push_str(r#"<img src=""#);
     self.push_icon_uri(n_str, Some("callouts/"));
-    self.push([r#"" alt=""#, n_str, r#"">"#]);
+    self.push([r#"" alt=""#, n_str, "\"", self.void_end()]);
   }
 
   fn push_callout_number_font(&mut self, num: u8) {
@@ -1543,7 +1753,8 @@ impl AsciidoctorHtml {
       }
       buffer.push_str(r#"" class="author">"#);
       buffer.push_str(&author.fullname());
-      buffer.push_str(r#"</span><br>"#);
+      buffer.push_str("</span><br");
+      buffer.push_str(self.void_end());
       if let Some(email) = &author.email {
         buffer.push_str(r#"<span id="email"#);
         if idx > 0 {
@@ -1553,12 +1764,26 @@ impl AsciidoctorHtml {
         buffer.push_str(email);
         buffer.push_str(r#"">"#);
         buffer.push_str(email);
-        buffer.push_str(r#"</a></span><br>"#);
+        buffer.push_str("</a></span><br");
+        buffer.push_str(self.void_end());
       }
     }
     self.push([&buffer, "</div>"]);
   }
 
+  /// end of a void element's start tag, self-closing for XHTML
+  const fn void_end(&self) -> &'static str {
+    if self.xhtml {
+      "/>"
+    } else {
+      ">"
+    }
+  }
+
+  fn push_br(&mut self) {
+    self.push(["<br", self.void_end(), " "]);
+  }
+
   fn standalone(&self) -> bool {
     self.doc_meta.get_doctype() != DocType::Inline
       && !self.in_asciidoc_table_cell
@@ -1588,7 +1813,7 @@ impl AsciidoctorHtml {
       push_img_path(&mut self.html, fallback, &self.doc_meta);
       self.push_ch('"');
       self.push_named_or_pos_attr("alt", 0, attrs);
-      self.push_ch('>');
+      self.push_str(self.void_end());
     } else if let Some(alt) = attrs.named("alt").or_else(|| attrs.str_positional_at(0)) {
       self.push([r#"<span class="alt">"#, alt, "</span>"]);
     }
@@ -1607,9 +1832,7 @@ impl AsciidoctorHtml {
     if let Some(alt) = attrs.named("alt").or_else(|| attrs.str_positional_at(0)) {
       self.push_str_attr_escaped(alt);
     } else if let Some(Some(nodes)) = attrs.positional.first() {
-      for s in nodes.plain_text() {
-        self.push_str_attr_escaped(s);
-      }
+      self.push_str_attr_escaped(&nodes.to_plain_text());
     } else {
       let alt = file::stem(target).replace(['-', '_'], " ");
       self.push_str_attr_escaped(&alt);
@@ -1618,7 +1841,11 @@ impl AsciidoctorHtml {
     self.push_named_or_pos_attr("width", 1, attrs);
     self.push_named_or_pos_attr("height", 2, attrs);
     self.push_named_attr("title", attrs);
-    self.push_ch('>');
+    if self.doc_meta.is_true("image-srcset") {
+      self.push_named_attr("srcset", attrs);
+      self.push_named_attr("sizes", attrs);
+    }
+    self.push_str(self.void_end());
   }
 }
 
@@ -0,0 +1,41 @@
+use crate::internal::*;
+
+/// Allowlist of custom `data-*` attributes to pass through from attribute
+/// lists to html, configured by the `data-attrs` attribute, a comma-separated
+/// list of names with or without the `data-` prefix. A trailing `*` allows
+/// every name starting with what precedes it (e.g. `track-*`). Named
+/// attributes not allowed are kept in the AST, but never rendered.
+#[derive(Debug, Clone, Default)]
+pub struct DataAttrs {
+  names: Vec<String>,
+  prefixes: Vec<String>,
+}
+
+impl DataAttrs {
+  pub fn from_meta(meta: &DocumentMeta) -> Option<Self> {
+    let mut allowed = Self::default();
+    for name in meta.str("data-attrs")?.split(',') {
+      let name = name.trim();
+      let name = name.strip_prefix("data-").unwrap_or(name);
+      match name.strip_suffix('*') {
+        Some(prefix) => allowed.prefixes.push(prefix.to_string()),
+        None if !name.is_empty() => allowed.names.push(name.to_string()),
+        None => {}
+      }
+    }
+    if allowed.names.is_empty() && allowed.prefixes.is_empty() {
+      None
+    } else {
+      Some(allowed)
+    }
+  }
+
+  /// `name` is the attribute name, without the `data-` prefix
+  pub fn allows(&self, name: &str) -> bool {
+    self.names.iter().any(|allowed| allowed == name)
+      || self
+        .prefixes
+        .iter()
+        .any(|prefix| name.starts_with(prefix.as_str()))
+  }
+}
@@ -0,0 +1,161 @@
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+use crate::internal::*;
+
+/// How link and image targets are written into `href` and `src`
+/// attributes, configured by the `href-encoding` attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HrefEncoding {
+  /// match Asciidoctor: link targets are written as authored,
+  /// only spaces in image paths are percent-encoded
+  #[default]
+  Asciidoctor,
+  /// percent-encode spaces in link targets as well
+  Spaces,
+  /// NFC-normalize, then percent-encode non-ASCII characters, and ASCII
+  /// characters in the WHATWG fragment percent-encode set (space, `"`,
+  /// `<`, `>`, `` ` `` and controls). Existing escapes are left alone.
+  Whatwg,
+}
+
+impl HrefEncoding {
+  pub fn from_meta(meta: &DocumentMeta) -> Self {
+    match meta.str("href-encoding") {
+      Some("whatwg") => HrefEncoding::Whatwg,
+      Some("spaces") => HrefEncoding::Spaces,
+      _ => HrefEncoding::Asciidoctor,
+    }
+  }
+
+  /// image paths always have their spaces encoded
+  pub const fn for_img_path(self) -> Self {
+    match self {
+      HrefEncoding::Whatwg => HrefEncoding::Whatwg,
+      HrefEncoding::Asciidoctor | HrefEncoding::Spaces => HrefEncoding::Spaces,
+    }
+  }
+}
+
+pub fn encode_href(target: &str, encoding: HrefEncoding) -> Cow<'_, str> {
+  match encoding {
+    HrefEncoding::Asciidoctor => Cow::Borrowed(target),
+    HrefEncoding::Spaces if !target.contains(' ') => Cow::Borrowed(target),
+    HrefEncoding::Spaces => Cow::Owned(target.replace(' ', "%20")),
+    HrefEncoding::Whatwg if !target.chars().any(needs_encoding) => Cow::Borrowed(target),
+    HrefEncoding::Whatwg => {
+      let mut encoded = String::with_capacity(target.len() + 16);
+      if is_nfc_quick(target.chars()) == IsNormalized::Yes {
+        push_percent_encoded(&mut encoded, target.chars());
+      } else {
+        push_percent_encoded(&mut encoded, target.nfc());
+      }
+      Cow::Owned(encoded)
+    }
+  }
+}
+
+/// A link target is external when it is absolute and its scheme or host
+/// differ from those of the `site-url` attribute, if set.
+pub fn is_external_link(target: &str, site_url: Option<&str>) -> bool {
+  let Some(target_origin) = origin(target) else {
+    return false;
+  };
+  match site_url.and_then(origin) {
+    Some((scheme, host)) => {
+      !target_origin.0.eq_ignore_ascii_case(scheme) || !target_origin.1.eq_ignore_ascii_case(host)
+    }
+    None => true,
+  }
+}
+
+/// the scheme and host (with port) of an absolute url
+fn origin(url: &str) -> Option<(&str, &str)> {
+  let (scheme, rest) = url.split_once("://")?;
+  if scheme.is_empty()
+    || !scheme
+      .bytes()
+      .all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(&b))
+  {
+    return None;
+  }
+  let authority = &rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())];
+  let host = authority
+    .rsplit_once('@')
+    .map_or(authority, |(_, host)| host);
+  Some((scheme, host))
+}
+
+const fn needs_encoding(c: char) -> bool {
+  !c.is_ascii() || c.is_ascii_control() || matches!(c, ' ' | '"' | '<' | '>' | '`')
+}
+
+fn push_percent_encoded(buf: &mut String, chars: impl Iterator<Item = char>) {
+  let mut utf8 = [0; 4];
+  for c in chars {
+    if needs_encoding(c) {
+      for byte in c.encode_utf8(&mut utf8).bytes() {
+        buf.push_str(&format!("%{:02X}", byte));
+      }
+    } else {
+      buf.push(c);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_encode_href() {
+    let cases = [
+      ("https://example.com/a?b=c#d", "https://example.com/a?b=c#d"),
+      ("docs/my file.pdf", "docs/my%20file.pdf"),
+      ("docs/my%20file.pdf", "docs/my%20file.pdf"),
+      (
+        "https://例え.jp/パス",
+        "https://%E4%BE%8B%E3%81%88.jp/%E3%83%91%E3%82%B9",
+      ),
+      // decomposed `é` (e + combining acute) is composed before encoding
+      ("cafe\u{301}.html", "caf%C3%A9.html"),
+      ("caf\u{e9}.html", "caf%C3%A9.html"),
+      ("a\"b<c>", "a%22b%3Cc%3E"),
+    ];
+    for (input, expected) in cases {
+      assert_eq!(
+        encode_href(input, HrefEncoding::Whatwg),
+        expected,
+        "input: {input}"
+      );
+    }
+    assert_eq!(encode_href("a b/é", HrefEncoding::Spaces), "a%20b/é");
+    assert_eq!(encode_href("a b/é", HrefEncoding::Asciidoctor), "a b/é");
+  }
+
+  #[test]
+  fn test_is_external_link() {
+    let site = Some("https://example.com/docs");
+    let cases = [
+      ("https://example.com/other", site, false),
+      ("HTTPS://Example.COM", site, false),
+      ("http://example.com/other", site, true),
+      ("https://sub.example.com", site, true),
+      (
+        "https://user@example.com:8080/",
+        Some("https://example.com:8080"),
+        false,
+      ),
+      ("https://other.org?q=example.com", site, true),
+      ("page.html", site, false),
+      ("page.html", None, false),
+      ("https://other.org", None, true),
+    ];
+    for (target, site_url, expected) in cases {
+      assert_eq!(
+        is_external_link(target, site_url),
+        expected,
+        "target: {target}, site-url: {site_url:?}"
+      );
+    }
+  }
+}
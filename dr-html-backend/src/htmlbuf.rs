@@ -16,9 +16,16 @@ pub trait HtmlBuf {
     }
   }
 
-  #[allow(unused)]
-  fn push_url_encoded(&mut self, s: &str) {
-    push_url_encoded(self.htmlbuf(), s);
+  /// escapes the html special chars in text content, leaving quotes as is
+  fn push_str_escaped(&mut self, s: &str) {
+    for c in s.chars() {
+      match c {
+        '&' => self.htmlbuf().push_str("&amp;"),
+        '<' => self.htmlbuf().push_str("&lt;"),
+        '>' => self.htmlbuf().push_str("&gt;"),
+        _ => self.htmlbuf().push(c),
+      }
+    }
   }
 
   fn push_str(&mut self, s: &str) {
@@ -55,20 +62,12 @@ pub trait HtmlBuf {
 }
 
 pub fn push_img_path(buf: &mut String, target: &str, doc_meta: &DocumentMeta) {
+  let encoding = HrefEncoding::from_meta(doc_meta).for_img_path();
   if let Some(imagesdir) = doc_meta.str("imagesdir") {
     let mut path = Path::new_specifying_separator(imagesdir, '/');
     path.push(target);
-    push_url_encoded(buf, &path.to_string());
+    buf.push_str(&encode_href(&path.to_string(), encoding));
   } else {
-    push_url_encoded(buf, target);
-  }
-}
-
-fn push_url_encoded(buf: &mut String, s: &str) {
-  for c in s.chars() {
-    match c {
-      ' ' => buf.push_str("%20"),
-      _ => buf.push(c),
-    }
+    buf.push_str(&encode_href(target, encoding));
   }
 }
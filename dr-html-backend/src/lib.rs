@@ -5,13 +5,25 @@ extern crate asciidork_backend as backend;
 extern crate asciidork_eval as eval;
 
 mod asciidoctor_html;
+mod data_attrs;
+mod href;
 mod htmlbuf;
+#[cfg(feature = "minify")]
+mod minify;
 mod open_tag;
+mod role_classes;
 pub mod section;
+mod stem;
 mod table;
+mod xhtml;
 
 pub use asciidoctor_html::AsciidoctorHtml;
 pub use backend::Backend;
+#[cfg(feature = "minify")]
+pub use minify::minify;
+
+/// Included in conversion cache keys, so upgrading invalidates old output
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub fn convert(document: ast::Document) -> Result<String, Box<dyn Error>> {
   Ok(eval::eval(&document, AsciidoctorHtml::new())?)
@@ -25,9 +37,13 @@ mod internal {
   pub use lazy_static::lazy_static;
   pub use regex::Regex;
 
+  pub use crate::data_attrs::*;
+  pub use crate::href::*;
   pub use crate::htmlbuf::*;
   pub use crate::open_tag::*;
+  pub use crate::role_classes::*;
   pub use crate::section;
+  pub use crate::xhtml::*;
   pub use crate::AsciidoctorHtml;
   pub use asciidork_core::*;
   pub use ast::prelude::*;
@@ -0,0 +1,196 @@
+// elements whose content is copied verbatim
+const PRESERVE: [&str; 2] = ["pre", "code"];
+
+// elements whose content is raw text, and can't be scanned for tags
+const RAW_TEXT: [&str; 3] = ["script", "style", "textarea"];
+
+const BLOCK: [&str; 48] = [
+  "address",
+  "article",
+  "aside",
+  "blockquote",
+  "body",
+  "br",
+  "caption",
+  "col",
+  "colgroup",
+  "dd",
+  "details",
+  "div",
+  "dl",
+  "dt",
+  "figcaption",
+  "figure",
+  "footer",
+  "h1",
+  "h2",
+  "h3",
+  "h4",
+  "h5",
+  "h6",
+  "head",
+  "header",
+  "hr",
+  "html",
+  "li",
+  "link",
+  "main",
+  "meta",
+  "nav",
+  "ol",
+  "p",
+  "pre",
+  "script",
+  "section",
+  "style",
+  "summary",
+  "table",
+  "tbody",
+  "td",
+  "tfoot",
+  "th",
+  "thead",
+  "title",
+  "tr",
+  "ul",
+];
+
+/// Shrinks html by collapsing the whitespace between tags. A whitespace-only
+/// run next to a block-level tag is dropped, elsewhere it becomes a single
+/// space, since it may separate inline content. Text is never touched, nor is
+/// anything inside `pre`, `code`, `script`, `style` or `textarea` elements.
+pub fn minify(html: &str) -> String {
+  let mut out = String::with_capacity(html.len());
+  let mut preserve_depth = 0_usize;
+  let mut prev_block = true;
+  let mut rest = html;
+  while !rest.is_empty() {
+    let text_len = rest.find('<').unwrap_or(rest.len());
+    let (text, tail) = rest.split_at(text_len);
+    rest = tail;
+    if preserve_depth > 0 || !text.chars().all(|c| c.is_ascii_whitespace()) {
+      out.push_str(text);
+    } else if !text.is_empty() && !prev_block && !tag(rest).is_some_and(|t| t.block) {
+      out.push(' ');
+    }
+
+    if rest.starts_with("<!--") {
+      let len = rest.find("-->").map_or(rest.len(), |i| i + 3);
+      out.push_str(&rest[..len]);
+      rest = &rest[len..];
+      continue;
+    }
+    let Some(tag) = tag(rest) else {
+      out.push_str(rest);
+      break;
+    };
+    out.push_str(&rest[..tag.len]);
+    rest = &rest[tag.len..];
+    prev_block = tag.block;
+    if tag.closing || tag.self_closing {
+      if PRESERVE.contains(&tag.name.as_str()) {
+        preserve_depth = preserve_depth.saturating_sub(1);
+      }
+    } else if PRESERVE.contains(&tag.name.as_str()) {
+      preserve_depth += 1;
+    } else if RAW_TEXT.contains(&tag.name.as_str()) {
+      let close = format!("</{}", tag.name);
+      let len = rest.to_ascii_lowercase().find(&close).unwrap_or(rest.len());
+      out.push_str(&rest[..len]);
+      rest = &rest[len..];
+    }
+  }
+  out
+}
+
+struct Tag {
+  name: String,
+  len: usize,
+  closing: bool,
+  self_closing: bool,
+  block: bool,
+}
+
+fn tag(src: &str) -> Option<Tag> {
+  let bytes = src.as_bytes();
+  if bytes.first() != Some(&b'<') {
+    return None;
+  }
+  let mut quote = None;
+  let mut len = None;
+  for (i, &byte) in bytes.iter().enumerate().skip(1) {
+    match (quote, byte) {
+      (Some(q), _) if q == byte => quote = None,
+      (Some(_), _) => {}
+      (None, b'"' | b'\'') => quote = Some(byte),
+      (None, b'>') => {
+        len = Some(i + 1);
+        break;
+      }
+      _ => {}
+    }
+  }
+  let len = len?;
+  let inner = &src[1..len - 1];
+  let closing = inner.starts_with('/');
+  let name = inner
+    .trim_start_matches('/')
+    .split(|c: char| !c.is_ascii_alphanumeric())
+    .next()
+    .unwrap_or("")
+    .to_ascii_lowercase();
+  Some(Tag {
+    // doctypes and processing instructions sit between blocks
+    block: name.is_empty() || BLOCK.contains(&name.as_str()),
+    self_closing: inner.ends_with('/'),
+    name,
+    len,
+    closing,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_minify() {
+    let cases = [
+      ("<p>a</p>", "<p>a</p>"),
+      (
+        "<!DOCTYPE html>\n<html>\n<head>\n<title>T</title>\n</head>",
+        "<!DOCTYPE html><html><head><title>T</title></head>",
+      ),
+      (
+        "<div class=\"paragraph\">\n  <p>text  with\nspaces</p>\n</div>\n",
+        "<div class=\"paragraph\"><p>text  with\nspaces</p></div>",
+      ),
+      (
+        "<p><em>a</em>\n  <strong>b</strong></p>",
+        "<p><em>a</em> <strong>b</strong></p>",
+      ),
+      (
+        "<div>\n<pre class=\"x\">\n  <code>\n  a\n</code>\n</pre>\n</div>",
+        "<div><pre class=\"x\">\n  <code>\n  a\n</code>\n</pre></div>",
+      ),
+      (
+        "<p><code><b>a</b> <i>b</i></code>\n<span>c</span></p>",
+        "<p><code><b>a</b> <i>b</i></code> <span>c</span></p>",
+      ),
+      (
+        "<script>\nif (a<b) {}\n</script>\n<p>x</p>",
+        "<script>\nif (a<b) {}\n</script><p>x</p>",
+      ),
+      (
+        "<img alt=\"a > b\">\n<span>c</span>",
+        "<img alt=\"a > b\"> <span>c</span>",
+      ),
+      ("<div>\n<!-- a  b -->\n</div>", "<div><!-- a  b --></div>"),
+      ("<br/>\n<span>a</span>", "<br/><span>a</span>"),
+      ("a < b", "a < b"),
+    ];
+    for (input, expected) in cases {
+      assert_eq!(minify(input), expected, "input: {input:?}");
+    }
+  }
+}
@@ -9,6 +9,7 @@ pub struct OpenTag {
   pub opened_classes: bool,
   append_classes: Option<String>,
   styles: Option<String>,
+  data_attrs: Option<Vec<(String, String)>>,
 }
 
 impl HtmlBuf for OpenTag {
@@ -32,6 +33,7 @@ impl OpenTag {
       opened_classes: false,
       append_classes: None,
       styles: None,
+      data_attrs: None,
     };
 
     tag.buf.push('<');
@@ -53,9 +55,45 @@ impl OpenTag {
       }
       tag.append_classes = Some(append);
     }
+
+    // held until we know which are allowed, see `retain_data_attrs`
+    for (name, value) in attrs.named_entries() {
+      let Some(name) = name.strip_prefix("data-") else {
+        continue;
+      };
+      if !name.is_empty()
+        && name
+          .bytes()
+          .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+      {
+        let data_attrs = tag.data_attrs.get_or_insert_with(Vec::new);
+        data_attrs.push((name.to_string(), value.to_string()));
+      }
+    }
     tag
   }
 
+  pub fn retain_data_attrs(&mut self, allowed: Option<&DataAttrs>) {
+    match (self.data_attrs.as_mut(), allowed) {
+      (Some(data_attrs), Some(allowed)) => data_attrs.retain(|(name, _)| allowed.allows(name)),
+      (Some(_), None) => self.data_attrs = None,
+      (None, _) => {}
+    }
+  }
+
+  pub fn map_roles(&mut self, role_classes: &RoleClasses) {
+    if let Some(roles) = self.append_classes.take() {
+      let mut append = String::with_capacity(roles.len() * 2);
+      for (idx, role) in roles.split(' ').enumerate() {
+        if idx > 0 {
+          append.push(' ');
+        }
+        role_classes.push_class(role, &mut append);
+      }
+      self.append_classes = Some(append);
+    }
+  }
+
   pub fn push_str(&mut self, s: &str) {
     self.buf.push_str(s);
   }
@@ -142,6 +180,17 @@ impl OpenTag {
     if let Some(title) = attrs.named("title") {
       self.push_html_attr("title", title)
     }
+    self.push_link_constraint_attrs(attrs, has_link_text, blank_window_shorthand);
+  }
+
+  /// pushes the `target` and `rel` attrs, without the `title`, which
+  /// belongs to the wrapped element when an image is linked
+  pub fn push_link_constraint_attrs(
+    &mut self,
+    attrs: &AttrList,
+    has_link_text: bool,
+    blank_window_shorthand: bool,
+  ) {
     if let Some(window) = attrs.named("window") {
       if !has_link_text {
         self.push_ch('"');
@@ -181,6 +230,13 @@ impl OpenTag {
     if let Some(styles) = self.styles.take() {
       self.push_html_attr("style", &styles);
     }
+    for (name, value) in self.data_attrs.take().unwrap_or_default() {
+      self.buf.push_str(" data-");
+      self.buf.push_str(&name);
+      self.buf.push_str("=\"");
+      self.push_str_attr_escaped(&value);
+      self.buf.push('"');
+    }
     self.buf.push('>');
     self.buf
   }
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use crate::internal::*;
+
+/// Translation of roles into html classes, for targeting css frameworks.
+/// Configured by the `role-class-map` attribute, a comma-separated list of
+/// `role=classes` pairs (e.g. `lead=text-lg font-medium, big=text-xl`),
+/// and the `role-class-prefix` attribute, which is prepended to every role
+/// not found in the map. Mapped classes are emitted as-is.
+#[derive(Debug, Clone, Default)]
+pub struct RoleClasses {
+  prefix: Option<String>,
+  map: HashMap<String, String>,
+}
+
+impl RoleClasses {
+  pub fn from_meta(meta: &DocumentMeta) -> Option<Self> {
+    let prefix = meta.str("role-class-prefix").filter(|p| !p.is_empty());
+    let map = meta.str("role-class-map").unwrap_or("");
+    if prefix.is_none() && map.trim().is_empty() {
+      return None;
+    }
+    let map = map
+      .split(',')
+      .filter_map(|pair| pair.split_once('='))
+      .map(|(role, classes)| (role.trim(), classes.trim()))
+      .filter(|(role, classes)| !role.is_empty() && !classes.is_empty())
+      .map(|(role, classes)| (role.to_string(), classes.to_string()))
+      .collect();
+    Some(Self {
+      prefix: prefix.map(str::to_string),
+      map,
+    })
+  }
+
+  pub fn class<'a>(&'a self, role: &'a str) -> Cow<'a, str> {
+    match (self.map.get(role), &self.prefix) {
+      (Some(classes), _) => Cow::Borrowed(classes),
+      (None, Some(prefix)) => Cow::Owned(format!("{prefix}{role}")),
+      (None, None) => Cow::Borrowed(role),
+    }
+  }
+
+  pub fn push_class(&self, role: &str, buf: &mut String) {
+    match (self.map.get(role), &self.prefix) {
+      (Some(classes), _) => buf.push_str(classes),
+      (None, Some(prefix)) => {
+        buf.push_str(prefix);
+        buf.push_str(role);
+      }
+      (None, None) => buf.push_str(role),
+    }
+  }
+}
@@ -34,6 +34,35 @@ pub fn class(section: &Section) -> &'static str {
 }
 
 impl AsciidoctorHtml {
+  /// pushes a link to the heading's own id, if `heading-permalinks` is set,
+  /// positioned `before` or `after` (the default) the heading text
+  pub(crate) fn push_heading_permalink(&mut self, id: Option<&str>, before: bool) {
+    let Some(id) = id else {
+      return;
+    };
+    let position = match self.doc_meta.get("heading-permalinks") {
+      Some(AttrValue::String(position)) => position.as_str(),
+      Some(AttrValue::Bool(true)) => "after",
+      _ => return,
+    };
+    if (position == "before") != before {
+      return;
+    }
+    let class = self.doc_meta.str_or("heading-permalink-class", "permalink");
+    let symbol = self.doc_meta.str_or("heading-permalink-symbol", "¶");
+    let link = [
+      r#"<a class=""#,
+      class,
+      "\" href=\"#",
+      id,
+      "\">",
+      symbol,
+      "</a>",
+    ]
+    .concat();
+    self.push_str(&link);
+  }
+
   pub(super) fn should_number_section(&self, section: &Section) -> bool {
     let Some(sectnums) = self.doc_meta.get("sectnums") else {
       return false;
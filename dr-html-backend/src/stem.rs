@@ -0,0 +1,22 @@
+use crate::internal::*;
+
+impl AsciidoctorHtml {
+  /// Wraps inline stem content in the delimiters a client-side math library
+  /// scans for. `\(…\)` (or `\$…\$` for AsciiMath) is what MathJax expects,
+  /// `:stem-delimiters: dollars` emits `$$…$$` instead, for KaTeX and others.
+  /// Content is HTML-escaped unless `stem-escape` is unset.
+  pub(super) fn push_inline_stem(&mut self, text: &str, notation: StemNotation) {
+    let (open, close) = match (self.doc_meta.str("stem-delimiters"), notation) {
+      (Some("dollars"), _) => ("$$", "$$"),
+      (_, StemNotation::Latexmath) => (r"\(", r"\)"),
+      (_, StemNotation::Asciimath) => (r"\$", r"\$"),
+    };
+    self.push_str(open);
+    if self.doc_meta.is_false("stem-escape") {
+      self.push_str(text);
+    } else {
+      self.push_str_escaped(text);
+    }
+    self.push_str(close);
+  }
+}
@@ -78,6 +78,9 @@ impl AsciidoctorHtml {
     } else {
       self.push_str("<td");
     }
+    if let Some(id) = &cell.id {
+      self.push_html_attr("id", id);
+    }
     self.push([" class=\"tableblock halign-", cell.h_align.word()]);
     self.push([" valign-", cell.v_align.word()]);
     if cell.col_span > 1 {
@@ -0,0 +1,66 @@
+use crate::internal::*;
+
+lazy_static! {
+  static ref VOID_ELEMENT: Regex = Regex::new(
+    r"(?i)<(?:area|base|br|col|embed|hr|img|input|link|meta|param|source|track|wbr)(?:\s[^<>]*)?>"
+  )
+  .unwrap();
+  static ref NAMED_ENTITY: Regex = Regex::new(r"&([a-zA-Z][a-zA-Z0-9]*);").unwrap();
+}
+
+/// Rewrites raw html, from passthroughs or entities in text, so that it is
+/// well-formed XML: void elements are self-closed, and named character
+/// references other than the five predefined by XML become numeric.
+pub fn xhtml_compliant(html: &str) -> Cow<'_, str> {
+  if !html.contains(['<', '&']) {
+    return Cow::Borrowed(html);
+  }
+  let closed = VOID_ELEMENT.replace_all(html, |caps: &regex::Captures| {
+    let tag = &caps[0];
+    if tag.ends_with("/>") {
+      tag.to_string()
+    } else {
+      format!("{}/>", &tag[..tag.len() - 1])
+    }
+  });
+  let numeric = NAMED_ENTITY.replace_all(&closed, |caps: &regex::Captures| {
+    if matches!(&caps[1], "amp" | "lt" | "gt" | "quot" | "apos") {
+      return caps[0].to_string();
+    }
+    match decode_entity(&caps[0]) {
+      Some(chars) => chars.chars().map(|c| format!("&#{};", c as u32)).collect(),
+      None => caps[0].to_string(),
+    }
+  });
+  match (&closed, numeric) {
+    (Cow::Borrowed(_), Cow::Borrowed(_)) => Cow::Borrowed(html),
+    (_, numeric) => Cow::Owned(numeric.into_owned()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_xhtml_compliant() {
+    let cases = [
+      ("plain text", "plain text"),
+      ("a<br>b", "a<br/>b"),
+      ("<br/>", "<br/>"),
+      (
+        r#"<img src="a.png" alt="a">"#,
+        r#"<img src="a.png" alt="a"/>"#,
+      ),
+      ("<HR>", "<HR/>"),
+      ("<b>bold</b>", "<b>bold</b>"),
+      ("<bright>", "<bright>"),
+      ("a&nbsp;b", "a&#160;b"),
+      ("&amp;&lt;&#169;", "&amp;&lt;&#169;"),
+      ("&bogus;", "&bogus;"),
+    ];
+    for (input, expected) in cases {
+      assert_eq!(xhtml_compliant(input), expected, "input: {input}");
+    }
+  }
+}
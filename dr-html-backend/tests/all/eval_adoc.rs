@@ -40,6 +40,16 @@ assert_inline_html!(curly_quotes, "foo \"`bar`\"", r#"foo &#8220;bar&#8221;"#);
 assert_inline_html!(implicit_apos, "Olaf's wrench", r#"Olaf&#8217;s wrench"#);
 assert_inline_html!(multichar_whitespace, "foo   bar", r#"foo bar"#);
 assert_inline_html!(litmono_attr_ref, "`+{name}+`", r#"<code>{name}</code>"#);
+assert_inline_html!(
+  litmono_special_chars,
+  "`+a <b> & c+`",
+  r#"<code>a &lt;b&gt; &amp; c</code>"#
+);
+assert_inline_html!(
+  litmono_quotes_unescaped,
+  r#"`+a "b" 'c'+`"#,
+  r#"<code>a "b" 'c'</code>"#
+);
 
 assert_inline_html!(
   not_passthrough,
@@ -47,6 +57,36 @@ assert_inline_html!(
   "<code>\\d+[a]\\d+[b]</code>"
 );
 
+assert_inline_html!(
+  unknown_named_entity_escaped,
+  "&copy; &bogus; &#169;",
+  r#"&copy; &amp;bogus; &#169;"#
+);
+
+assert_html!(
+  decode_entities,
+  adoc! {r#"
+    :decode-entities:
+
+    &copy; &#x1f600; &bogus;
+  "#},
+  html! {r#"
+    <div class="paragraph"><p>© 😀 &amp;bogus;</p></div>
+  "#}
+);
+
+assert_html!(
+  decode_entities_keeps_markup_escaped,
+  adoc! {r#"
+    :decode-entities:
+
+    &lt;script&gt;alert(1)&lt;/script&gt; &amp;lt; &#60;b&#62; &quot;&apos;
+  "#},
+  html! {r#"
+    <div class="paragraph"><p>&lt;script&gt;alert(1)&lt;/script&gt; &amp;lt; &#60;b&#62; &quot;&apos;</p></div>
+  "#}
+);
+
 assert_inline_html!(
   emdash_start_line_swallows_newline,
   "foo\n-- bar", // rx removes the newline in this case
@@ -89,12 +129,25 @@ assert_inline_html!(
   r#"John&#8217;s Hideout is the Whites&#8217; place&#8230;&#8203; foo'bar"#
 );
 
-assert_inline_html!(
+assert_html!(
   btn_macro,
+  |settings: &mut ::asciidork_core::JobSettings| {
+    settings.embedded = true;
+    settings.doctype = Some(::asciidork_core::DocType::Inline);
+    settings
+      .job_attrs
+      .insert_unchecked("experimental", ::asciidork_core::JobAttr::readonly(true));
+  },
   "press the btn:[OK] button",
   r#"press the <b class="button">OK</b> button"#
 );
 
+assert_inline_html!(
+  btn_macro_not_experimental,
+  "press the btn:[OK] button",
+  r#"press the btn:[OK] button"#
+);
+
 assert_html!(
   comment_lines,
   adoc! {r#"
@@ -140,7 +193,11 @@ assert_html!(
 
 assert_html!(
   menu_macro,
-  "select menu:File[Save].",
+  adoc! {r#"
+    :experimental:
+
+    select menu:File[Save].
+  "#},
   html! {r#"
     <div class="paragraph">
       <p>select <span class="menuseq"><span class="menu">File</span>&#160;&#9656;<span class="menuitem">Save</span></span>.</p>
@@ -150,7 +207,11 @@ assert_html!(
 
 assert_html!(
   menu_macro_2,
-  "select menu:File[Save > Reset].",
+  adoc! {r#"
+    :experimental:
+
+    select menu:File[Save > Reset].
+  "#},
   html! {r#"
     <div class="paragraph">
       <p>
@@ -539,6 +600,79 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  line_breaks_in_cells_and_list_items,
+  adoc! {r#"
+    |===
+    |foo +
+    bar |baz
+    |===
+
+    term:: desc +
+    more
+
+    //-
+
+    * item +
+    next
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup><col style="width: 100%;"></colgroup>
+      <tbody>
+        <tr><td class="tableblock halign-left valign-top"><p class="tableblock">foo<br> bar</p></td></tr>
+        <tr><td class="tableblock halign-left valign-top"><p class="tableblock">baz</p></td></tr>
+      </tbody>
+    </table>
+    <div class="dlist">
+      <dl>
+        <dt class="hdlist1">term</dt>
+        <dd><p>desc<br> more</p></dd>
+      </dl>
+    </div>
+    <div class="ulist">
+      <ul>
+        <li><p>item<br> next</p></li>
+      </ul>
+    </div>
+  "#}
+);
+
+assert_html!(
+  header_hardbreaks_option,
+  adoc! {r#"
+    = Doc
+    :hardbreaks-option:
+
+    para
+    line
+
+    * a
+    b
+
+    |===
+    |x
+    y
+    |===
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>para<br> line</p>
+    </div>
+    <div class="ulist">
+      <ul>
+        <li><p>a<br> b</p></li>
+      </ul>
+    </div>
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup><col style="width: 100%;"></colgroup>
+      <tbody>
+        <tr><td class="tableblock halign-left valign-top"><p class="tableblock">x<br> y</p></td></tr>
+      </tbody>
+    </table>
+  "#}
+);
+
 assert_html!(
   simple_listing_block,
   adoc! {r#"
@@ -700,6 +834,45 @@ enum SubstrTest {
   DoesNotContain(&'static str),
 }
 
+assert_html!(
+  block_meta_merged_across_blank_lines,
+  adoc! {r#"
+    [#intro]
+
+    .Intro
+    [.lead]
+    Some text.
+
+    == Section
+
+    [#inner]
+
+    .Listing
+    ----
+    x
+    ----
+  "#},
+  html! {r#"
+    <div id="preamble">
+      <div class="sectionbody">
+        <div id="intro" class="paragraph lead">
+          <div class="title">Intro</div>
+          <p>Some text.</p>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_section">Section</h2>
+      <div class="sectionbody">
+        <div id="inner" class="listingblock">
+          <div class="title">Listing</div>
+          <div class="content"><pre>x</pre></div>
+        </div>
+      </div>
+    </div>
+  "#}
+);
+
 #[test]
 fn test_head_opts() {
   use SubstrTest::*;
@@ -780,6 +953,21 @@ fn test_head_opts() {
   assert!(html.contains("<title>Untitled</title>"));
 }
 
+#[test]
+fn test_text_only_title_and_alt() {
+  let input = "= The *Best* Guide(TM) -> Go\n\nimage:x.png[A _fancy_ alt]\n";
+  let parser = test_parser!(input);
+  let document = parser.parse().unwrap().document;
+  let html = eval(&document, AsciidoctorHtml::new()).unwrap();
+  assert!(html.contains("<title>The Best Guide™ → Go</title>"));
+  assert!(html.contains(r#"<img src="x.png" alt="A fancy alt">"#));
+
+  let parser = test_parser!("= Fish & <Chips>\n\npara\n");
+  let document = parser.parse().unwrap().document;
+  let html = eval(&document, AsciidoctorHtml::new()).unwrap();
+  assert!(html.contains("<title>Fish &amp; &lt;Chips&gt;</title>"));
+}
+
 #[test]
 fn test_non_embedded() {
   let input = adoc! {r#"
@@ -827,3 +1015,18 @@ fn test_non_embedded() {
     from: input
   );
 }
+
+#[test]
+fn test_unknown_block_does_not_panic() {
+  use asciidork_ast::{BlockContext, DocContent};
+  let parser = test_parser!("foo\n\nbar");
+  let mut document = parser.parse().unwrap().document;
+  let DocContent::Blocks(blocks) = &mut document.content else {
+    panic!("expected blocks");
+  };
+  // no eval arm handles a video context with simple content
+  blocks[0].context = BlockContext::Video;
+  let html = eval(&document, AsciidoctorHtml::new()).unwrap();
+  assert!(!html.contains("<p>foo</p>"));
+  assert!(html.contains("<p>bar</p>"));
+}
@@ -309,8 +309,8 @@ assert_html!(
       <colgroup><col style="width: 100%;"></colgroup>
       <thead>
         <tr>
-          <th class="tableblock halign-left valign-top">
-            <a id="foo"></a>* not AsciiDoc
+          <th id="foo" class="tableblock halign-left valign-top">
+            * not AsciiDoc
           </th>
         </tr>
       </thead>
@@ -347,8 +347,8 @@ assert_html!(
       <colgroup><col style="width: 100%;"></colgroup>
       <thead>
         <tr>
-          <th class="tableblock halign-left valign-top">
-            <a id="foo"></a>* not AsciiDoc
+          <th id="foo" class="tableblock halign-left valign-top">
+            * not AsciiDoc
           </th>
         </tr>
       </thead>
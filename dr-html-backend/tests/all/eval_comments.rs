@@ -114,3 +114,43 @@ assert_error!(
       | ^^^^ This delimiter was never closed
   "}
 );
+
+assert_html!(
+  block_meta_carries_over_comments,
+  adoc! {r#"
+    [#first.lead]
+
+    ////
+    block comment
+    ////
+
+    first paragraph
+
+    == Section
+
+    .Title
+
+    // line comment
+
+    [#second]
+    second paragraph
+  "#},
+  html! {r#"
+    <div id="preamble">
+      <div class="sectionbody">
+        <div id="first" class="paragraph lead">
+          <p>first paragraph</p>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_section">Section</h2>
+      <div class="sectionbody">
+        <div id="second" class="paragraph">
+          <div class="title">Title</div>
+          <p>second paragraph</p>
+        </div>
+      </div>
+    </div>
+  "#}
+);
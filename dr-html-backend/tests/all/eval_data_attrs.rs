@@ -0,0 +1,58 @@
+use asciidork_core::{JobAttr, JobSettings};
+use test_utils::*;
+
+assert_html!(
+  data_attrs_allowlist,
+  adoc! {r#"
+    :data-attrs: test-id, track-*
+
+    [data-test-id=intro,data-track-area="hero banner",data-secret=nope]
+    foo [.big,data-test-id=bar]#bar#
+
+    [#list,data-test-id=x]
+    * item
+  "#},
+  html! {r#"
+    <div class="paragraph" data-test-id="intro" data-track-area="hero banner">
+      <p>foo <span class="big" data-test-id="bar">bar</span></p>
+    </div>
+    <div id="list" class="ulist" data-test-id="x">
+      <ul>
+        <li><p>item</p></li>
+      </ul>
+    </div>
+  "#}
+);
+
+assert_html!(
+  data_attrs_not_rendered_by_default,
+  adoc! {r#"
+    [data-test-id=intro]
+    foo
+  "#},
+  html! {r#"
+    <div class="paragraph"><p>foo</p></div>
+  "#}
+);
+
+assert_html!(
+  data_attrs_from_job_attrs,
+  |settings: &mut JobSettings| {
+    settings
+      .job_attrs
+      .insert_unchecked("data-attrs", JobAttr::readonly("data-test-id"));
+  },
+  adoc! {r#"
+    [data-test-id=tip]
+    ****
+    foo
+    ****
+  "#},
+  html! {r#"
+    <div class="sidebarblock" data-test-id="tip">
+      <div class="content">
+        <div class="paragraph"><p>foo</p></div>
+      </div>
+    </div>
+  "#}
+);
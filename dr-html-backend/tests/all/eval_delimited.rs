@@ -55,9 +55,52 @@ assert_html!(
     baz
     ++++
   "#},
-  html! {r#"
-    foo &amp; <em>&lt;bar&gt;</em> baz
-  "#}
+  "foo &amp; <em>&lt;bar&gt;</em>\nbaz"
+);
+
+assert_html!(
+  passthrough_block_raw,
+  adoc! {r#"
+    ++++
+    <b>{foo}</b> \*bar* +++baz+++ (C) +
+    <i>qux</i>
+    ++++
+  "#},
+  "<b>{foo}</b> \\*bar* +++baz+++ (C) +\n<i>qux</i>"
+);
+
+assert_html!(
+  passthrough_block_w_subs_attributes,
+  adoc! {r#"
+    :foo: <em>foo</em>
+
+    [subs=attributes]
+    ++++
+    <b>{foo}</b> & *bar*
+    ++++
+  "#},
+  "<b><em>foo</em></b> & *bar*"
+);
+
+assert_html!(
+  pass_style_paragraph,
+  adoc! {r#"
+    [pass]
+    <u>foo</u> & _bar_
+  "#},
+  "<u>foo</u> & _bar_"
+);
+
+assert_html!(
+  pass_style_open_block,
+  adoc! {r#"
+    [pass]
+    --
+    <u>foo</u> & _bar_
+    <s>baz</s>
+    --
+  "#},
+  "<u>foo</u> & _bar_\n<s>baz</s>"
 );
 
 assert_html!(
@@ -78,6 +121,146 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  delimited_block_titles,
+  adoc! {r#"
+    :product: Asciidork
+
+    .Using *{product}*
+    ====
+    foo
+    ====
+
+    .Code for {product}
+    ----
+    bar
+    ----
+
+    .Sidebar _title_
+    ****
+    baz
+    ****
+  "#},
+  html! {r#"
+    <div class="exampleblock">
+      <div class="title">Example 1. Using <strong>Asciidork</strong></div>
+      <div class="content">
+        <div class="paragraph"><p>foo</p></div>
+      </div>
+    </div>
+    <div class="listingblock">
+      <div class="title">Code for Asciidork</div>
+      <div class="content"><pre>bar</pre></div>
+    </div>
+    <div class="sidebarblock">
+      <div class="content">
+        <div class="title">Sidebar <em>title</em></div>
+        <div class="paragraph"><p>baz</p></div>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  verbatim_nowrap,
+  adoc! {r#"
+    [%nowrap]
+    ----
+    foo
+    ----
+
+    [source%nowrap,rust]
+    ----
+    bar
+    ----
+
+    [%nowrap,max-height=10em]
+    ....
+    baz
+    ....
+  "#},
+  html! {r#"
+    <div class="listingblock">
+      <div class="content"><pre class="nowrap">foo</pre></div>
+    </div>
+    <div class="listingblock">
+      <div class="content">
+        <pre class="highlight nowrap"><code class="language-rust" data-lang="rust">bar</code></pre>
+      </div>
+    </div>
+    <div class="literalblock">
+      <div class="content">
+        <pre class="nowrap" style="max-height: 10em; overflow-y: auto;">baz</pre>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  verbatim_prewrap_unset,
+  adoc! {r#"
+    :prewrap!:
+    :nowrap-class: scroll
+
+    ----
+    foo
+    ----
+
+    ....
+    bar
+    ....
+  "#},
+  html! {r#"
+    <div class="listingblock">
+      <div class="content"><pre class="scroll">foo</pre></div>
+    </div>
+    <div class="literalblock">
+      <div class="content"><pre class="scroll">bar</pre></div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  verbatim_nowrap_class_escaped,
+  adoc! {r#"
+    :nowrap-class: x"><script>
+
+    [%nowrap]
+    ----
+    foo
+    ----
+
+    [source%nowrap,rust]
+    ----
+    bar
+    ----
+  "#},
+  contains:
+    r#"<pre class="x&quot;&gt;&lt;script&gt;">foo</pre>"#,
+    r#"<pre class="highlight x&quot;&gt;&lt;script&gt;"><code"#,
+);
+
+assert_html!(
+  block_title_anchor,
+  adoc! {r#"
+    .The *Title*[[title-anchor]]
+    ....
+    foo
+    ....
+
+    See <<title-anchor>>.
+  "#},
+  html! {r##"
+    <div class="literalblock">
+      <div class="title">The <strong>Title</strong><a id="title-anchor"></a></div>
+      <div class="content"><pre>foo</pre></div>
+    </div>
+    <div class="paragraph">
+      <p>See <a href="#title-anchor">[title-anchor]</a>.</p>
+    </div>
+  "##}
+);
+
 assert_html!(
   delimited_quote,
   adoc! {r#"
@@ -105,6 +288,94 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  nested_delimited_quotes,
+  adoc! {r#"
+    [quote,Outer,Book]
+    ____
+    outer
+
+    [quote,Inner]
+    ______
+    inner
+    ______
+    ____
+  "#},
+  html! {r#"
+    <div class="quoteblock">
+      <blockquote>
+        <div class="paragraph"><p>outer</p></div>
+        <div class="quoteblock">
+          <blockquote>
+            <div class="paragraph"><p>inner</p></div>
+          </blockquote>
+          <div class="attribution">&#8212; Inner</div>
+        </div>
+      </blockquote>
+      <div class="attribution">&#8212; Outer<br><cite>Book</cite></div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  markdown_quote_compat_mode,
+  adoc! {r#"
+    :compat-mode:
+
+    > foo
+    > bar
+    >
+    > > nested
+    > > -- Inner
+    > -- Outer, Book
+  "#},
+  html! {r#"
+    <div class="quoteblock">
+      <blockquote>
+        <div class="paragraph"><p>foo bar</p></div>
+        <div class="quoteblock">
+          <blockquote>
+            <div class="paragraph"><p>nested</p></div>
+          </blockquote>
+          <div class="attribution">&#8212; Inner</div>
+        </div>
+      </blockquote>
+      <div class="attribution">&#8212; Outer<br><cite>Book</cite></div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  markdown_quote_delimited_block,
+  adoc! {r#"
+    :compat-mode:
+
+    > ----
+    > code
+    > ----
+    >
+    > after
+  "#},
+  html! {r#"
+    <div class="quoteblock">
+      <blockquote>
+        <div class="listingblock">
+          <div class="content"><pre>code</pre></div>
+        </div>
+        <div class="paragraph"><p>after</p></div>
+      </blockquote>
+    </div>
+  "#}
+);
+
+assert_html!(
+  markdown_quote_requires_compat_mode,
+  "> foo",
+  html! {r#"
+    <div class="paragraph"><p>&gt; foo</p></div>
+  "#}
+);
+
 assert_html!(
   nested_delimited_blocks,
   adoc! {r#"
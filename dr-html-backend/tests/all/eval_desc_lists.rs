@@ -723,9 +723,6 @@ assert_html!(
   "#}
 );
 
-// NB: asciicoctor does not render `[grays-peak]` as the link text,
-// it uses the term text `Grays Peak`, for now we're not supporting this
-// see `/todo.md#differences-from-asciidoctor`
 assert_html!(
   anchors_starting_desc_terms,
   adoc! {r#"
@@ -737,7 +734,7 @@ assert_html!(
   "#},
   html! {r##"
     <div class="paragraph">
-      <p>Highest is <a href="#grays-peak">[grays-peak]</a>, which tops <a href="#mount-evans">Mount Evans</a>.</p>
+      <p>Highest is <a href="#grays-peak">Grays Peak</a>, which tops <a href="#mount-evans">Mount Evans</a>.</p>
     </div>
     <div class="dlist">
       <dl>
@@ -749,3 +746,72 @@ assert_html!(
     </div>
   "##}
 );
+
+assert_html!(
+  open_block_as_description,
+  adoc! {r#"
+    term::
+    --
+    para 1
+
+    para 2
+    --
+    other:: desc
+  "#},
+  html! {r#"
+    <div class="dlist">
+      <dl>
+        <dt class="hdlist1">term</dt>
+        <dd>
+          <div class="openblock">
+            <div class="content">
+              <div class="paragraph"><p>para 1</p></div>
+              <div class="paragraph"><p>para 2</p></div>
+            </div>
+          </div>
+        </dd>
+        <dt class="hdlist1">other</dt>
+        <dd><p>desc</p></dd>
+      </dl>
+    </div>
+  "#}
+);
+
+assert_html!(
+  desc_w_attached_listing_and_open_block,
+  adoc! {r#"
+    term:: desc
+    +
+    ----
+    code
+    ----
+    +
+    --
+    para 1
+
+    para 2
+    --
+    other:: desc
+  "#},
+  html! {r#"
+    <div class="dlist">
+      <dl>
+        <dt class="hdlist1">term</dt>
+        <dd>
+          <p>desc</p>
+          <div class="listingblock">
+            <div class="content"><pre>code</pre></div>
+          </div>
+          <div class="openblock">
+            <div class="content">
+              <div class="paragraph"><p>para 1</p></div>
+              <div class="paragraph"><p>para 2</p></div>
+            </div>
+          </div>
+        </dd>
+        <dt class="hdlist1">other</dt>
+        <dd><p>desc</p></dd>
+      </dl>
+    </div>
+  "#}
+);
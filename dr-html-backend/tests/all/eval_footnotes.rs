@@ -119,3 +119,102 @@ assert_html!(
     </div>
   "##}
 );
+
+assert_html!(
+  legacy_footnoteref,
+  adoc! {r#"
+    A footnoteref:[disclaimer,Opinions are _my own_.] and B.footnoteref:[disclaimer]
+  "#},
+  html! { r##"
+    <div class="paragraph">
+      <p>A <sup class="footnote" id="_footnote_disclaimer">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup> and B.<sup class="footnoteref">[<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup></p>
+    </div>
+    <div id="footnotes">
+      <hr>
+      <div class="footnote" id="_footnotedef_1"><a href="#_footnoteref_1">1</a>. Opinions are <em>my own</em>.</div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  footnotes_placed_per_section,
+  adoc! {r#"
+    :footnote-placement: section
+
+    intro.footnote:[one]
+
+    == Section 1
+
+    foo.footnote:[two]
+
+    === Nested
+
+    bar.footnote:[three]
+
+    == Section 2
+
+    No footnotes here.
+
+    == Section 3
+
+    baz.footnote:[four]
+  "#},
+  html! {r##"
+    <div id="preamble">
+      <div class="sectionbody">
+        <div class="paragraph">
+          <p>intro.<sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup></p>
+        </div>
+        <div class="footnotes">
+          <hr>
+          <div class="footnote" id="_footnotedef_1"><a href="#_footnoteref_1">1</a>. one</div>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_1">Section 1</h2>
+      <div class="sectionbody">
+        <div class="paragraph">
+          <p>foo.<sup class="footnote">[<a id="_footnoteref_2" class="footnote" href="#_footnotedef_2" title="View footnote.">2</a>]</sup></p>
+        </div>
+        <div class="sect2">
+          <h3 id="_nested">Nested</h3>
+          <div class="paragraph">
+            <p>bar.<sup class="footnote">[<a id="_footnoteref_3" class="footnote" href="#_footnotedef_3" title="View footnote.">3</a>]</sup></p>
+          </div>
+        </div>
+        <div class="footnotes">
+          <hr>
+          <div class="footnote" id="_footnotedef_2"><a href="#_footnoteref_2">2</a>. two</div>
+          <div class="footnote" id="_footnotedef_3"><a href="#_footnoteref_3">3</a>. three</div>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_2">Section 2</h2>
+      <div class="sectionbody">
+        <div class="paragraph"><p>No footnotes here.</p></div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_3">Section 3</h2>
+      <div class="sectionbody">
+        <div class="paragraph">
+          <p>baz.<sup class="footnote">[<a id="_footnoteref_4" class="footnote" href="#_footnotedef_4" title="View footnote.">4</a>]</sup></p>
+        </div>
+        <div class="footnotes">
+          <hr>
+          <div class="footnote" id="_footnotedef_4"><a href="#_footnoteref_4">4</a>. four</div>
+        </div>
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  legacy_footnoteref_xref,
+  adoc! {r#"
+    A footnoteref:[disclaimer,Opinions are my own.] and <<disclaimer>>.
+  "#},
+  contains: r##"<a href="#_footnote_disclaimer">Opinions are my own.</a>"##
+);
@@ -0,0 +1,55 @@
+use test_utils::*;
+
+assert_html!(
+  heading_permalinks_default,
+  adoc! {r#"
+    :heading-permalinks:
+
+    == Section
+
+    [discrete]
+    === Discrete
+  "#},
+  html! {r##"
+    <div class="sect1">
+      <h2 id="_section">Section<a class="permalink" href="#_section">¶</a></h2>
+      <div class="sectionbody">
+        <h3 id="_discrete" class="discrete">Discrete<a class="permalink" href="#_discrete">¶</a></h3>
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  heading_permalinks_before_w_custom_class_and_symbol,
+  adoc! {r#"
+    :heading-permalinks: before
+    :heading-permalink-class: anchor
+    :heading-permalink-symbol: #
+    :sectnums:
+
+    == Section
+  "#},
+  html! {r##"
+    <div class="sect1">
+      <h2 id="_section"><a class="anchor" href="#_section">#</a>1. Section</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  heading_permalinks_skip_headings_wo_id,
+  adoc! {r#"
+    :heading-permalinks:
+    :!sectids:
+
+    == Section
+  "#},
+  html! {r#"
+    <div class="sect1">
+      <h2>Section</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "#}
+);
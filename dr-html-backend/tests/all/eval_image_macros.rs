@@ -381,3 +381,48 @@ assert_html!(
     </div>
   "#}
 );
+
+assert_html!(
+  inline_image_title_link_role,
+  adoc! {r#"
+    See image:logo.png[Logo,100,title="Our logo",link=https://example.org,role=related] here.
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>See <span class="image related"><a class="image" href="https://example.org"><img src="logo.png" alt="Logo" width="100" title="Our logo"></a></span> here.</p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  inline_image_srcset_ignored_by_default,
+  adoc! {r#"
+    image:logo.png[Logo,srcset="logo-2x.png 2x",sizes="50vw"]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p><span class="image"><img src="logo.png" alt="Logo"></span></p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  image_srcset_sizes_opt_in,
+  adoc! {r#"
+    :image-srcset:
+
+    image:logo.png[Logo,srcset="logo-1x.png 1x, logo-2x.png 2x"]
+
+    image::hero.jpg[Hero,srcset="hero-640.jpg 640w, hero-1280.jpg 1280w",sizes="(max-width: 640px) 100vw, 50vw"]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p><span class="image"><img src="logo.png" alt="Logo" srcset="logo-1x.png 1x, logo-2x.png 2x"></span></p>
+    </div>
+    <div class="imageblock">
+      <div class="content">
+        <img src="hero.jpg" alt="Hero" srcset="hero-640.jpg 640w, hero-1280.jpg 1280w" sizes="(max-width: 640px) 100vw, 50vw">
+      </div>
+    </div>
+  "#}
+);
@@ -1,5 +1,8 @@
 use asciidork_core::{JobSettings, SafeMode};
+use asciidork_dr_html_backend::AsciidoctorHtml;
+use asciidork_eval::eval;
 use asciidork_parser::includes::*;
+use asciidork_parser::prelude::*;
 use test_utils::*;
 
 assert_html!(
@@ -393,6 +396,152 @@ assert_html!(
   contains: "included",
 );
 
+assert_html!(
+  memory_resolver_nested_relative_includes,
+  resolver: MemoryResolver::new()
+    .with_file("chapters/one.adoc", "one\n\ninclude::../shared/note.adoc[]\n")
+    .with_file("shared/note.adoc", "NOTE: shared"),
+  adoc! {r#"
+    include::chapters/one.adoc[]
+
+    include::missing.adoc[opts=optional]
+  "#},
+  html! {r#"
+    <div class="paragraph"><p>one</p></div>
+    <div class="admonitionblock note">
+      <table>
+        <tr>
+          <td class="icon"><div class="title">Note</div></td>
+          <td class="content">shared</td>
+        </tr>
+      </table>
+    </div>
+  "#}
+);
+
+assert_html!(
+  namespaced_section_ids,
+  resolver: MemoryResolver::new()
+    .with_file("chapters/chapter-1.adoc", "== Intro\n\none\n")
+    .with_file("chapters/chapter-2.adoc", "== Intro\n\ntwo\n"),
+  adoc! {r#"
+    = Book
+    :sectid-namespace:
+
+    See xref:chapters/chapter-2.adoc#_intro[] and <<chapters/chapter-1.adoc#_intro>>.
+
+    include::chapters/chapter-1.adoc[]
+
+    include::chapters/chapter-2.adoc[]
+  "#},
+  html! {r##"
+    <div id="preamble">
+      <div class="sectionbody">
+        <div class="paragraph">
+          <p>See <a href="#chapters-chapter-2_intro">Intro</a> and <a href="#chapters-chapter-1_intro">Intro</a>.</p>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="chapters-chapter-1_intro">Intro</h2>
+      <div class="sectionbody"><div class="paragraph"><p>one</p></div></div>
+    </div>
+    <div class="sect1">
+      <h2 id="chapters-chapter-2_intro">Intro</h2>
+      <div class="sectionbody"><div class="paragraph"><p>two</p></div></div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  namespaced_section_ids_same_file_name,
+  resolver: MemoryResolver::new()
+    .with_file("a/intro.adoc", "== Overview\n\nsee <<_overview>>\n")
+    .with_file("b/intro.adoc", "== Overview\n\nsee <<_overview>>\n"),
+  adoc! {r#"
+    = Book
+    :sectid-namespace:
+
+    See xref:b/intro.adoc#_overview[].
+
+    include::a/intro.adoc[]
+
+    include::b/intro.adoc[]
+  "#},
+  html! {r##"
+    <div id="preamble">
+      <div class="sectionbody">
+        <div class="paragraph"><p>See <a href="#b-intro_overview">Overview</a>.</p></div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="a-intro_overview">Overview</h2>
+      <div class="sectionbody">
+        <div class="paragraph"><p>see <a href="#a-intro_overview">Overview</a></p></div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="b-intro_overview">Overview</h2>
+      <div class="sectionbody">
+        <div class="paragraph"><p>see <a href="#b-intro_overview">Overview</a></p></div>
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  section_ids_not_namespaced_by_default,
+  resolver: MemoryResolver::new()
+    .with_file("chapters/chapter-1.adoc", "== Intro\n\none\n")
+    .with_file("chapters/chapter-2.adoc", "== Intro\n\ntwo\n"),
+  adoc! {r#"
+    include::chapters/chapter-1.adoc[]
+
+    include::chapters/chapter-2.adoc[]
+  "#},
+  html! {r##"
+    <div class="sect1">
+      <h2 id="_intro">Intro</h2>
+      <div class="sectionbody"><div class="paragraph"><p>one</p></div></div>
+    </div>
+    <div class="sect1">
+      <h2 id="_intro_2">Intro</h2>
+      <div class="sectionbody"><div class="paragraph"><p>two</p></div></div>
+    </div>
+  "##}
+);
+
+#[test]
+fn large_include_in_listing() {
+  let line = "a *b* <c> &amp; & \\*d* {e} -- f // <g>\n";
+  let render = |content: &str| {
+    let bump = &Bump::new();
+    let file = SourceFile::Path(Path::new("test.adoc"));
+    let mut parser = Parser::from_str("----\ninclude::log.txt[]\n----\n", file, bump);
+    parser.apply_job_settings(JobSettings {
+      safe_mode: SafeMode::Unsafe,
+      ..JobSettings::embedded()
+    });
+    parser.set_resolver(Box::new(
+      MemoryResolver::new().with_file("log.txt", content),
+    ));
+    let document = parser.parse().unwrap().document;
+    eval(&document, AsciidoctorHtml::new()).unwrap()
+  };
+  let single = render(line);
+  let start = single.find("<pre>").unwrap() + 5;
+  let end = single.rfind("</pre>").unwrap();
+  // large enough to be lexed in chunks, which must not change the output
+  let num_lines = 5 * 1024 * 1024 / line.len();
+  let expected = format!(
+    "{}{}{}",
+    &single[..start],
+    vec![&single[start..end]; num_lines].join("\n"),
+    &single[end..]
+  );
+  assert!(render(&line.repeat(num_lines)) == expected);
+}
+
 const TAGGED_RUBY_CLASS: &[u8] = b"#tag::all[]
 class Dog
   #tag::init[]
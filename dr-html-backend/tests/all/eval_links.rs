@@ -511,3 +511,33 @@ assert_html!(
   "\u{00A0}http://asciidoc.org[AsciiDoc] project page.",
   contains: "\u{00A0}<a href=\"http://asciidoc.org\">AsciiDoc</a> project page.</p>"
 );
+
+assert_html!(
+  external_links_blank,
+  adoc! {r#"
+    :external-links-blank:
+    :site-url: https://example.com
+
+    https://example.com/docs[Docs] and link:about.html[About]
+
+    Bare: https://other.org
+
+    https://other.org[Other,role=x]
+
+    https://other.org[Mine,window=_self]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p><a href="https://example.com/docs">Docs</a> and <a href="about.html">About</a></p>
+    </div>
+    <div class="paragraph">
+      <p>Bare: <a href="https://other.org" target="_blank" rel="nofollow noopener" class="bare">https://other.org</a></p>
+    </div>
+    <div class="paragraph">
+      <p><a href="https://other.org" target="_blank" rel="nofollow noopener" class="x">Other</a></p>
+    </div>
+    <div class="paragraph">
+      <p><a href="https://other.org" target="_self">Mine</a></p>
+    </div>
+  "#}
+);
@@ -1,4 +1,4 @@
-use test_utils::{adoc, html};
+use test_utils::{adoc, html, raw_html};
 
 assert_html!(
   most_basic_unordered_list,
@@ -299,6 +299,69 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  unstyled_and_no_bullet_lists,
+  adoc! {r#"
+    [unstyled]
+    . one
+
+    [no-bullet]
+    * two
+
+    [.unstyled]
+    * three
+  "#},
+  html! {r#"
+    <div class="olist unstyled">
+      <ol class="unstyled">
+        <li><p>one</p></li>
+      </ol>
+    </div>
+    <div class="ulist no-bullet">
+      <ul class="no-bullet">
+        <li><p>two</p></li>
+      </ul>
+    </div>
+    <div class="ulist unstyled">
+      <ul>
+        <li><p>three</p></li>
+      </ul>
+    </div>
+  "#}
+);
+
+assert_html!(
+  compact_lists,
+  adoc! {r#"
+    [%compact]
+    * one
+
+    [loweralpha%compact%reversed]
+    . two
+
+    [%compact]
+    term:: three
+  "#},
+  html! {r#"
+    <div class="ulist">
+      <ul class="compact">
+        <li><p>one</p></li>
+      </ul>
+    </div>
+    <div class="olist loweralpha">
+      <ol class="loweralpha compact" type="a" reversed>
+        <li><p>two</p></li>
+      </ol>
+    </div>
+    <div class="dlist">
+      <dl class="compact">
+        <dt class="hdlist1">term</dt>
+        <dd><p>three</p></dd>
+      </dl>
+    </div>
+  "#}
+);
+
 assert_html!(
   list_nested_ordered,
   adoc! {r#"
@@ -849,3 +912,116 @@ assert_html!(
     </div>
   "#}
 );
+
+// asciidoctor formatted output, compared ignoring insignificant whitespace
+assert_html!(
+  golden_nested_list,
+  golden: adoc! {"
+    * foo
+    ** bar
+  "},
+  raw_html! {r#"
+    <div class="ulist">
+    <ul>
+    <li>
+    <p>foo</p>
+    <div class="ulist">
+    <ul>
+    <li>
+    <p>bar</p>
+    </li>
+    </ul>
+    </div>
+    </li>
+    </ul>
+    </div>
+  "#}
+);
+
+assert_html!(
+  list_subs_override_applies_to_item_text,
+  adoc! {r#"
+    [subs=-quotes]
+    * no *bold* here
+    * pass:[<u>raw</u>] still
+  "#},
+  html! {r#"
+    <div class="ulist">
+      <ul>
+        <li><p>no *bold* here</p></li>
+        <li><p><u>raw</u> still</p></li>
+      </ul>
+    </div>
+  "#}
+);
+
+assert_html!(
+  list_subs_override_skips_attached_blocks,
+  adoc! {r#"
+    [subs=-quotes]
+    * no *bold* here
+    ** but *bold* here
+    * no *bold* again
+    +
+    but *bold* here
+
+    [subs=-quotes]
+    *term*:: no *bold* here
+    +
+    but *bold* here
+  "#},
+  html! {r#"
+    <div class="ulist">
+      <ul>
+        <li>
+          <p>no *bold* here</p>
+          <div class="ulist">
+            <ul><li><p>but <strong>bold</strong> here</p></li></ul>
+          </div>
+        </li>
+        <li>
+          <p>no *bold* again</p>
+          <div class="paragraph"><p>but <strong>bold</strong> here</p></div>
+        </li>
+      </ul>
+    </div>
+    <div class="dlist">
+      <dl>
+        <dt class="hdlist1">*term*</dt>
+        <dd>
+          <p>no *bold* here</p>
+          <div class="paragraph"><p>but <strong>bold</strong> here</p></div>
+        </dd>
+      </dl>
+    </div>
+  "#}
+);
+
+assert_html!(
+  list_item_leading_anchor_reftext,
+  adoc! {r#"
+    * [x] [[ck]]checked pass:[<i>i</i>]
+    * [[fn]]with a footnote:[note] inside
+
+    See <<ck>> and <<fn>>.
+  "#},
+  html! {r##"
+    <div class="ulist checklist">
+      <ul class="checklist">
+        <li><p>&#10003; <a id="ck"></a>checked <i>i</i></p></li>
+        <li>
+          <p>
+            <a id="fn"></a>with a <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup> inside
+          </p>
+        </li>
+      </ul>
+    </div>
+    <div class="paragraph">
+      <p>See <a href="#ck">checked <i>i</i></a> and <a href="#fn">with a inside</a>.</p>
+    </div>
+    <div id="footnotes">
+      <hr>
+      <div class="footnote" id="_footnotedef_1"><a href="#_footnoteref_1">1</a>. note</div>
+    </div>
+  "##}
+);
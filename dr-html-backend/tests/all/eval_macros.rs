@@ -174,6 +174,8 @@ assert_html!(
 assert_html!(
   keyboard_macro,
   adoc! {r#"
+    :experimental:
+
     Press kbd:[F11] to toggle.
 
     Or kbd:[Ctrl+Shift+N] for fun.
@@ -188,6 +190,18 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  ui_macros_not_experimental,
+  adoc! {r#"
+    Press kbd:[Ctrl+T], then btn:[Save] from menu:File[Save].
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>Press kbd:[Ctrl+T], then btn:[Save] from menu:File[Save].</p>
+    </div>
+  "#}
+);
+
 assert_html!(
   inline_pass_macro,
   adoc! {r#"
@@ -209,3 +223,50 @@ assert_html!(
     </div>
   "#}
 );
+
+assert_html!(
+  link_and_image_targets_percent_encoded,
+  adoc! {"
+    :href-encoding: whatwg
+
+    link:docs/café.pdf[Menu] and image:cafe\u{301}.png[Cafe]
+
+    image::naïve art.png[link=https://example.com/naïve]
+  "},
+  html! {r#"
+    <div class="paragraph">
+      <p><a href="docs/caf%C3%A9.pdf">Menu</a> and <span class="image"><img src="caf%C3%A9.png" alt="Cafe"></span></p>
+    </div>
+    <div class="imageblock">
+      <div class="content">
+        <a class="image" href="https://example.com/na%C3%AFve"><img src="na%C3%AFve%20art.png" alt="naïve art"></a>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  href_encoding_configurable,
+  adoc! {r#"
+    :href-encoding: spaces
+
+    link:pass:[docs/my café.pdf][Menu]
+
+    :href-encoding: none
+
+    link:pass:[docs/my café.pdf][Menu]
+
+    image::my café.png[]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p><a href="docs/my%20café.pdf">Menu</a></p>
+    </div>
+    <div class="paragraph">
+      <p><a href="docs/my café.pdf">Menu</a></p>
+    </div>
+    <div class="imageblock">
+      <div class="content"><img src="my%20café.png" alt="my café"></div>
+    </div>
+  "#}
+);
@@ -530,3 +530,48 @@ assert_html!(
   "#},
   contains: r#"<pre>slash preserved \</pre>"#
 );
+
+assert_html!(
+  leading_anchor_identifies_cell,
+  adoc! {r#"
+    |===
+    |Item 2+^|[[total,Grand "total"]] 42
+    |x |[[a&b]]y |z
+    |===
+
+    See <<total>>.
+  "#},
+  html! {r##"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 33.3333%;">
+        <col style="width: 33.3333%;">
+        <col style="width: 33.3333%;">
+      </colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top">
+            <p class="tableblock">Item</p>
+          </td>
+          <td id="total" class="tableblock halign-center valign-top" colspan="2">
+            <p class="tableblock">42</p>
+          </td>
+        </tr>
+        <tr>
+          <td class="tableblock halign-left valign-top">
+            <p class="tableblock">x</p>
+          </td>
+          <td id="a&amp;b" class="tableblock halign-left valign-top">
+            <p class="tableblock">y</p>
+          </td>
+          <td class="tableblock halign-left valign-top">
+            <p class="tableblock">z</p>
+          </td>
+        </tr>
+      </tbody>
+    </table>
+    <div class="paragraph">
+      <p>See <a href="#total">Grand "total"</a>.</p>
+    </div>
+  "##}
+);
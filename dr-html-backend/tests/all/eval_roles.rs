@@ -0,0 +1,64 @@
+use asciidork_core::{JobAttr, JobSettings};
+use test_utils::*;
+
+assert_html!(
+  role_class_map_and_prefix,
+  adoc! {r#"
+    :role-class-map: lead=text-lg font-medium, big=text-xl
+    :role-class-prefix: x-
+
+    [.lead.other]
+    foo [.big]#bar# [.baz]#qux#
+
+    [discrete.lead]
+    == Heading
+
+    image:cat.png[role=big]
+  "#},
+  html! {r#"
+    <div class="paragraph text-lg font-medium x-other">
+      <p>foo <span class="text-xl">bar</span> <span class="x-baz">qux</span></p>
+    </div>
+    <h2 id="_heading" class="discrete text-lg font-medium">Heading</h2>
+    <div class="paragraph">
+      <p><span class="image text-xl"><img src="cat.png" alt="cat"></span></p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  role_class_map_from_job_attrs,
+  |settings: &mut JobSettings| {
+    settings
+      .job_attrs
+      .insert_unchecked("role-class-map", JobAttr::readonly("tip=alert alert-info"));
+  },
+  adoc! {r#"
+    [.tip]
+    ****
+    foo
+    ****
+  "#},
+  html! {r#"
+    <div class="sidebarblock alert alert-info">
+      <div class="content">
+        <div class="paragraph">
+          <p>foo</p>
+        </div>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  roles_unchanged_without_mapping,
+  adoc! {r#"
+    [.lead]
+    foo [.big]#bar#
+  "#},
+  html! {r#"
+    <div class="paragraph lead">
+      <p>foo <span class="big">bar</span></p>
+    </div>
+  "#}
+);
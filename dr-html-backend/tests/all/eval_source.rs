@@ -25,6 +25,37 @@ assert_html!(
   source::wrap_literal("<pre> a\n// b\n c</pre>")
 );
 
+assert_html!(
+  indented_literal_block_common_indent,
+  "   foo\n  bar\n    baz",
+  source::wrap_literal("<pre> foo\nbar\n  baz</pre>")
+);
+
+assert_html!(
+  indented_literal_block_w_callouts,
+  adoc! {r#"
+      puts "hello" <1>
+        exit <2>
+
+    <1> Greets
+    <2> Leaves
+  "#},
+  format!(
+    "{}{}",
+    source::wrap_literal(
+      "<pre>puts \"hello\" <b class=\"conum\">(1)</b>\n  exit <b class=\"conum\">(2)</b></pre>"
+    ),
+    html! {r#"
+      <div class="colist arabic">
+        <ol>
+          <li><p>Greets</p></li>
+          <li><p>Leaves</p></li>
+        </ol>
+      </div>
+    "#}
+  )
+);
+
 assert_html!(
   source_block_explicit,
   adoc! {r#"
@@ -0,0 +1,43 @@
+use test_utils::*;
+
+assert_html!(
+  inline_stem_macros,
+  adoc! {r#"
+    stem:[sqrt(4) = 2] and latexmath:[a < b + c_{1}]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>\$sqrt(4) = 2\$ and \(a &lt; b + c_{1}\)</p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  stem_attr_selects_notation,
+  adoc! {r#"
+    :stem: latexmath
+
+    stem:[\sqrt{4}] and asciimath:[x^2]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>\(\sqrt{4}\) and \$x^2\$</p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  stem_dollar_delimiters_unescaped,
+  adoc! {r#"
+    :stem: latexmath
+    :stem-delimiters: dollars
+    :!stem-escape:
+
+    stem:[a < b]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>$$a < b$$</p>
+    </div>
+  "#}
+);
@@ -176,6 +176,39 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  toc_entry_footnotes_not_registered,
+  adoc! {"
+    = Doc Title
+    :toc:
+
+    == Sec footnote:[note]
+
+    text footnote:[other]
+  "},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li><a href="#_sec_footnotenote">Sec <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup></a></li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="_sec_footnotenote">Sec <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup></h2>
+      <div class="sectionbody">
+        <div class="paragraph">
+          <p>text <sup class="footnote">[<a id="_footnoteref_2" class="footnote" href="#_footnotedef_2" title="View footnote.">2</a>]</sup></p>
+        </div>
+      </div>
+    </div>
+    <div id="footnotes">
+      <hr>
+      <div class="footnote" id="_footnotedef_1"><a href="#_footnoteref_1">1</a>. note</div>
+      <div class="footnote" id="_footnotedef_2"><a href="#_footnoteref_2">2</a>. other</div>
+    </div>
+  "##}
+);
+
 test_non_embedded_contains!(
   toc_special_classes,
   adoc! {"
@@ -189,3 +222,48 @@ test_non_embedded_contains!(
     r#"<div id="toc" class="toc2">"#
   ],
 );
+
+assert_html!(
+  toc_discrete_heading_opt_in,
+  adoc! {"
+    = Doc Title
+    :toc:
+    :toclevels: 3
+
+    == Section 1
+
+    [discrete%toc]
+    ==== Deeper than parent
+
+    [discrete%toc]
+    === Included
+
+    [discrete]
+    === Excluded
+
+    See <<_included>>.
+  "},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li>
+          <a href="#_section_1">Section 1</a>
+          <ul class="sectlevel2">
+            <li><a href="#_deeper_than_parent">Deeper than parent</a></li>
+            <li><a href="#_included">Included</a></li>
+          </ul>
+        </li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_1">Section 1</h2>
+      <div class="sectionbody">
+        <h4 id="_deeper_than_parent" class="discrete">Deeper than parent</h4>
+        <h3 id="_included" class="discrete">Included</h3>
+        <h3 id="_excluded" class="discrete">Excluded</h3>
+        <div class="paragraph"><p>See <a href="#_included">Included</a>.</p></div>
+      </div>
+    </div>
+  "##}
+);
@@ -0,0 +1,89 @@
+use asciidork_core::JobSettings;
+use asciidork_parser::prelude::*;
+use test_utils::*;
+
+assert_html!(
+  xhtml_void_elements,
+  |settings: &mut JobSettings| settings.xhtml = true,
+  adoc! {r#"
+    foo +
+    bar image:a.png[] +++<br><img src="b.png">+++
+
+    '''
+
+    [%hardbreaks]
+    baz
+    qux
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>foo<br/> bar <span class="image"><img src="a.png" alt="a"/></span> <br/><img src="b.png"/></p>
+    </div>
+    <hr/>
+    <div class="paragraph"><p>baz<br/> qux</p></div>
+  "#}
+);
+
+assert_html!(
+  xhtml_numeric_entities,
+  |settings: &mut JobSettings| settings.xhtml = true,
+  "a&nbsp;b &amp; &copy; {nbsp}",
+  html! {r#"
+    <div class="paragraph"><p>a&#160;b &amp; &#169; &#160;</p></div>
+  "#}
+);
+
+assert_html!(
+  xhtml_from_htmlsyntax_attr,
+  adoc! {r#"
+    :htmlsyntax: xml
+
+    * [x] done
+  "#},
+  html! {r#"
+    <div class="ulist checklist">
+      <ul class="checklist">
+        <li><p>&#10003; done</p></li>
+      </ul>
+    </div>
+  "#}
+);
+
+assert_html!(
+  xhtml_interactive_checklist,
+  |settings: &mut JobSettings| settings.xhtml = true,
+  adoc! {r#"
+    [%interactive]
+    * [x] done
+    * [ ] todo
+  "#},
+  html! {r#"
+    <div class="ulist checklist">
+      <ul class="checklist">
+        <li><p><input type="checkbox" data-item-complete="1" checked="checked"/> done</p></li>
+        <li><p><input type="checkbox" data-item-complete="0"/> todo</p></li>
+      </ul>
+    </div>
+  "#}
+);
+
+assert_standalone_body!(
+  xhtml_standalone_header,
+  |settings: &mut JobSettings| settings.xhtml = true,
+  adoc! {r#"
+    = Document Title
+    Bob Smith
+  "#},
+  html! {r#"
+    <body class="article">
+      <div id="header">
+        <h1>Document Title</h1>
+        <div class="details">
+          <span id="author" class="author">Bob Smith</span><br/>
+        </div>
+      </div>
+      <div id="content"></div>
+      <div id="footer"></div>
+    </body>
+  "#}
+);
@@ -1,4 +1,8 @@
+use asciidork_ast::Document;
 use asciidork_core::JobSettings;
+use asciidork_dr_html_backend::AsciidoctorHtml;
+use asciidork_eval::eval;
+use asciidork_parser::prelude::*;
 use test_utils::*;
 
 // NB: many of these tests are ported directly from the asciidoctor test suite
@@ -281,16 +285,16 @@ assert_html!(
       <colgroup><col style="width: 50%;"><col style="width: 50%;"></colgroup>
       <tbody>
         <tr>
-          <td class="tableblock halign-left valign-top">
-            <p class="tableblock"><strong><a id="mount-evans"></a>Mount Evans</strong></p>
+          <td id="mount-evans" class="tableblock halign-left valign-top">
+            <p class="tableblock"><strong>Mount Evans</strong></p>
           </td>
           <td class="tableblock halign-left valign-top">
             <p class="tableblock">14,271 feet</p>
           </td>
         </tr>
         <tr>
-          <th class="tableblock halign-left valign-top">
-            <p class="tableblock"><a id="grays-peak"></a> Grays Peak</p>
+          <th id="grays-peak" class="tableblock halign-left valign-top">
+            <p class="tableblock">Grays Peak</p>
           </th>
           <td class="tableblock halign-left valign-top">
             <p class="tableblock">14,278 feet</p>
@@ -614,3 +618,80 @@ assert_html!(
   "#},
   contains: r##"<h2 id="a">See <a href="#b">Consult Google</a></h2>"##,
 );
+
+assert_html!(
+  xref_macro_attrs,
+  adoc! {r#"
+    == Install
+
+    See xref:_install[the install guide,role=ref,window=_blank] and xref:_install[role=quiet].
+  "#},
+  contains:
+    r##"See <a href="#_install" target="_blank" rel="noopener" class="ref">the install guide</a>"##,
+    r##"and <a href="#_install" class="quiet">Install</a>."##,
+);
+
+assert_html!(
+  xref_default_reftext_by_anchor_type,
+  adoc! {r#"
+    * [[step-1]]Unpack the archive
+    * Run the installer
+
+    |===
+    |[[cell-a]]Cell A |b
+    |===
+
+    Noted.footnote:disclaimer[Your mileage may vary.]
+
+    See <<step-1>>, <<cell-a>>, <<disclaimer>> and <<bare>>.
+
+    Text with a [[bare]]bare anchor.
+  "#},
+  contains:
+    r##"<a href="#step-1">Unpack the archive</a>"##,
+    r##"<a href="#cell-a">Cell A</a>"##,
+    r##"<a href="#_footnote_disclaimer">Your mileage may vary.</a>"##,
+    r##"<a href="#bare">[bare]</a>"##,
+);
+
+assert_html!(
+  reftext_and_id_maxlength,
+  adoc! {r#"
+    :idmaxlength: 16
+    :reftext-maxlength: 12
+
+    == Installing the Überwaldian toolchain
+
+    * [[step-1]]Unpack the *whole* archive
+
+    Noted.footnote:disclaimer[Your mileage may vary.]
+
+    See <<_installing_the>>, <<step-1>> and <<disclaimer>>.
+  "#},
+  contains:
+    r##"<h2 id="_installing_the">Installing the Überwaldian toolchain</h2>"##,
+    r##"<a href="#_installing_the">Installing t…</a>"##,
+    r##"<a href="#step-1">Unpack the…</a>"##,
+    r##"<a href="#_footnote_disclaimer">Your mileage…</a>"##,
+);
+
+#[test]
+fn test_xref_across_chunks() {
+  let input = adoc! {"
+    == One
+
+    See <<_two>>.
+
+    == Two
+
+    two
+  "};
+  let parser = test_parser!(input);
+  let document = parser.parse().unwrap().document;
+  let mut chunks = document.split_at_level(1);
+  assert_eq!(Document::anchor_chunks(&chunks)["_two"], 1);
+  chunks[0].meta.embedded = true;
+  let html = eval(&chunks[0], AsciidoctorHtml::new()).unwrap();
+  // the text resolves, though the other chunk isn't part of this one
+  assert!(html.contains(r##"See <a href="#_two">Two</a>."##), "{html}");
+}
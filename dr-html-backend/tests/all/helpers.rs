@@ -2,6 +2,13 @@
 
 #[macro_export]
 macro_rules! assert_html {
+  ($name:ident, golden: $input:expr, $expected:expr$(,)?) => {
+    #[test]
+    fn $name() {
+      let actual = _html!($input, |_| {}, None);
+      ::test_utils::expect_html_eq!(actual, $expected, from: $input);
+    }
+  };
   ($name:ident, $input:expr, $expected:expr) => {
     assert_html!($name, |_| {}, $input, $expected);
   };
@@ -26,6 +33,13 @@ macro_rules! assert_html {
       ::test_utils::expect_eq!(actual, $expected.to_string(), from: $input);
     }
   };
+  ($name:ident, resolver: $resolver:expr, $input:expr, $expected:expr$(,)?) => {
+    #[test]
+    fn $name() {
+      let actual = _html!($input, |_| {}, Some(Box::new($resolver)));
+      ::test_utils::expect_eq!(actual, $expected.to_string(), from: $input);
+    }
+  };
   ($name:ident, resolving_err: $err:expr, $input:expr, $expected:expr$(,)?) => {
     #[test]
     fn $name() {
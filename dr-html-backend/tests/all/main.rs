@@ -8,21 +8,26 @@ mod eval_callouts;
 mod eval_collapsible;
 mod eval_comments;
 mod eval_csv_tables;
+mod eval_data_attrs;
 mod eval_delimited;
 mod eval_desc_lists;
 mod eval_dsv_tables;
 mod eval_footnotes;
+mod eval_heading_permalinks;
 mod eval_image_macros;
 mod eval_includes;
 mod eval_links;
 mod eval_lists;
 mod eval_macros;
 mod eval_psv_tables;
+mod eval_roles;
 mod eval_sections;
 mod eval_source;
 mod eval_standalone;
+mod eval_stem;
 mod eval_toc;
 mod eval_verses;
 mod eval_win_crlf;
+mod eval_xhtml;
 mod eval_xrefs;
 mod eval_xrefs_interdoc;
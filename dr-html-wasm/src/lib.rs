@@ -9,13 +9,14 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen]
 pub fn convert(adoc: &str, timestamp: f64) -> String {
   let bump = &Bump::new();
-  let mut parser = Parser::from_str(adoc, SourceFile::Tmp, bump);
-
   let mut job_settings = JobSettings::embedded();
   job_settings.strict = false;
   AsciidoctorHtml::set_job_attrs(&mut job_settings.job_attrs);
-  parser.apply_job_settings(job_settings);
-  parser.provide_timestamps(timestamp as u64, None, None);
+  let parser = Parser::builder(bump)
+    .source_str(adoc)
+    .settings(job_settings)
+    .timestamps(timestamp as u64, None, None)
+    .build();
 
   let result = parser.parse();
   match result {
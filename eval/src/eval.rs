@@ -14,6 +14,7 @@ struct Ctx<'a, 'b> {
 }
 
 pub fn visit<B: Backend>(doc: &Document, backend: &mut B) {
+  let _span = span!(INFO, "eval");
   let ctx = Ctx {
     doc,
     resolving_xref: RefCell::new(false),
@@ -60,6 +61,12 @@ fn eval_doc_content(ctx: &Ctx, backend: &mut impl Backend) {
 }
 
 fn eval_section(section: &Section, ctx: &Ctx, backend: &mut impl Backend) {
+  let _span = span!(
+    DEBUG,
+    "section",
+    level = section.level,
+    start = section.meta.start
+  );
   backend.enter_section(section);
   backend.enter_section_heading(section);
   section
@@ -325,10 +332,7 @@ fn eval_block(block: &Block, ctx: &Ctx, backend: &mut impl Backend) {
     }
     (Context::TableOfContents, _) => eval_toc_at(&[TocPosition::Macro], ctx, backend),
     (Context::Comment, _) => {}
-    _ => {
-      dbg!(block.context, &block.content);
-      todo!();
-    }
+    _ => backend.visit_unknown_block(block),
   }
 }
 
@@ -383,7 +387,11 @@ fn eval_inline(inline: &InlineNode, ctx: &Ctx, backend: &mut impl Backend) {
     Macro(Footnote { id, text }) => {
       backend.enter_footnote(id.as_deref(), text.as_ref().map(|t| t.as_slice()));
       if let Some(text) = text {
-        text.iter().for_each(|node| eval_inline(node, ctx, backend));
+        let mut text_backend = backend.nested_backend(NestedContext::FootnoteText);
+        text
+          .iter()
+          .for_each(|node| eval_inline(node, ctx, &mut text_backend));
+        backend.visit_nested_result(text_backend, NestedContext::FootnoteText);
       }
       backend.exit_footnote(id.as_deref(), text.as_ref().map(|t| t.as_slice()));
     }
@@ -403,14 +411,23 @@ fn eval_inline(inline: &InlineNode, ctx: &Ctx, backend: &mut impl Backend) {
     Macro(Keyboard { keys, .. }) => {
       backend.visit_keyboard_macro(&keys.iter().map(|s| s.as_str()).collect::<Vec<&str>>())
     }
+    Macro(Stem { notation, text }) => backend.visit_stem_macro(text, *notation),
     Macro(Menu(items)) => {
       backend.visit_menu_macro(&items.iter().map(|s| s.src.as_str()).collect::<Vec<&str>>())
     }
-    Macro(Xref { target, linktext, kind }) => {
+    Macro(Xref { target, linktext, kind, attrs }) => {
+      let target =
+        &*utils::xref::resolve_target(&target.src, target.loc.include_depth, &ctx.doc.meta);
       let anchors = ctx.doc.anchors.borrow();
-      let anchor = anchors.get(utils::xref::get_id(&target.src));
+      let anchor = anchors.get(&*utils::xref::resolve_id(target, &ctx.doc.meta));
       let is_biblio = anchor.map(|a| a.is_biblio).unwrap_or(false);
-      backend.enter_xref(target, linktext.as_ref().map(|t| t.as_slice()), *kind);
+      backend.enter_xref(
+        target,
+        linktext.as_ref().map(|t| t.as_slice()),
+        *kind,
+        attrs.as_ref(),
+        anchor,
+      );
       if ctx.resolving_xref.replace(true) {
         backend.visit_missing_xref(target, *kind, ctx.doc.title.as_ref());
       } else if let Some(text) = anchor
@@ -458,6 +475,7 @@ fn eval_inline(inline: &InlineNode, ctx: &Ctx, backend: &mut impl Backend) {
     Symbol(kind) => backend.visit_symbol(*kind),
     Macro(Icon { target, attrs }) => backend.visit_icon_macro(target, attrs),
     LineComment(_) | Discarded => {}
+    _ => backend.visit_unknown_inline(inline),
   }
 }
 
@@ -481,9 +499,9 @@ fn eval_table_row(row: &Row, section: TableSection, ctx: &Ctx, backend: &mut imp
         nodes.iter().for_each(|n| eval_inline(n, ctx, backend));
       }
       CellContent::AsciiDoc(document) => {
-        let mut cell_backend = backend.asciidoc_table_cell_backend();
+        let mut cell_backend = backend.nested_backend(NestedContext::AsciidocTableCell);
         visit(document, &mut cell_backend);
-        backend.visit_asciidoc_table_cell_result(cell_backend);
+        backend.visit_nested_result(cell_backend, NestedContext::AsciidocTableCell);
       }
     }
     backend.exit_table_cell(cell, section);
@@ -509,7 +527,12 @@ fn eval_toc_level(nodes: &[TocNode], ctx: &Ctx, backend: &mut impl Backend) {
     nodes.iter().for_each(|node| {
       backend.enter_toc_node(node);
       backend.enter_toc_content(&node.title);
-      node.title.iter().for_each(|n| eval_inline(n, ctx, backend));
+      let mut entry_backend = backend.nested_backend(NestedContext::TocEntry);
+      node
+        .title
+        .iter()
+        .for_each(|n| eval_inline(n, ctx, &mut entry_backend));
+      backend.visit_nested_result(entry_backend, NestedContext::TocEntry);
       backend.exit_toc_content(&node.title);
       eval_toc_level(&node.children, ctx, backend);
       backend.exit_toc_node(node);
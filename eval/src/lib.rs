@@ -9,4 +9,5 @@ mod internal {
   pub use asciidork_ast::variants::inline::*;
   pub use asciidork_ast::variants::r#macro::*;
   pub use asciidork_backend::prelude::*;
+  pub(crate) use asciidork_core::span;
 }
@@ -12,7 +12,7 @@ impl<'arena> ChunkMetaExt<'arena> for ChunkMeta<'arena> {
   }
 
   fn block_paragraph_context(&self, lines: &mut ContiguousLines) -> BlockContext {
-    let uniform_indented = lines.trim_uniform_leading_whitespace();
+    let indented = lines.trim_common_leading_whitespace();
 
     // line from block attrs takes precedence
     if let Some(block_style) = self.attrs.block_style() {
@@ -35,7 +35,7 @@ impl<'arena> ChunkMetaExt<'arena> for ChunkMeta<'arena> {
     }
 
     // https://docs.asciidoctor.org/asciidoc/latest/verbatim/listing-blocks/#indent-method
-    if uniform_indented || lines.current_satisfies(Line::is_indented) {
+    if indented || lines.current_satisfies(Line::is_indented) {
       BlockContext::Literal
     } else {
       BlockContext::Paragraph
@@ -154,6 +154,13 @@ impl<'arena> ContiguousLines<'arena> {
     penult.ends(DoubleQuote)
   }
 
+  /// every line starts with `>`, followed by a space or nothing
+  pub fn is_markdown_quote(&self) -> bool {
+    self.lines.iter().all(|line| {
+      line.starts(GreaterThan) && line.nth_token(1).is_none_or(|token| token.kind(Whitespace))
+    })
+  }
+
   pub fn starts_list(&self) -> bool {
     for line in self.lines.iter() {
       if line.starts_list_item() {
@@ -233,22 +240,18 @@ impl<'arena> ContiguousLines<'arena> {
     }
   }
 
-  pub fn trim_uniform_leading_whitespace(&mut self) -> bool {
-    if self.is_empty() || !self.first().unwrap().starts(Whitespace) {
-      return false;
-    }
-    let len = self.first().unwrap().current_token().unwrap().lexeme.len();
-    if !self
-      .lines
-      .iter()
-      .all(|l| l.current_is_len(Whitespace, len) && l.num_tokens() > 1)
+  /// strips the indentation shared by every line, so that an indented
+  /// literal paragraph keeps only its relative indentation
+  pub fn trim_common_leading_whitespace(&mut self) -> bool {
+    if self.is_empty()
+      || !self
+        .lines
+        .iter()
+        .all(|l| l.starts(Whitespace) && l.num_tokens() > 1)
     {
       return false;
     }
-
-    for line in self.lines.iter_mut() {
-      line.discard_assert(Whitespace);
-    }
+    self.set_indentation(0);
     true
   }
 
@@ -316,4 +319,41 @@ mod tests {
       expect_eq!(lines.is_quoted_paragraph(), expected, from: input);
     }
   }
+
+  #[test]
+  fn test_is_markdown_quote() {
+    let cases = vec![
+      ("> foo\n> bar", true),
+      ("> foo\n>\n> > bar", true),
+      ("> foo\nbar", false),
+      (">foo", false),
+      ("foo\n> bar", false),
+    ];
+    for (input, expected) in cases {
+      let mut parser = test_parser!(input);
+      let lines = parser.read_lines().unwrap().unwrap();
+      expect_eq!(lines.is_markdown_quote(), expected, from: input);
+    }
+  }
+
+  #[test]
+  fn test_trim_common_leading_whitespace() {
+    let cases = vec![
+      ("  foo\n  bar", true, "foo\nbar"),
+      ("   foo\n  bar\n    baz", true, " foo\nbar\n  baz"),
+      ("  foo\nbar", false, "  foo\nbar"),
+      ("foo\n  bar", false, "foo\n  bar"),
+    ];
+    for (input, expected_trimmed, expected_src) in cases {
+      let mut parser = test_parser!(input);
+      let mut lines = parser.read_lines().unwrap().unwrap();
+      expect_eq!(lines.trim_common_leading_whitespace(), expected_trimmed, from: input);
+      let src = lines
+        .iter()
+        .map(|l| l.reassemble_src())
+        .collect::<Vec<_>>()
+        .join("\n");
+      expect_eq!(src, expected_src, from: input);
+    }
+  }
 }
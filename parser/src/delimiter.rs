@@ -2,7 +2,8 @@ use crate::internal::*;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Delimiter {
-  BlockQuote,
+  /// holds the length of the delimiter, which only closes on an exact match
+  BlockQuote(u32),
   Example,
   Open,
   Sidebar,
@@ -18,7 +19,7 @@ impl From<Delimiter> for BlockContext {
       Delimiter::Sidebar => BlockContext::Sidebar,
       Delimiter::Open => BlockContext::Open,
       Delimiter::Example => BlockContext::Example,
-      Delimiter::BlockQuote => BlockContext::BlockQuote,
+      Delimiter::BlockQuote(_) => BlockContext::BlockQuote,
       Delimiter::Listing => BlockContext::Listing,
       Delimiter::Literal => BlockContext::Literal,
       Delimiter::Passthrough => BlockContext::Passthrough,
@@ -35,7 +36,7 @@ impl Token<'_> {
     match self.lexeme.as_str() {
       "****" => Some(Delimiter::Sidebar),
       "====" => Some(Delimiter::Example),
-      "____" => Some(Delimiter::BlockQuote),
+      quote if quote.starts_with('_') => Some(Delimiter::BlockQuote(quote.len() as u32)),
       "----" => Some(Delimiter::Listing),
       "...." => Some(Delimiter::Literal),
       "++++" => Some(Delimiter::Passthrough),
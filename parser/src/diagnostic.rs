@@ -2,6 +2,8 @@ use crate::internal::*;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Diagnostic {
+  pub severity: Severity,
+  /// 0 for errors not tied to a line of the source, e.g. in the job settings
   pub line_num: u32,
   pub line: String,
   pub message: String,
@@ -10,10 +12,19 @@ pub struct Diagnostic {
   pub source_file: SourceFile,
 }
 
+/// NB: errors are only fatal in strict mode, otherwise
+/// they are collected as warnings in the `ParseResult`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+  Warning,
+  Error,
+}
+
 impl Parser<'_> {
   pub(crate) fn err_at(&self, message: impl Into<String>, start: u32, end: u32) -> Result<()> {
     let (line_num, offset) = self.lexer.line_number_with_offset(start);
     self.handle_err(Diagnostic {
+      severity: Severity::Error,
       line_num,
       line: self.lexer.line_of(start).to_string(),
       message: message.into(),
@@ -28,6 +39,7 @@ impl Parser<'_> {
     let (line_num, offset) = self.lexer.line_number_with_offset(start);
     let line = line.reassemble_src().to_string();
     self.handle_err(Diagnostic {
+      severity: Severity::Error,
       line_num,
       message: message.into(),
       underline_start: offset,
@@ -41,6 +53,7 @@ impl Parser<'_> {
     let (line_num, offset) = self.lexer.line_number_with_offset(start);
     let line = self.lexer.line_of(start);
     self.handle_err(Diagnostic {
+      severity: Severity::Error,
       line_num,
       message: message.into(),
       underline_start: offset,
@@ -67,6 +80,7 @@ impl Parser<'_> {
       }
       if line.starts_with(key) {
         return self.handle_err(Diagnostic {
+          severity: Severity::Error,
           line_num: idx as u32 + 1,
           line: line.to_string(),
           message: message.into(),
@@ -90,6 +104,7 @@ impl Parser<'_> {
     let line = self.lexer.line_of(line_start);
     if let Some(idx) = line.find(pattern) {
       return self.handle_err(Diagnostic {
+        severity: Severity::Error,
         line_num,
         line: line.to_string(),
         message: message.into(),
@@ -99,6 +114,7 @@ impl Parser<'_> {
       });
     }
     self.handle_err(Diagnostic {
+      severity: Severity::Error,
       line_num,
       line: line.to_string(),
       message: message.into(),
@@ -112,9 +128,30 @@ impl Parser<'_> {
     self.err_at(message, loc.start, loc.end)
   }
 
+  /// like `err_at_loc`, but for a `loc` in any source, not only the one
+  /// being lexed, e.g. when diagnosing the document after parsing
+  pub(crate) fn err_at_source_loc(
+    &self,
+    message: impl Into<String>,
+    loc: SourceLocation,
+  ) -> Result<()> {
+    let idx = loc.include_depth;
+    let (line_num, offset) = self.lexer.line_number_with_offset_in(idx, loc.start);
+    self.handle_err(Diagnostic {
+      severity: Severity::Error,
+      line_num,
+      line: self.lexer.line_of_in(idx, loc.start).to_string(),
+      message: message.into(),
+      underline_start: offset,
+      underline_width: loc.end - loc.start,
+      source_file: self.lexer.source_file_at(idx).clone(),
+    })
+  }
+
   pub(crate) fn err_token_full(&self, message: impl Into<String>, token: &Token) -> Result<()> {
     let (line_num, offset) = self.lexer.line_number_with_offset(token.loc.start);
     self.handle_err(Diagnostic {
+      severity: Severity::Error,
       line_num,
       line: self.lexer.line_of(token.loc.start).to_string(),
       message: message.into(),
@@ -127,6 +164,7 @@ impl Parser<'_> {
   pub(crate) fn err_token_start(&self, message: impl Into<String>, token: &Token) -> Result<()> {
     let (line_num, offset) = self.lexer.line_number_with_offset(token.loc.start);
     self.handle_err(Diagnostic {
+      severity: Severity::Error,
       line_num,
       line: self.lexer.line_of(token.loc.start).to_string(),
       message: message.into(),
@@ -140,6 +178,7 @@ impl Parser<'_> {
     let location = token.map_or_else(|| self.lexer.loc(), |t| t.loc);
     let (line_num, offset) = self.lexer.line_number_with_offset(location.start);
     self.handle_err(Diagnostic {
+      severity: Severity::Error,
       line_num,
       line: self.lexer.line_of(location.start).to_string(),
       message: message.into(),
@@ -153,14 +192,44 @@ impl Parser<'_> {
     self.handle_err(diagnostic)
   }
 
+  /// records a diagnostic that never fails the parse, even in strict mode
+  pub(crate) fn warn_at(&self, message: impl Into<String>, start: u32, end: u32) {
+    let (line_num, offset) = self.lexer.line_number_with_offset(start);
+    self.push_diagnostic(Diagnostic {
+      severity: Severity::Warning,
+      line_num,
+      line: self.lexer.line_of(start).to_string(),
+      message: message.into(),
+      underline_start: offset,
+      underline_width: end - start,
+      source_file: self.lexer.source_file().clone(),
+    });
+  }
+
+  pub(crate) fn warn_token_full(&self, message: impl Into<String>, token: &Token) {
+    self.warn_at(message, token.loc.start, token.loc.end);
+  }
+
   fn handle_err(&self, err: Diagnostic) -> Result<()> {
     if self.strict {
+      self.notify_diagnostic_sink(&err);
       Err(err)
     } else {
-      self.errors.borrow_mut().push(err);
+      self.push_diagnostic(err);
       Ok(())
     }
   }
+
+  pub(crate) fn push_diagnostic(&self, diagnostic: Diagnostic) {
+    self.notify_diagnostic_sink(&diagnostic);
+    self.errors.borrow_mut().push(diagnostic);
+  }
+
+  pub(crate) fn notify_diagnostic_sink(&self, diagnostic: &Diagnostic) {
+    if let Some(sink) = &self.diagnostic_sink {
+      sink(diagnostic);
+    }
+  }
 }
 
 pub trait DiagnosticColor {
@@ -186,6 +255,14 @@ impl Diagnostic {
   }
 
   pub fn plain_text_with<C: DiagnosticColor>(&self, colorizer: C) -> String {
+    if self.line_num == 0 {
+      return format!(
+        " {}{}\n  = {}\n",
+        colorizer.line_num("--> "),
+        colorizer.line_num(self.source_file.display_name()),
+        colorizer.message(&self.message),
+      );
+    }
     let line_num_pad = match self.line_num {
       n if n < 10 => 4,
       n if n < 100 => 5,
@@ -197,7 +274,7 @@ impl Diagnostic {
       "{}{}{}{}{}{}{}\n{}{}\n{} {} {}\n{}{} {}{} {}\n",
       " ".repeat((line_num_pad - 3) as usize),
       colorizer.line_num("--> "),
-      colorizer.line_num(self.source_file.file_name()),
+      colorizer.line_num(self.source_file.display_name()),
       colorizer.line_num(":"),
       colorizer.line_num(self.line_num.to_string()),
       colorizer.line_num(":"),
@@ -9,6 +9,10 @@ pub struct RootLexer<'arena> {
   source_stack: Vec<u16>,
   sources: BumpVec<'arena, SourceLexer<'arena>>,
   tmp_buf: Option<(SourceLexer<'arena>, BufLoc)>,
+  /// every run of source bytes consumed, when parsing in full fidelity mode
+  trivia: Option<BumpVec<'arena, Trivia<'arena>>>,
+  /// the closing delimiter line of the verbatim block being lexed, if any
+  verbatim_delimiter: Option<(u32, u8)>,
 }
 
 #[derive(Debug)]
@@ -24,8 +28,10 @@ impl<'arena> RootLexer<'arena> {
       idx: 0,
       next_idx: None,
       source_stack: Vec::new(),
-      sources: bvec![in bump; SourceLexer::new(src, file, 0, None, bump)],
+      sources: bvec![in bump; SourceLexer::new(src, file, None, None, None, bump)],
       tmp_buf: None,
+      trivia: None,
+      verbatim_delimiter: None,
     }
   }
 
@@ -37,6 +43,8 @@ impl<'arena> RootLexer<'arena> {
       source_stack: Vec::new(),
       sources: bvec![in bump; SourceLexer::from_str(src, file, bump)],
       tmp_buf: None,
+      trivia: None,
+      verbatim_delimiter: None,
     }
   }
 
@@ -48,13 +56,15 @@ impl<'arena> RootLexer<'arena> {
       source_stack: Vec::new(),
       sources: bvec![in bump; SourceLexer::from_byte_slice(bytes, file, bump)],
       tmp_buf: None,
+      trivia: None,
+      verbatim_delimiter: None,
     }
   }
 
   pub fn push_source(
     &mut self,
     src_file: SourceFile,
-    leveloffset: i8,
+    leveloffset: Option<AttrValue>,
     max_include_depth: Option<u16>,
     mut src_bytes: BumpVec<'arena, u8>,
   ) {
@@ -64,13 +74,16 @@ impl<'arena> RootLexer<'arena> {
     if src_bytes.last() != Some(&b'\n') {
       src_bytes.push(b'\n');
     }
-    self.sources.push(SourceLexer::new(
+    let mut source = SourceLexer::new(
       src_bytes,
       src_file,
       leveloffset,
+      Some(self.idx),
       max_include_depth,
       self.bump,
-    ));
+    );
+    source.chunked = source.src.len() > CHUNKED_INCLUDE_LEN;
+    self.sources.push(source);
     let next_idx = self.sources.len() as u16 - 1;
     self.next_idx = Some(next_idx);
   }
@@ -79,6 +92,16 @@ impl<'arena> RootLexer<'arena> {
     self.tmp_buf = Some((SourceLexer::from_str(buf, SourceFile::Tmp, self.bump), loc));
   }
 
+  /// lex verbatim lines in chunks (for large includes) until the
+  /// delimiter line, or stop doing so if `None`
+  pub const fn lex_verbatim_until(&mut self, delimiter: Option<(u32, u8)>) {
+    self.verbatim_delimiter = delimiter;
+  }
+
+  pub fn source_is_chunked(&self, idx: u16) -> bool {
+    self.sources[idx as usize].chunked
+  }
+
   pub fn adjust_offset(&mut self, offset_adjustment: u32) {
     self.sources[self.idx as usize].offset = offset_adjustment;
   }
@@ -109,8 +132,24 @@ impl<'arena> RootLexer<'arena> {
       .map(|(i, _)| i as u16)
   }
 
-  pub fn leveloffset(&self, idx: u16) -> i8 {
-    self.sources[idx as usize].leveloffset
+  pub fn leveloffset(&self, idx: u16) -> Option<&AttrValue> {
+    self.sources[idx as usize].leveloffset.as_ref()
+  }
+
+  pub fn source_parent_idx(&self, idx: u16) -> Option<u16> {
+    self.sources[idx as usize].parent_idx
+  }
+
+  /// returns the index of the nearest source (starting with `idx` itself)
+  /// that was included with a `leveloffset` attr, or the primary source
+  pub fn leveloffset_scope(&self, mut idx: u16) -> u16 {
+    while let Some(parent_idx) = self.source_parent_idx(idx) {
+      if self.leveloffset(idx).is_some() {
+        break;
+      }
+      idx = parent_idx;
+    }
+    idx
   }
 
   pub fn at_newline(&self) -> bool {
@@ -158,6 +197,8 @@ impl<'arena> RootLexer<'arena> {
     self.maybe_advance_source();
     if self.sources[self.idx as usize].peek().is_some() {
       self.sources[self.idx as usize].pos += 1;
+      let end = self.sources[self.idx as usize].pos;
+      self.record_trivia(TriviaKind::Newline, end - 1, end);
     } else if let Some(prev_idx) = self.source_stack.pop() {
       self.idx = prev_idx;
       self.skip_byte();
@@ -173,7 +214,10 @@ impl<'arena> RootLexer<'arena> {
 
   pub fn consume_empty_lines(&mut self) {
     self.maybe_advance_source();
+    let start = self.sources[self.idx as usize].pos;
     self.sources[self.idx as usize].consume_empty_lines();
+    let end = self.sources[self.idx as usize].pos;
+    self.record_trivia(TriviaKind::BlankLines, start, end);
     if self.sources[self.idx as usize].is_eof() {
       if let Some(prev_idx) = self.source_stack.pop() {
         self.idx = prev_idx;
@@ -206,6 +250,14 @@ impl<'arena> RootLexer<'arena> {
     self.sources[self.idx as usize].line_of(location)
   }
 
+  pub fn line_of_in(&self, idx: u16, location: u32) -> BumpString<'arena> {
+    self.sources[idx as usize].line_of(location)
+  }
+
+  pub fn line_number_with_offset_in(&self, idx: u16, location: u32) -> (u32, u32) {
+    self.sources[idx as usize].line_number_with_offset(location)
+  }
+
   pub fn line_number(&self, location: u32) -> u32 {
     let (line_number, _) = self.line_number_with_offset(location);
     line_number
@@ -229,8 +281,22 @@ impl<'arena> RootLexer<'arena> {
       }
     }
     self.maybe_advance_source();
-    match self.sources[self.idx as usize].next_token() {
+    let source = &mut self.sources[self.idx as usize];
+    let start = source.pos;
+    if self.verbatim_delimiter.is_some() && source.at_delimiter_line() == self.verbatim_delimiter {
+      self.verbatim_delimiter = None;
+    }
+    let next = match self.verbatim_delimiter {
+      Some(_) => source.next_verbatim_token(),
+      None => source.next_token(),
+    };
+    match next {
       Some(mut token) => {
+        let kind = match token.kind {
+          TokenKind::Newline => TriviaKind::Newline,
+          _ => TriviaKind::Tokens,
+        };
+        self.record_trivia(kind, start, self.sources[self.idx as usize].pos);
         token.loc.include_depth = self.idx;
         token
       }
@@ -245,13 +311,104 @@ impl<'arena> RootLexer<'arena> {
   }
 
   pub fn truncate(&mut self) {
+    let start = self.sources[self.idx as usize].pos;
     self.sources[self.idx as usize].truncate();
+    let end = self.sources[self.idx as usize].pos;
+    self.record_trivia(TriviaKind::Unparsed, start, end);
+  }
+
+  /// start recording trivia, for full fidelity mode
+  pub fn record_all_trivia(&mut self) {
+    self.trivia = Some(BumpVec::new_in(self.bump));
+  }
+
+  /// the recorded trivia, grouped by source, each in source order
+  pub fn take_trivia(&mut self) -> Option<BumpVec<'arena, Trivia<'arena>>> {
+    let mut trivia = self.trivia.take()?;
+    trivia.sort_by_key(|t| (t.loc.include_depth, t.loc.start));
+    Some(trivia)
+  }
+
+  /// relabels a line recorded as `TriviaKind::Tokens`, once
+  /// the parser has recognized what kind of line it is
+  pub fn classify_trivia(&mut self, kind: TriviaKind, loc: SourceLocation) {
+    let Some(trivia) = self.trivia.as_mut() else {
+      return;
+    };
+    for recorded in trivia.iter_mut().rev() {
+      if recorded.loc.include_depth != loc.include_depth {
+        continue;
+      }
+      if recorded.loc.start == loc.start && recorded.loc.end == loc.end {
+        if recorded.kind == TriviaKind::Tokens {
+          recorded.kind = kind;
+        }
+        return;
+      }
+      // trivia of a single source is recorded in order
+      if recorded.loc.start < loc.start {
+        return;
+      }
+    }
+  }
+
+  /// records the bytes of the current source from `start` to `end`, merging
+  /// consecutive tokens, and the two bytes of a `\r\n`, into a single trivia
+  fn record_trivia(&mut self, kind: TriviaKind, start: u32, end: u32) {
+    let Some(trivia) = self.trivia.as_mut() else {
+      return;
+    };
+    if start == end {
+      return;
+    }
+    let source = &self.sources[self.idx as usize];
+    let raw = std::str::from_utf8(&source.src[start as usize..end as usize]).unwrap();
+    let loc = SourceLocation::new_depth(start + source.offset, end + source.offset, self.idx);
+    if let Some(last) = trivia.last_mut() {
+      let continues = last.loc.include_depth == loc.include_depth && last.loc.end == loc.start;
+      let merges = match kind {
+        TriviaKind::Tokens => last.kind == TriviaKind::Tokens,
+        TriviaKind::Newline => last.kind == TriviaKind::Newline && last.raw == "\r",
+        _ => false,
+      };
+      if continues && merges {
+        last.loc.end = loc.end;
+        last.raw.push_str(raw);
+        return;
+      }
+    }
+    trivia.push(Trivia {
+      kind,
+      loc,
+      raw: BumpString::from_str_in(raw, self.bump),
+    });
   }
 
   pub const fn include_depth(&self) -> u16 {
     self.idx
   }
 
+  pub fn primary_source_len(&self) -> usize {
+    self.sources[0].src.len()
+  }
+
+  pub fn primary_source_pos(&self) -> usize {
+    self.sources[0].pos as usize
+  }
+
+  pub fn num_sources(&self) -> usize {
+    self.sources.len()
+  }
+
+  /// every source lexed, indexed by `SourceLocation::include_depth`
+  pub fn into_sources(self) -> Vec<&'arena str> {
+    self
+      .sources
+      .into_iter()
+      .map(SourceLexer::into_source)
+      .collect()
+  }
+
   pub fn max_include_depth(&self) -> Option<(u16, u16)> {
     self
       .sources
@@ -562,6 +719,7 @@ mod tests {
       ("****", vec![(DelimiterLine, "****")]),
       ("====", vec![(DelimiterLine, "====")]),
       ("____", vec![(DelimiterLine, "____")]),
+      ("______", vec![(DelimiterLine, "______")]),
       ("----", vec![(DelimiterLine, "----")]),
       ("----\r\n", vec![(DelimiterLine, "----"), (Newline, "\r\n")]),
       ("....", vec![(DelimiterLine, "....")]),
@@ -573,6 +731,41 @@ mod tests {
     ]);
   }
 
+  #[test]
+  fn test_chunked_verbatim_tokens() {
+    let input = "foo bar.baz\n  x -> y // <1>\n\\*a* b\n----\nc d";
+    let mut lexer = test_lexer!(input);
+    lexer.sources[0].chunked = true;
+    lexer.lex_verbatim_until(Some((4, b'-')));
+    let expected = vec![
+      (Word, "foo"),
+      (Word, " bar.baz"),
+      (Newline, "\n"),
+      (Whitespace, "  "),
+      (Word, "x -"),
+      (GreaterThan, ">"),
+      (Word, " y "),
+      (ForwardSlashes, "//"),
+      (Whitespace, " "),
+      (CalloutNumber, "<1>"),
+      (Newline, "\n"),
+      (Backslash, "\\"),
+      (Star, "*"),
+      (Word, "a* b"),
+      (Newline, "\n"),
+      (DelimiterLine, "----"),
+      (Newline, "\n"),
+      (Word, "c"),
+      (Whitespace, " "),
+      (Word, "d"),
+    ];
+    for (kind, lexeme) in expected {
+      let token = lexer.next_token();
+      assert_eq!((token.kind, token.lexeme.as_str()), (kind, lexeme));
+    }
+    assert_eq!(lexer.next_token().kind, Eof);
+  }
+
   #[test]
   fn test_newlines() {
     assert_token_cases!([
@@ -864,4 +1057,25 @@ mod tests {
     lexer.consume_empty_lines();
     assert!(lexer.is_eof());
   }
+
+  proptest::proptest! {
+    #[test]
+    fn tokens_reassemble_input(input in test_utils::strategies::lexer_input()) {
+      let mut lexer = test_lexer!(&input);
+      let mut reassembled = String::with_capacity(input.len());
+      let mut prev_end = 0;
+      loop {
+        let token = lexer.next_token();
+        if token.kind == Eof {
+          break;
+        }
+        proptest::prop_assert_eq!(token.loc.start, prev_end, "gap before {:?}", token);
+        proptest::prop_assert!(token.loc.end as usize <= input.len(), "{:?} out of bounds", token);
+        proptest::prop_assert_eq!(token.loc.end - token.loc.start, token.lexeme.len() as u32);
+        reassembled.push_str(&token.lexeme);
+        prev_end = token.loc.end;
+      }
+      proptest::prop_assert_eq!(reassembled, input);
+    }
+  }
 }
@@ -9,15 +9,21 @@ pub struct SourceLexer<'arena> {
   pub pos: u32,
   pub offset: u32,
   pub file: SourceFile,
-  pub leveloffset: i8,
+  /// the `leveloffset` attr of the include directive that pulled in this source
+  pub leveloffset: Option<AttrValue>,
+  /// index of the source containing the include directive for this source
+  pub parent_idx: Option<u16>,
   pub max_include_depth: Option<u16>,
+  /// whether verbatim lines are lexed in chunks, see `CHUNKED_INCLUDE_LEN`
+  pub chunked: bool,
 }
 
 impl<'arena> SourceLexer<'arena> {
   pub const fn new(
     src: BumpVec<'arena, u8>,
     file: SourceFile,
-    leveloffset: i8,
+    leveloffset: Option<AttrValue>,
+    parent_idx: Option<u16>,
     max_include_depth: Option<u16>,
     bump: &'arena Bump,
   ) -> Self {
@@ -27,8 +33,10 @@ impl<'arena> SourceLexer<'arena> {
       pos: 0,
       offset: 0,
       leveloffset,
+      parent_idx,
       file,
       max_include_depth,
+      chunked: false,
     }
   }
 
@@ -42,12 +50,18 @@ impl<'arena> SourceLexer<'arena> {
       src: BumpVec::from_iter_in(bytes.iter().copied(), bump),
       pos: 0,
       offset: 0,
-      leveloffset: 0,
+      leveloffset: None,
+      parent_idx: None,
       file,
       max_include_depth: None,
+      chunked: false,
     }
   }
 
+  pub fn into_source(self) -> &'arena str {
+    std::str::from_utf8(self.src.into_bump_slice()).unwrap_or_default()
+  }
+
   pub fn next_token(&mut self) -> Option<Token<'arena>> {
     if let Some(token) = self.delimiter_line() {
       return Some(token);
@@ -103,6 +117,34 @@ impl<'arena> SourceLexer<'arena> {
     }
   }
 
+  /// lexes the rest of a verbatim line as a single token, stopping only
+  /// at the bytes that verbatim substitutions need to see as tokens
+  pub fn next_verbatim_token(&mut self) -> Option<Token<'arena>> {
+    // a backslash escapes the whole token following it
+    if !self.chunked || self.at_line_start() || self.src[self.pos as usize - 1] == b'\\' {
+      return self.next_token();
+    }
+    let start = self.pos;
+    let rest = &self.src[start as usize..];
+    let stop = rest
+      .iter()
+      .position(|b| matches!(b, b'<' | b'>' | b'&' | b'\\' | b'\n' | b'\r'))
+      .unwrap_or(rest.len());
+    let mut end = stop;
+    if rest.get(stop) == Some(&b'<') {
+      // leave a possible callout, and the comment tucked before it, e.g. `// <1>`
+      end = rest[..stop].trim_ascii_end().len();
+      while end > 0 && !matches!(rest[end - 1], b' ' | b'\t') {
+        end -= 1;
+      }
+    }
+    if end == 0 {
+      return self.next_token();
+    }
+    self.pos += end as u32;
+    Some(self.token(Word, start, self.pos))
+  }
+
   pub fn codepoint(&mut self, n: u32) -> Token<'arena> {
     debug_assert!(n > 1);
     self.pos += n - 1;
@@ -155,6 +197,17 @@ impl<'arena> SourceLexer<'arena> {
     ];
     match sequence {
       [Some(b'-'), Some(b'-'), Some(b'\n' | b'\r') | None, _, _] => Some((2, b'-')),
+      // quote blocks may be lengthened, to nest them
+      [Some(b'_'), Some(b'_'), Some(b'_'), Some(b'_'), Some(b'_')] => {
+        let mut len = 5;
+        while self.nth(len) == Some(b'_') {
+          len += 1;
+        }
+        match self.nth(len) {
+          Some(b'\n' | b'\r') | None => Some((len, b'_')),
+          _ => None,
+        }
+      }
       [Some(b'*'), Some(b'*'), Some(b'*'), Some(b'*'), Some(b'\n' | b'\r') | None]
       | [Some(b'_'), Some(b'_'), Some(b'_'), Some(b'_'), Some(b'\n' | b'\r') | None]
       | [Some(b'-'), Some(b'-'), Some(b'-'), Some(b'-'), Some(b'\n' | b'\r') | None]
@@ -168,8 +221,9 @@ impl<'arena> SourceLexer<'arena> {
     }
   }
 
+  /// skips the rest of the source, retaining the bytes for diagnostics
   pub fn truncate(&mut self) {
-    self.src.truncate(self.offset as usize);
+    self.pos = self.src.len() as u32;
   }
 
   pub fn raw_lines(&'arena self) -> impl Iterator<Item = &'arena str> {
@@ -348,14 +402,18 @@ impl<'arena> SourceLexer<'arena> {
     matches!(
       lexeme,
       b"footnote"
+        | b"footnoteref"
         | b"image"
         | b"anchor"
+        | b"asciimath"
         | b"icon"
         | b"kbd"
+        | b"latexmath"
         | b"link"
         | b"pass"
         | b"btn"
         | b"menu"
+        | b"stem"
         | b"toc"
         | b"xref"
     )
@@ -627,6 +685,7 @@ impl Debug for SourceLexer<'_> {
       .field("pos", &self.pos)
       .field("offset", &self.offset)
       .field("leveloffset", &self.leveloffset)
+      .field("parent_idx", &self.parent_idx)
       .finish()
   }
 }
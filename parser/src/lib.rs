@@ -6,10 +6,12 @@ mod delimiter;
 mod deq;
 mod diagnostic;
 mod lexer;
+mod limits;
 mod line;
 mod list_context;
 mod parse_context;
 pub mod parser;
+mod parser_builder;
 pub mod regx;
 mod substitutions;
 mod tasks;
@@ -23,14 +25,16 @@ pub mod includes {
 }
 
 pub mod prelude {
-  pub use crate::diagnostic::{Diagnostic, DiagnosticColor};
+  pub use crate::diagnostic::{Diagnostic, DiagnosticColor, Severity};
   pub use crate::parser::{Parser, SourceFile};
+  pub use crate::parser_builder::{DiagnosticSink, ParserBuilder};
   pub use asciidork_core::Path;
   pub use bumpalo::Bump;
 }
 
-pub use diagnostic::{Diagnostic, DiagnosticColor};
+pub use diagnostic::{Diagnostic, DiagnosticColor, Severity};
 pub use parser::Parser;
+pub use parser_builder::{DiagnosticSink, ParserBuilder};
 
 mod internal {
   pub use crate::chunk::*;
@@ -39,10 +43,12 @@ mod internal {
   pub use crate::deq::*;
   pub use crate::diagnostic::*;
   pub use crate::lexer::*;
+  pub use crate::limits::*;
   pub use crate::line::*;
   pub use crate::list_context::*;
   pub use crate::parse_context::*;
   pub use crate::parser::*;
+  pub use crate::parser_builder::*;
   pub use crate::regx;
   pub use crate::substitutions::*;
   pub use crate::tasks::collect_text::*;
@@ -50,8 +56,14 @@ mod internal {
   pub use crate::tasks::directives::includes::*;
   pub use crate::token::*;
   pub use crate::utils::bump::*;
+  pub use crate::utils::truncate::*;
   pub use asciidork_core::file;
-  pub use asciidork_core::{Author, DocType, JobAttr, JobSettings, Path, ReadAttr, SafeMode};
+  pub(crate) use asciidork_core::span;
+  pub use asciidork_core::{
+    content_hash, decode_entity, lookup_named_entity, Author, DocType, EntityLookup,
+    IncludeFingerprint, JobAttr, JobSettings, Path, ProgressEvent, ProgressHandler, ReadAttr,
+    SafeMode, SectionLevelPolicy,
+  };
   pub use ast::*;
   pub use smallvec::SmallVec;
   pub type Result<T> = std::result::Result<T, Diagnostic>;
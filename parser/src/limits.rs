@@ -0,0 +1,15 @@
+// Guards against input the parser can't represent, and against the
+// per-token overhead of lexing very large includes byte by byte.
+
+/// source locations are `u32` byte offsets, so no single source
+/// (the primary document, or any include) may exceed 4GiB
+pub const MAX_SOURCE_LEN: usize = u32::MAX as usize;
+
+/// sources are indexed by a `u16` (`SourceLocation::include_depth`),
+/// which limits the total number of includes in a document
+pub const MAX_SOURCES: usize = u16::MAX as usize;
+
+/// includes larger than this are lexed in chunks while inside a verbatim
+/// block body, one token per run of plain text instead of one per word
+/// or punctuation mark, so that multi-hundred-MB log files stay cheap
+pub const CHUNKED_INCLUDE_LEN: usize = 4 * 1024 * 1024;
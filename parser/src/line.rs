@@ -199,6 +199,11 @@ impl<'arena> Line<'arena> {
     self.starts(Dots) && self.iter().len() > 1 && self.peek_token().unwrap().not_kind(Whitespace)
   }
 
+  /// a title, attr list or anchor line preceding a block
+  pub fn is_block_meta(&self) -> bool {
+    self.is_chunk_title() || self.is_block_attr_list() || self.is_block_anchor()
+  }
+
   pub fn is_delimiter(&self, delimiter: Delimiter) -> bool {
     self.num_tokens() == 1 && self.current_token().unwrap().to_delimeter() == Some(delimiter)
   }
@@ -402,12 +407,20 @@ impl<'arena> Line<'arena> {
       // or else `foo __bar` would include an empty italic node
       // TODO: maybe that's only true for _single_ tok sequences?
       Some(0) => None,
-      Some(n) if self.nth_token(n + 1).not_kind(Word) => match ctx.specs() {
-        Some(specs) => self
-          .index_of_seq(specs)
-          .map_or(Some(n), |m| if m < n { None } else { Some(n) }),
-        None => Some(n),
-      },
+      Some(n)
+        if !self
+          .nth_token(n + 1)
+          .is_some_and(Token::starts_with_word_char) =>
+      {
+        match ctx.specs() {
+          Some(specs) => {
+            self
+              .index_of_seq(specs)
+              .map_or(Some(n), |m| if m < n { None } else { Some(n) })
+          }
+          None => Some(n),
+        }
+      }
       _ => None,
     }
   }
@@ -429,6 +442,33 @@ impl<'arena> Line<'arena> {
     SourceString::new(s, loc)
   }
 
+  /// Like `consume_to_string_until`, but keeps backslashes and unresolved
+  /// attr refs verbatim, except for a backslash escaping the `stop` token
+  #[must_use]
+  pub fn consume_to_string_until_unescaped(
+    &mut self,
+    stop: TokenKind,
+    bump: &'arena Bump,
+  ) -> SourceString<'arena> {
+    let mut loc = self.loc().expect("no tokens to consume").clamp_start();
+    let mut s = BumpString::new_in(bump);
+    while !self.is_empty() && !self.current_is(stop) {
+      let mut token = self.consume_current().unwrap();
+      if token.kind(Backslash) && self.current_is(stop) {
+        loc.extend(token.loc);
+        token = self.consume_current().unwrap();
+      } else if token.kind(AttrRef) {
+        // drop the tokens the attr ref was resolved to, they share its loc
+        while self.current_token().is_some_and(|t| t.loc == token.loc) {
+          self.discard(1);
+        }
+      }
+      s.push_str(&token.lexeme);
+      loc.extend(token.loc);
+    }
+    SourceString::new(s, loc)
+  }
+
   #[must_use]
   pub fn consume_to_string_until_one_of(
     &mut self,
@@ -567,6 +607,30 @@ impl<'arena> Line<'arena> {
     src
   }
 
+  /// maps a byte offset into the `reassemble_src()` of this line back to
+  /// a source location, offsets within a token whose lexeme was replaced,
+  /// e.g. by an attribute value, map to the start of that token
+  pub fn src_loc_at(&self, offset: usize) -> u32 {
+    let mut remaining = offset;
+    let mut loc = self.loc().map_or(0, |loc| loc.start);
+    for token in self
+      .tokens
+      .iter()
+      .filter(|t| !matches!(t.kind, AttrRef | Discard))
+    {
+      let len = token.lexeme.len();
+      if remaining < len {
+        if len == (token.loc.end - token.loc.start) as usize {
+          return token.loc.start + remaining as u32;
+        }
+        return token.loc.start;
+      }
+      remaining -= len;
+      loc = token.loc.end;
+    }
+    loc
+  }
+
   pub fn list_marker(&self) -> Option<ListMarker> {
     if self.is_comment() {
       return None;
@@ -593,7 +657,9 @@ impl<'arena> Line<'arena> {
         let captures = regx::REPEAT_STAR_LI_START.captures(&src)?;
         Some(ListMarker::Star(captures.get(1).unwrap().len() as u8))
       }
-      CalloutNumber if token.lexeme.as_bytes()[1] != b'!' => {
+      CalloutNumber
+        if token.lexeme.as_bytes()[1] != b'!' && second.kind(Whitespace) && third.is_some() =>
+      {
         Some(ListMarker::Callout(token.parse_callout_num()))
       }
       Digits if second.kind(Dots) && third.kind(Whitespace) => {
@@ -6,6 +6,8 @@ use crate::internal::*;
 pub(crate) struct ListContext {
   pub(crate) stack: ListStack,
   pub(crate) parsing_continuations: bool,
+  /// subs from the current list's `subs` attr, for its items' principal text
+  pub(crate) item_subs: Option<Substitutions>,
 }
 
 impl ListContext {
@@ -9,11 +9,13 @@ pub struct ParseContext<'arena> {
   pub delimiter: Option<Delimiter>,
   pub list: ListContext,
   pub section_level: u8,
-  pub leveloffset: i8,
+  /// leveloffsets set by attr entries, keyed by leveloffset scope source idx
+  pub leveloffsets: HashMap<u16, i8>,
   pub custom_line_comment: Option<SmallVec<[u8; 3]>>,
   pub anchor_ids: Rc<RefCell<HashSet<BumpString<'arena>>>>,
-  /// xrefs are only used for diagnosing errors
-  pub xrefs: Rc<RefCell<HashMap<BumpString<'arena>, SourceLocation>>>,
+  /// xrefs are only used for diagnosing errors, keyed by target and the
+  /// index of the source they're in, as targets resolve per source
+  pub xrefs: Rc<RefCell<HashMap<(BumpString<'arena>, u16), SourceLocation>>>,
   pub can_nest_blocks: bool,
   pub saw_toc_macro: bool,
   pub bibliography_ctx: BiblioContext,
@@ -21,7 +23,10 @@ pub struct ParseContext<'arena> {
   pub inline_ctx: InlineCtx,
   pub passthrus: BumpVec<'arena, Option<InlineNodes<'arena>>>,
   pub max_include_depth: u16,
+  pub section_level_policy: SectionLevelPolicy,
   pub ifdef_stack: BumpVec<'arena, BumpString<'arena>>,
+  /// the source of the `= Title` line, to tell if `doctitle` was overridden
+  pub doctitle_src: Option<BumpString<'arena>>,
   callouts: Rc<RefCell<BumpVec<'arena, Callout>>>,
 }
 
@@ -63,7 +68,7 @@ impl<'arena> ParseContext<'arena> {
       delimiter: None,
       list: ListContext::default(),
       section_level: 0,
-      leveloffset: 0,
+      leveloffsets: HashMap::new(),
       can_nest_blocks: true,
       callouts: Rc::new(RefCell::new(bvec![in bump])),
       custom_line_comment: None,
@@ -75,7 +80,9 @@ impl<'arena> ParseContext<'arena> {
       passthrus: BumpVec::new_in(bump),
       inline_ctx: InlineCtx::None,
       max_include_depth: 64,
+      section_level_policy: SectionLevelPolicy::default(),
       ifdef_stack: BumpVec::new_in(bump),
+      doctitle_src: None,
     }
   }
 
@@ -85,7 +92,7 @@ impl<'arena> ParseContext<'arena> {
       delimiter: None,
       list: ListContext::default(),
       section_level: 0,
-      leveloffset: 0,
+      leveloffsets: HashMap::new(),
       can_nest_blocks: true,
       callouts: Rc::clone(&self.callouts),
       custom_line_comment: None,
@@ -97,7 +104,9 @@ impl<'arena> ParseContext<'arena> {
       passthrus: BumpVec::new_in(bump),
       inline_ctx: InlineCtx::None,
       max_include_depth: 64,
+      section_level_policy: self.section_level_policy,
       ifdef_stack: BumpVec::new_in(bump),
+      doctitle_src: None,
     }
   }
 
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::{cell::RefCell, rc::Rc};
 
 use crate::internal::*;
@@ -12,23 +13,35 @@ pub struct Parser<'arena> {
   pub(super) errors: RefCell<Vec<Diagnostic>>,
   pub(super) strict: bool, // todo: naming...
   pub(super) include_resolver: Option<Box<dyn IncludeResolver>>,
+  pub(super) progress: Option<ProgressHandler>,
+  pub(super) blocks_parsed: usize,
+  pub(super) includes: Vec<IncludeFingerprint>,
+  pub(super) diagnostic_sink: Option<DiagnosticSink<'arena>>,
 }
 
 pub struct ParseResult<'arena> {
   pub document: Document<'arena>,
   pub warnings: Vec<Diagnostic>,
+  /// every file resolved by an include directive, for cache invalidation
+  pub includes: Vec<IncludeFingerprint>,
 }
 
 impl<'arena> Parser<'arena> {
+  pub fn builder(bump: &'arena Bump) -> ParserBuilder<'arena> {
+    ParserBuilder::new(bump)
+  }
+
+  /// shorthand for a [`ParserBuilder`] given only a source and file
   pub fn new(src: BumpVec<'arena, u8>, file: SourceFile, bump: &'arena Bump) -> Self {
-    Parser::from_lexer(Lexer::new(src, file, bump))
+    Parser::builder(bump).source(src).file(file).build()
   }
 
+  /// shorthand for a [`ParserBuilder`] given only a source and file
   pub fn from_str(src: &str, file: SourceFile, bump: &'arena Bump) -> Self {
-    Parser::from_lexer(Lexer::from_str(bump, file, src))
+    Parser::builder(bump).source_str(src).file(file).build()
   }
 
-  fn from_lexer(lexer: Lexer<'arena>) -> Self {
+  pub(crate) fn from_lexer(lexer: Lexer<'arena>) -> Self {
     let mut parser = Parser {
       bump: lexer.bump,
       document: Document::new(lexer.bump),
@@ -38,18 +51,28 @@ impl<'arena> Parser<'arena> {
       errors: RefCell::new(Vec::new()),
       strict: true,
       include_resolver: None,
+      progress: None,
+      blocks_parsed: 0,
+      includes: Vec::new(),
+      diagnostic_sink: None,
       lexer,
     };
     parser.set_source_file_attrs();
     parser
   }
 
-  pub fn apply_job_settings(&mut self, settings: JobSettings) {
+  pub fn apply_job_settings(&mut self, mut settings: JobSettings) {
+    self.resolve_job_attr_refs(&mut settings.job_attrs);
     if let Some(leveloffset) = settings.job_attrs.get("leveloffset") {
-      Parser::adjust_leveloffset(&mut self.ctx.leveloffset, &leveloffset.value);
+      self.set_leveloffset(0, &leveloffset.value);
     }
     self.strict = settings.strict;
+    if settings.full_fidelity {
+      self.lexer.record_all_trivia();
+    }
     self.ctx.max_include_depth = settings.job_attrs.u16("max-include-depth").unwrap_or(64);
+    self.ctx.section_level_policy = settings.section_level_policy;
+    self.progress = settings.on_progress.clone();
     self.document.meta = settings.into();
     self.set_source_file_attrs();
   }
@@ -68,15 +91,25 @@ impl<'arena> Parser<'arena> {
   }
 
   pub fn cell_parser(&mut self, src: BumpVec<'arena, u8>, offset: u32) -> Parser<'arena> {
-    let mut cell_parser = Parser::new(src, self.lexer.source_file().clone(), self.bump);
-    cell_parser.strict = self.strict;
-    cell_parser.lexer.adjust_offset(offset);
-    cell_parser.ctx = self.ctx.clone_for_cell(self.bump);
+    let mut cell_parser = self.nested_parser(src, offset);
+    cell_parser.ctx.table_cell_ctx = TableCellContext::AsciiDocCell;
     cell_parser.document.meta = self.document.meta.clone_for_cell();
-    cell_parser.document.anchors = Rc::clone(&self.document.anchors);
     cell_parser
   }
 
+  /// a parser for content lexed apart from the current source, e.g. the
+  /// lines of a markdown blockquote, stripped of their leading `> `
+  pub(crate) fn nested_parser(&mut self, src: BumpVec<'arena, u8>, offset: u32) -> Parser<'arena> {
+    let mut parser = Parser::new(src, self.lexer.source_file().clone(), self.bump);
+    parser.strict = self.strict;
+    parser.lexer.adjust_offset(offset);
+    parser.ctx = self.ctx.clone_for_cell(self.bump);
+    parser.ctx.table_cell_ctx = self.ctx.table_cell_ctx;
+    parser.document.meta = self.document.meta.clone();
+    parser.document.anchors = Rc::clone(&self.document.anchors);
+    parser
+  }
+
   pub(crate) fn loc(&self) -> SourceLocation {
     self
       .peeked_lines
@@ -135,7 +168,7 @@ impl<'arena> Parser<'arena> {
         }
       }
       lines.push(line);
-      if self.lexer.at_newline() {
+      if self.lexer.at_newline() || self.starts_chunked_verbatim_body(&lines) {
         break;
       }
     }
@@ -159,23 +192,59 @@ impl<'arena> Parser<'arena> {
 
     let mut additional_lines = BumpVec::new_in(self.bump);
     while !self.lexer.is_eof() && !self.at_delimiter(delimiter) {
-      additional_lines.push(self.read_line()?.unwrap());
+      // NB: a trailing directive line may be consumed without producing a line
+      let Some(line) = self.read_line()? else {
+        break;
+      };
+      // a directive line is replaced by the line following it, which may be the delimiter
+      let is_delimiter = line.is_delimiter(delimiter);
+      additional_lines.push(line);
+      if is_delimiter {
+        break;
+      }
+    }
+    if self.lexer.is_eof() {
+      // an unclosed block doesn't swallow the blank lines ending the document
+      while additional_lines.last().is_some_and(|line| line.is_empty()) {
+        additional_lines.pop();
+      }
     }
     lines.extend(additional_lines);
     Ok(Some(lines))
   }
 
+  /// whether the lines open a verbatim block whose body starts with a large
+  /// include, which is left unread until the block's subs are known, so that
+  /// it can be lexed in chunks (see `CHUNKED_INCLUDE_LEN`)
+  fn starts_chunked_verbatim_body(&self, lines: &Deq<'arena, Line<'arena>>) -> bool {
+    let [meta @ .., delimiter, body] = lines.as_slice() else {
+      return false;
+    };
+    let verbatim = |d| {
+      matches!(
+        d,
+        Delimiter::Listing | Delimiter::Literal | Delimiter::Passthrough
+      )
+    };
+    // inside a verbatim block, a delimiter line can only be closing it
+    !self.ctx.delimiter.is_some_and(verbatim)
+      && body
+        .loc()
+        .is_some_and(|loc| self.lexer.source_is_chunked(loc.include_depth))
+      && delimiter
+        .current_token()
+        .and_then(Token::to_delimeter)
+        .is_some_and(verbatim)
+      && meta.iter().all(Line::is_block_meta)
+  }
+
   fn at_delimiter(&self, delimiter: Delimiter) -> bool {
-    match delimiter {
-      Delimiter::BlockQuote => self.lexer.at_delimiter_line() == Some((4, b'_')),
-      Delimiter::Example => self.lexer.at_delimiter_line() == Some((4, b'=')),
-      Delimiter::Open => self.lexer.at_delimiter_line() == Some((2, b'-')),
-      Delimiter::Sidebar => self.lexer.at_delimiter_line() == Some((4, b'*')),
-      Delimiter::Listing => self.lexer.at_delimiter_line() == Some((4, b'-')),
-      Delimiter::Literal => self.lexer.at_delimiter_line() == Some((4, b'.')),
-      Delimiter::Passthrough => self.lexer.at_delimiter_line() == Some((4, b'+')),
-      Delimiter::Comment => self.lexer.at_delimiter_line() == Some((4, b'/')),
-    }
+    self.lexer.at_delimiter_line() == Some(delimiter_line(delimiter))
+  }
+
+  /// lexes the lines of a verbatim block body in chunks, until its closing delimiter
+  pub(crate) fn lex_verbatim_until(&mut self, delimiter: Option<Delimiter>) {
+    self.lexer.lex_verbatim_until(delimiter.map(delimiter_line));
   }
 
   pub(crate) fn restore_lines(&mut self, lines: ContiguousLines<'arena>) {
@@ -198,7 +267,35 @@ impl<'arena> Parser<'arena> {
   }
 
   pub fn parse(mut self) -> std::result::Result<ParseResult<'arena>, Vec<Diagnostic>> {
-    self.parse_document_header()?;
+    if self.lexer.primary_source_len() > MAX_SOURCE_LEN {
+      let diagnostic = Diagnostic {
+        severity: Severity::Error,
+        line_num: 1,
+        line: String::new(),
+        message: "Document exceeds maximum supported size of 4GiB".to_string(),
+        underline_start: 0,
+        underline_width: 0,
+        source_file: self.lexer.source_file().clone(),
+      };
+      self.notify_diagnostic_sink(&diagnostic);
+      return Err(vec![diagnostic]);
+    }
+    // errors in the job settings are found before there's anywhere to
+    // return them from, so strict mode fails on them before parsing
+    if self.strict
+      && self
+        .errors
+        .get_mut()
+        .iter()
+        .any(|d| d.severity == Severity::Error)
+    {
+      return Err(std::mem::take(self.errors.get_mut()));
+    }
+    let _span = span!(INFO, "parse", file = self.lexer.source_file().file_name());
+    {
+      let _span = span!(DEBUG, "parse_header");
+      self.parse_document_header()?;
+    }
 
     // ensure we only read a single "paragraph" for `inline` doc_type
     // https://docs.asciidoctor.org/asciidoc/latest/document/doctype/#inline-doctype-rules
@@ -210,21 +307,35 @@ impl<'arena> Parser<'arena> {
       self.lexer.truncate();
     }
 
-    while let Some(chunk) = self.parse_chunk()? {
-      match chunk {
-        Chunk::Block(block) => self.document.content.push_block(block, self.bump),
-        Chunk::Section(section) => self.document.content.push_section(section, self.bump),
+    {
+      let _span = span!(DEBUG, "parse_body");
+      while let Some(chunk) = self.parse_chunk()? {
+        match chunk {
+          Chunk::Block(block) => self.document.content.push_block(block, self.bump),
+          Chunk::Section(section) => self.document.content.push_section(section, self.bump),
+        }
       }
     }
 
+    self.apply_doctitle_attr()?;
+
+    self.document.trivia = self.lexer.take_trivia();
+
+    self.report_finished();
+
     // clear the doc attrs so the backend can see them replayed in decl order
     self.document.meta.clear_doc_attrs();
 
-    self.diagnose_document()?;
+    {
+      let _span = span!(DEBUG, "diagnose");
+      self.diagnose_document()?;
+    }
 
+    self.document.sources = self.lexer.into_sources();
     Ok(ParseResult {
       document: self.document,
-      warnings: vec![],
+      warnings: self.errors.take(),
+      includes: self.includes,
     })
   }
 
@@ -239,30 +350,41 @@ impl<'arena> Parser<'arena> {
     &mut self,
     lines: &mut ContiguousLines<'arena>,
   ) -> Result<ChunkMeta<'arena>> {
-    if let Some(meta) = self.peeked_meta.take() {
-      return Ok(meta);
-    }
-    assert!(!lines.is_empty());
-    let start = lines.current_token().unwrap().loc.start;
-    let mut attrs = MultiAttrList::new_in(self.bump);
-    let mut title = None;
-    if !lines.current().unwrap().is_fully_unconsumed() {
-      return Ok(ChunkMeta { attrs, title, start });
-    }
+    let ChunkMeta { mut attrs, mut title, start } = match self.peeked_meta.take() {
+      // metadata separated from more metadata by a blank line is merged
+      Some(meta) if lines.current().is_some_and(Line::is_block_meta) => meta,
+      Some(meta) => return Ok(meta),
+      None => {
+        assert!(!lines.is_empty());
+        let start = lines.current_token().unwrap().loc.start;
+        let meta = ChunkMeta::empty(start, self.bump);
+        if !lines.current().unwrap().is_fully_unconsumed() {
+          return Ok(meta);
+        }
+        meta
+      }
+    };
     loop {
       match lines.current() {
         Some(line) if line.is_chunk_title() => {
           let mut line = lines.consume_current().unwrap();
+          self.classify_line_trivia(TriviaKind::BlockTitle, &line);
           line.discard_assert(TokenKind::Dots);
-          title = Some(self.parse_inlines(&mut line.into_lines())?);
+          // titles always receive normal (default) subs, regardless of the block
+          let restore = std::mem::take(&mut self.ctx.subs);
+          let parsed = self.parse_inlines(&mut line.into_lines());
+          self.ctx.subs = restore;
+          title = Some(parsed?);
         }
         Some(line) if line.is_block_attr_list() => {
           let mut line = lines.consume_current().unwrap();
+          self.classify_line_trivia(TriviaKind::AttrList, &line);
           line.discard_assert(TokenKind::OpenBracket);
           attrs.push(self.parse_block_attr_list(&mut line)?);
         }
         Some(line) if line.is_block_anchor() => {
           let mut line = lines.consume_current().unwrap();
+          self.classify_line_trivia(TriviaKind::BlockAnchor, &line);
           line.discard_assert(TokenKind::OpenBracket);
           line.discard_assert(TokenKind::OpenBracket);
           let anchor = self.parse_block_anchor(&mut line)?.unwrap();
@@ -314,8 +436,17 @@ pub enum DirectiveAction<'arena> {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SourceFile {
-  Stdin { cwd: Path },
+  Stdin {
+    cwd: Path,
+  },
   Path(Path),
+  /// a remote document, i.e. from an `include::https://...[]` directive
+  Uri(String),
+  /// in-memory content not backed by a file, named for diagnostics,
+  /// e.g. an editor buffer or a document assembled by a build tool
+  Virtual {
+    name: String,
+  },
   Tmp,
 }
 
@@ -324,15 +455,37 @@ impl SourceFile {
     match self {
       SourceFile::Stdin { .. } => "<stdin>",
       SourceFile::Path(path) => path.file_name(),
+      SourceFile::Uri(uri) => last_segment(uri.trim_end_matches('/')),
+      SourceFile::Virtual { name } => last_segment(name),
       SourceFile::Tmp => "<temp-buffer>",
     }
   }
 
+  /// Name used to identify the source in diagnostics, e.g. `<stdin>:12:3`
+  pub fn display_name(&self) -> &str {
+    match self {
+      SourceFile::Uri(uri) => uri,
+      SourceFile::Virtual { name } => name,
+      _ => self.file_name(),
+    }
+  }
+
+  /// A stable identity for the source: the full path, URI, or virtual
+  /// name. Stdin and temporary buffers have none.
+  pub fn identity(&self) -> Option<Cow<'_, str>> {
+    match self {
+      SourceFile::Path(path) => Some(Cow::Owned(path.to_string())),
+      SourceFile::Uri(uri) => Some(Cow::Borrowed(uri)),
+      SourceFile::Virtual { name } => Some(Cow::Borrowed(name)),
+      SourceFile::Stdin { .. } | SourceFile::Tmp => None,
+    }
+  }
+
   pub fn matches_xref_target(&self, target: &str) -> bool {
-    let SourceFile::Path(path) = self else {
+    let Some(identity) = self.identity() else {
       return false;
     };
-    let filename = path.file_name();
+    let filename = self.file_name();
     if filename == target {
       return true;
     }
@@ -341,17 +494,44 @@ impl SourceFile {
     if xref_ext.is_some() && xref_ext != path_ext {
       return false;
     }
-    let fullpath = path.to_string();
-    if fullpath.ends_with(target) {
+    if identity.ends_with(target) {
       true
     } else if xref_ext.is_some() {
       false
     } else {
-      file::remove_ext(&fullpath).ends_with(target)
+      file::remove_ext(&identity).ends_with(target)
     }
   }
 }
 
+impl From<Path> for SourceFile {
+  fn from(path: Path) -> Self {
+    if path.is_uri() {
+      SourceFile::Uri(path.to_string())
+    } else {
+      SourceFile::Path(path)
+    }
+  }
+}
+
+fn last_segment(name: &str) -> &str {
+  name.rsplit(['/', '\\']).next().unwrap_or(name)
+}
+
+/// the length and repeated byte of the line lexed for a delimiter
+const fn delimiter_line(delimiter: Delimiter) -> (u32, u8) {
+  match delimiter {
+    Delimiter::BlockQuote(len) => (len, b'_'),
+    Delimiter::Example => (4, b'='),
+    Delimiter::Open => (2, b'-'),
+    Delimiter::Sidebar => (4, b'*'),
+    Delimiter::Listing => (4, b'-'),
+    Delimiter::Literal => (4, b'.'),
+    Delimiter::Passthrough => (4, b'+'),
+    Delimiter::Comment => (4, b'/'),
+  }
+}
+
 impl From<Diagnostic> for Vec<Diagnostic> {
   fn from(diagnostic: Diagnostic) -> Self {
     vec![diagnostic]
@@ -421,6 +601,81 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_source_file_names() {
+    let cases = [
+      (
+        SourceFile::Stdin { cwd: Path::new("/") },
+        "<stdin>",
+        "<stdin>",
+        None,
+      ),
+      (
+        SourceFile::Path(Path::new("/docs/guide.adoc")),
+        "guide.adoc",
+        "guide.adoc",
+        Some("/docs/guide.adoc"),
+      ),
+      (
+        SourceFile::Uri("https://example.com/docs/guide.adoc".to_string()),
+        "guide.adoc",
+        "https://example.com/docs/guide.adoc",
+        Some("https://example.com/docs/guide.adoc"),
+      ),
+      (
+        SourceFile::Virtual {
+          name: "drafts/intro.adoc".to_string(),
+        },
+        "intro.adoc",
+        "drafts/intro.adoc",
+        Some("drafts/intro.adoc"),
+      ),
+      (SourceFile::Tmp, "<temp-buffer>", "<temp-buffer>", None),
+    ];
+    for (file, file_name, display_name, identity) in cases {
+      assert_eq!(file.file_name(), file_name);
+      assert_eq!(file.display_name(), display_name);
+      assert_eq!(file.identity().as_deref(), identity);
+    }
+    assert_eq!(
+      SourceFile::from(Path::new("https://example.com/a.adoc")),
+      SourceFile::Uri("https://example.com/a.adoc".to_string())
+    );
+  }
+
+  #[test]
+  fn test_source_file_matches_xref_target() {
+    let uri = SourceFile::Uri("https://example.com/docs/other.adoc".to_string());
+    assert!(uri.matches_xref_target("other.adoc"));
+    assert!(uri.matches_xref_target("docs/other"));
+    assert!(!uri.matches_xref_target("other.html"));
+    let virtual_file = SourceFile::Virtual {
+      name: "drafts/intro.adoc".to_string(),
+    };
+    assert!(virtual_file.matches_xref_target("drafts/intro"));
+    assert!(!virtual_file.matches_xref_target("outro"));
+    assert!(!SourceFile::Stdin { cwd: Path::new("/") }.matches_xref_target("stdin"));
+  }
+
+  #[test]
+  fn test_virtual_file_diagnostics_and_attrs() {
+    let bump = &Bump::new();
+    let name = "drafts/intro.adoc".to_string();
+    let parser = Parser::from_str("{docname}\n\n|===", SourceFile::Virtual { name }, bump);
+    let diagnostic = parser.parse().err().unwrap().remove(0);
+    assert!(diagnostic
+      .plain_text()
+      .contains("--> drafts/intro.adoc:3:1"));
+    let parser = Parser::from_str(
+      "{docname}{docfilesuffix}",
+      SourceFile::Virtual { name: "intro.adoc".to_string() },
+      bump,
+    );
+    let document = parser.parse().unwrap().document;
+    assert_eq!(document.meta.str("docname"), Some("intro"));
+    assert_eq!(document.meta.get("docfile"), None);
+  }
+
   #[test]
   fn invalid_directive_line_passed_thru() {
     let input = adoc! {"
@@ -0,0 +1,87 @@
+use crate::internal::*;
+
+/// Called with each diagnostic as soon as it is found, e.g. to stream
+/// warnings from a long parse, including the error that ends a strict
+/// parse. Diagnostics are still returned from [`Parser::parse`] as well.
+pub type DiagnosticSink<'arena> = Box<dyn Fn(&Diagnostic) + 'arena>;
+
+/// Configures and creates a [`Parser`]. Only the source is required,
+/// everything else falls back to defaults, e.g. a strict parse.
+pub struct ParserBuilder<'arena> {
+  bump: &'arena Bump,
+  src: BumpVec<'arena, u8>,
+  file: SourceFile,
+  settings: Option<JobSettings>,
+  resolver: Option<Box<dyn IncludeResolver>>,
+  timestamps: Option<(u64, Option<u64>, Option<u64>)>,
+  diagnostic_sink: Option<DiagnosticSink<'arena>>,
+}
+
+impl<'arena> ParserBuilder<'arena> {
+  pub fn new(bump: &'arena Bump) -> Self {
+    ParserBuilder {
+      bump,
+      src: BumpVec::new_in(bump),
+      file: SourceFile::Tmp,
+      settings: None,
+      resolver: None,
+      timestamps: None,
+      diagnostic_sink: None,
+    }
+  }
+
+  pub fn source(mut self, src: BumpVec<'arena, u8>) -> Self {
+    self.src = src;
+    self
+  }
+
+  pub fn source_str(mut self, src: &str) -> Self {
+    self.src = BumpVec::from_iter_in(src.bytes(), self.bump);
+    self
+  }
+
+  pub fn file(mut self, file: SourceFile) -> Self {
+    self.file = file;
+    self
+  }
+
+  pub fn settings(mut self, settings: JobSettings) -> Self {
+    self.settings = Some(settings);
+    self
+  }
+
+  pub fn resolver(mut self, resolver: Box<dyn IncludeResolver>) -> Self {
+    self.resolver = Some(resolver);
+    self
+  }
+
+  /// see [`Parser::provide_timestamps`]
+  pub const fn timestamps(
+    mut self,
+    now: u64,
+    input_modified_time: Option<u64>,
+    reproducible_override: Option<u64>,
+  ) -> Self {
+    self.timestamps = Some((now, input_modified_time, reproducible_override));
+    self
+  }
+
+  pub fn diagnostic_sink(mut self, sink: impl Fn(&Diagnostic) + 'arena) -> Self {
+    self.diagnostic_sink = Some(Box::new(sink));
+    self
+  }
+
+  pub fn build(self) -> Parser<'arena> {
+    let mut parser = Parser::from_lexer(Lexer::new(self.src, self.file, self.bump));
+    // settings replace the document attrs, so they go before timestamps
+    if let Some(settings) = self.settings {
+      parser.apply_job_settings(settings);
+    }
+    if let Some((now, input_modified_time, reproducible_override)) = self.timestamps {
+      parser.provide_timestamps(now, input_modified_time, reproducible_override);
+    }
+    parser.include_resolver = self.resolver;
+    parser.diagnostic_sink = self.diagnostic_sink;
+    parser
+  }
+}
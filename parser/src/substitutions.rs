@@ -128,6 +128,17 @@ impl Substitutions {
     self.flags & Subs::CALLOUTS != 0
   }
 
+  /// no subs beyond special chars and callouts, which can be applied
+  /// to verbatim lines lexed in chunks
+  pub const fn verbatim_only(&self) -> bool {
+    self.flags & !(Subs::SPECIAL_CHARS | Subs::CALLOUTS) == 0
+  }
+
+  /// backslash escapes are only meaningful if some sub could be escaped
+  pub const fn escapable(&self) -> bool {
+    self.flags & (Subs::INLINE_FORMATTING | Subs::ATTR_REFS | Subs::MACROS | Subs::CALLOUTS) != 0
+  }
+
   pub fn insert(&mut self, sub: Subs) {
     if self.contains(sub) {
       return;
@@ -40,6 +40,107 @@ impl<'arena> Parser<'arena> {
       source_loc,
       source_idx: self.lexer.source_idx(),
       is_biblio,
+      is_footnote: false,
     }
   }
+
+  /// an anchor leading a list item or table cell, without its own reftext,
+  /// takes the rest of the text as its title, e.g. `* [[step-1]]Unpack it`
+  pub(crate) fn set_leading_anchor_title(&self, nodes: &[InlineNode<'arena>]) {
+    // a checklist item's text starts with the space after its checkbox
+    let start = match nodes.first().map(|node| &node.content) {
+      Some(Inline::Text(text)) if text.trim().is_empty() => 1,
+      _ => 0,
+    };
+    let Some(InlineNode {
+      content: Inline::InlineAnchor(id), ..
+    }) = nodes.get(start)
+    else {
+      return;
+    };
+    self.set_anchor_title(id, &nodes[start + 1..]);
+  }
+
+  pub(crate) fn set_anchor_title(&self, id: &str, nodes: &[InlineNode<'arena>]) {
+    let mut anchors = self.document.anchors.borrow_mut();
+    let Some(anchor) = anchors.get_mut(id) else {
+      return;
+    };
+    if anchor.reftext.is_some() || !anchor.title.is_empty() {
+      return;
+    }
+    anchor.title = self.auto_reftext(nodes);
+  }
+
+  /// the title an anchor falls back to for its reftext, cut short on a
+  /// grapheme boundary with an ellipsis when `reftext-maxlength` is set
+  pub(crate) fn auto_reftext(&self, nodes: &[InlineNode<'arena>]) -> InlineNodes<'arena> {
+    let nodes = self.without_footnotes_and_anchors(nodes);
+    let max = self.document.meta.isize("reftext-maxlength");
+    let Some(mut budget) = max.filter(|max| *max > 0).map(|max| max as usize) else {
+      return nodes;
+    };
+    let mut title = InlineNodes::new(self.bump);
+    for node in nodes.iter() {
+      let len = grapheme_len(&node.to_plain_text());
+      if len <= budget {
+        budget -= len;
+        title.push(node.clone());
+        continue;
+      }
+      if let Inline::Text(text) = &node.content {
+        let kept = truncate_graphemes(text, budget);
+        let end = node.loc.start + kept.len() as u32;
+        let loc = SourceLocation { end, ..node.loc };
+        title.push(InlineNode::new(Inline::Text(self.string(kept)), loc));
+      }
+      if let Some(Inline::Text(text)) = title.last_mut().map(|node| &mut node.content) {
+        text.truncate(text.trim_end().len());
+      }
+      let loc = title.last_loc().unwrap_or(node.loc);
+      let loc = SourceLocation { start: loc.end, ..loc };
+      title.push(InlineNode::new(Inline::Text(self.string("…")), loc));
+      break;
+    }
+    title
+  }
+
+  // footnotes and anchors would be duplicated wherever the title is used, so
+  // they're left out, along with the whitespace that would double up
+  fn without_footnotes_and_anchors(&self, nodes: &[InlineNode<'arena>]) -> InlineNodes<'arena> {
+    let mut kept = InlineNodes::new(self.bump);
+    let mut dropped = false;
+    for node in nodes {
+      if matches!(
+        node.content,
+        Inline::Macro(MacroNode::Footnote { .. }) | Inline::InlineAnchor(_)
+      ) {
+        dropped = true;
+        continue;
+      }
+      let mut node = node.clone();
+      let after_space = match kept.last().map(|node| &node.content) {
+        Some(Inline::Text(text)) => text.ends_with(char::is_whitespace),
+        _ => kept.is_empty(),
+      };
+      if let Inline::Text(text) = &mut node.content {
+        if dropped && after_space {
+          let trimmed = text.trim_start();
+          node.loc.start += (text.len() - trimmed.len()) as u32;
+          *text = self.string(trimmed);
+          if text.is_empty() {
+            continue;
+          }
+        }
+      }
+      dropped = false;
+      kept.push(node);
+    }
+    if let Some(Inline::Text(text)) = kept.last_mut().map(|node| &mut node.content) {
+      if dropped {
+        text.truncate(text.trim_end().len());
+      }
+    }
+    kept
+  }
 }
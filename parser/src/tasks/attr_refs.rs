@@ -3,27 +3,30 @@ use crate::internal::*;
 impl<'arena> Parser<'arena> {
   pub(crate) fn set_source_file_attrs(&mut self) {
     let source_file = self.lexer.source_file().clone();
-    match source_file {
-      SourceFile::Stdin { .. } => {}
-      SourceFile::Path(path) => {
-        let file_stem = path.file_stem();
-        let ext = path.extension();
-        self.insert_file_attr("docfilesuffix", ext.to_string());
-        self.insert_file_attr("docname", file_stem.to_string());
-        self.insert_file_attr("asciidork-docfilename", format!("{}{}", file_stem, ext));
-        match self.document.meta.safe_mode {
-          SafeMode::Server | SafeMode::Secure => {
-            self.insert_file_attr("docdir", "");
-            self.insert_file_attr("docfile", "");
-          }
-          SafeMode::Safe | SafeMode::Unsafe => {
-            self.insert_file_attr("docfile", path.to_string());
-            self.insert_file_attr("docdir", path.dirname().to_string());
-          }
-        }
-      }
-      SourceFile::Tmp => {}
+    let path = match &source_file {
+      SourceFile::Path(path) => path.clone(),
+      // virtual files have a name, but nothing on disk for docfile/docdir
+      SourceFile::Virtual { name } => Path::new(name),
+      SourceFile::Stdin { .. } | SourceFile::Uri(_) | SourceFile::Tmp => return,
     };
+    let file_stem = path.file_stem();
+    let ext = path.extension();
+    self.insert_file_attr("docfilesuffix", ext.to_string());
+    self.insert_file_attr("docname", file_stem.to_string());
+    self.insert_file_attr("asciidork-docfilename", format!("{}{}", file_stem, ext));
+    if !matches!(source_file, SourceFile::Path(_)) {
+      return;
+    }
+    match self.document.meta.safe_mode {
+      SafeMode::Server | SafeMode::Secure => {
+        self.insert_file_attr("docdir", "");
+        self.insert_file_attr("docfile", "");
+      }
+      SafeMode::Safe | SafeMode::Unsafe => {
+        self.insert_file_attr("docfile", path.to_string());
+        self.insert_file_attr("docdir", path.dirname().to_string());
+      }
+    }
   }
 
   pub(crate) fn push_token_replacing_attr_ref(
@@ -6,8 +6,11 @@ pub fn from_meta(current: Substitutions, attrs: &MultiAttrList) -> Substitutions
     return current;
   };
 
-  let mut next = current;
+  // a list of plain step/group names replaces the current subs entirely,
+  // while modifiers (`+foo`, `foo+`, `-foo`) adjust whatever came before
+  let mut next: Option<Substitutions> = None;
   for part in subs.split(',') {
+    let part = part.trim();
     let len = part.len();
     if len < 4 {
       // TODO: error? warning?
@@ -27,54 +30,24 @@ pub fn from_meta(current: Substitutions, attrs: &MultiAttrList) -> Substitutions
       continue;
     };
 
-    match strategy {
-      Strategy::Replace => match step_or_group {
-        StepOrGroup::None => return Substitutions::none(),
-        StepOrGroup::Normal => return Substitutions::normal(),
-        StepOrGroup::Verbatim => todo!(),
-        StepOrGroup::SpecialChars => todo!(),
-        StepOrGroup::Callouts => todo!(),
-        StepOrGroup::Quotes => todo!(),
-        StepOrGroup::Attributes => todo!(),
-        StepOrGroup::Replacements => todo!(),
-        StepOrGroup::Macros => todo!(),
-        StepOrGroup::PostReplacements => todo!(),
-      },
-      Strategy::Append | Strategy::Prepend | Strategy::Remove => {
-        let modify = match strategy {
-          Strategy::Append => Substitutions::insert,
-          Strategy::Prepend => Substitutions::prepend,
-          Strategy::Remove => Substitutions::remove,
-          _ => unreachable!(),
-        };
-        match step_or_group {
-          StepOrGroup::None => todo!(),
-          StepOrGroup::Normal => todo!(),
-          StepOrGroup::Verbatim => todo!(),
-          StepOrGroup::SpecialChars => {
-            modify(&mut next, Subs::SpecialChars);
-          }
-          StepOrGroup::Callouts => {
-            modify(&mut next, Subs::Callouts);
-          }
-          StepOrGroup::Quotes => {
-            modify(&mut next, Subs::InlineFormatting);
-          }
-          StepOrGroup::Attributes => {
-            modify(&mut next, Subs::AttrRefs);
-          }
-          StepOrGroup::Replacements => todo!(),
-          StepOrGroup::Macros => {
-            modify(&mut next, Subs::Macros);
-          }
-          StepOrGroup::PostReplacements => {
-            modify(&mut next, Subs::PostReplacement);
-          }
-        }
-      }
+    let modify = match strategy {
+      Strategy::Replace | Strategy::Append => Substitutions::insert,
+      Strategy::Prepend => Substitutions::prepend,
+      Strategy::Remove => Substitutions::remove,
+    };
+    let target = next.get_or_insert(match strategy {
+      Strategy::Replace => Substitutions::none(),
+      _ => current,
+    });
+    let steps = step_or_group.steps();
+    if strategy == Strategy::Prepend {
+      // prepend in reverse to preserve the group order
+      steps.iter().rev().for_each(|step| modify(target, *step));
+    } else {
+      steps.iter().for_each(|step| modify(target, *step));
     }
   }
-  next
+  next.unwrap_or(current)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -100,6 +73,28 @@ enum StepOrGroup {
 }
 
 impl StepOrGroup {
+  const fn steps(&self) -> &'static [Subs] {
+    match self {
+      Self::None => &[],
+      Self::Normal => &[
+        Subs::SpecialChars,
+        Subs::InlineFormatting,
+        Subs::AttrRefs,
+        Subs::CharReplacement,
+        Subs::Macros,
+        Subs::PostReplacement,
+      ],
+      Self::Verbatim => &[Subs::SpecialChars, Subs::Callouts],
+      Self::SpecialChars => &[Subs::SpecialChars],
+      Self::Callouts => &[Subs::Callouts],
+      Self::Quotes => &[Subs::InlineFormatting],
+      Self::Attributes => &[Subs::AttrRefs],
+      Self::Replacements => &[Subs::CharReplacement],
+      Self::Macros => &[Subs::Macros],
+      Self::PostReplacements => &[Subs::PostReplacement],
+    }
+  }
+
   const fn from(s: &[u8]) -> Option<Self> {
     match s {
       b"none" => Some(Self::None),
@@ -174,6 +169,26 @@ mod tests {
         exactly(&[Subs::SpecialChars, Subs::Macros]),
         exactly(&[Subs::AttrRefs, Subs::SpecialChars, Subs::InlineFormatting]),
       ),
+      (
+        "[subs=attributes]",
+        Substitutions::none(),
+        exactly(&[Subs::AttrRefs]),
+      ),
+      (
+        r#"[subs="quotes, macros"]"#,
+        Substitutions::normal(),
+        exactly(&[Subs::InlineFormatting, Subs::Macros]),
+      ),
+      (
+        "[subs=verbatim]",
+        Substitutions::normal(),
+        Substitutions::verbatim(),
+      ),
+      (
+        "[subs=-replacements]",
+        Substitutions::normal(),
+        all_except(&[Subs::CharReplacement]),
+      ),
     ];
 
     for (attrs, current, expected) in cases {
@@ -11,7 +11,7 @@ impl Parser<'_> {
     if self.ctx.table_cell_ctx != TableCellContext::None {
       return Ok(());
     }
-    for (ref_target, ref_loc) in self.ctx.xrefs.borrow().iter() {
+    for ((ref_target, _), ref_loc) in self.ctx.xrefs.borrow().iter() {
       let Some((idx, id)) = self.target_data(ref_target) else {
         // couldn't find source idx
         self.invalid_xref(ref_target, *ref_loc)?;
@@ -21,7 +21,17 @@ impl Parser<'_> {
         continue;
       }
       let anchors = self.document.anchors.borrow();
-      let Some(anchor) = anchors.get(id) else {
+      // unqualified targets prefer ids namespaced by the xref's own source
+      let namespace_idx = if ref_target.contains('#') { idx } else { ref_loc.include_depth };
+      let namespaced = self
+        .document
+        .meta
+        .id_namespaces
+        .get(&namespace_idx)
+        .map(|namespace| file::namespaced_id(namespace, id))
+        .filter(|id| self.document.meta.namespaced_ids.contains(id));
+      let idx = if namespaced.is_some() { namespace_idx } else { idx };
+      let Some(anchor) = anchors.get(namespaced.as_deref().unwrap_or(id)) else {
         self.invalid_xref(ref_target, *ref_loc)?;
         continue;
       };
@@ -35,7 +45,7 @@ impl Parser<'_> {
   }
 
   fn invalid_xref(&self, target: &str, loc: SourceLocation) -> Result<()> {
-    self.err_at_loc(
+    self.err_at_source_loc(
       format!("Invalid cross reference, no anchor found for `{target}`"),
       loc,
     )
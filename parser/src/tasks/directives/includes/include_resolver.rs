@@ -81,6 +81,7 @@ pub enum ResolveError {
   UriReadNotSupported,
   UriRead(String),
   BaseDirRequired,
+  OutsideBaseDir { path: String, base_dir: String },
 }
 
 impl fmt::Display for ResolveError {
@@ -96,6 +97,13 @@ impl fmt::Display for ResolveError {
           "Include resolvers must supply a base_dir for relative includes from primary document"
         )
       }
+      ResolveError::OutsideBaseDir { path, base_dir } => {
+        write!(
+          f,
+          "Path `{}` is outside of base directory `{}`",
+          path, base_dir
+        )
+      }
     }
   }
 }
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use crate::internal::*;
+
+/// An include resolver backed by an in-memory map of paths (or URIs) to
+/// file contents, for tests and for embedding in environments without a
+/// filesystem, like wasm playgrounds. Relative paths are stored relative
+/// to the resolver's base dir, which defaults to `/`.
+#[derive(Debug, Clone)]
+pub struct MemoryResolver {
+  base_dir: String,
+  files: HashMap<String, Vec<u8>>,
+}
+
+impl MemoryResolver {
+  pub fn new() -> Self {
+    Self::with_base_dir("/")
+  }
+
+  pub fn with_base_dir(base_dir: impl Into<String>) -> Self {
+    Self {
+      base_dir: key(&base_dir.into()),
+      files: HashMap::new(),
+    }
+  }
+
+  pub fn with_file(mut self, path: &str, contents: impl Into<Vec<u8>>) -> Self {
+    self.insert(path, contents);
+    self
+  }
+
+  pub fn insert(&mut self, path: &str, contents: impl Into<Vec<u8>>) {
+    let key = if Path::new(path).is_absolute() {
+      key(path)
+    } else {
+      key(&Path::new(&self.base_dir).join(path).to_string())
+    };
+    self.files.insert(key, contents.into());
+  }
+
+  pub fn remove(&mut self, path: &str) -> Option<Vec<u8>> {
+    self.files.remove(&key(path))
+  }
+
+  pub fn contains(&self, path: &str) -> bool {
+    self.files.contains_key(&key(path))
+  }
+}
+
+impl Default for MemoryResolver {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl IncludeResolver for MemoryResolver {
+  fn resolve(
+    &mut self,
+    target: IncludeTarget,
+    buffer: &mut dyn IncludeBuffer,
+  ) -> std::result::Result<usize, ResolveError> {
+    let key = match target {
+      IncludeTarget::FilePath(path) => key(&path),
+      IncludeTarget::Uri(uri) => uri,
+    };
+    let Some(contents) = self.files.get(&key) else {
+      return Err(ResolveError::NotFound);
+    };
+    buffer.initialize(contents.len());
+    buffer.as_bytes_mut().copy_from_slice(contents);
+    Ok(contents.len())
+  }
+
+  fn get_base_dir(&self) -> Option<String> {
+    Some(self.base_dir.clone())
+  }
+}
+
+// uris are stored verbatim, paths with their `.` and `..` segments resolved
+fn key(path: &str) -> String {
+  let parsed = Path::new(path);
+  if parsed.is_uri() {
+    path.to_string()
+  } else {
+    parsed.normalize().to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_memory_resolver() {
+    let mut resolver = MemoryResolver::with_base_dir("/docs")
+      .with_file("a.adoc", "a")
+      .with_file("/other/b.adoc", "b")
+      .with_file("https://example.com/c.adoc", "c");
+    let cases = [
+      (IncludeTarget::FilePath("/docs/a.adoc".into()), Ok("a")),
+      (
+        IncludeTarget::FilePath("/docs/sub/../a.adoc".into()),
+        Ok("a"),
+      ),
+      (IncludeTarget::FilePath("/other/b.adoc".into()), Ok("b")),
+      (
+        IncludeTarget::Uri("https://example.com/c.adoc".into()),
+        Ok("c"),
+      ),
+      (
+        IncludeTarget::FilePath("/docs/b.adoc".into()),
+        Err(ResolveError::NotFound),
+      ),
+    ];
+    for (target, expected) in cases {
+      let mut buffer = Vec::new();
+      let result = resolver.resolve(target, &mut buffer);
+      assert_eq!(
+        result.map(|_| String::from_utf8(buffer).unwrap()),
+        expected.map(String::from)
+      );
+    }
+    assert!(resolver.contains("/docs/a.adoc"));
+    assert_eq!(resolver.get_base_dir(), Some("/docs".to_string()));
+  }
+
+  #[test]
+  fn test_memory_resolver_windows() {
+    let mut resolver = MemoryResolver::with_base_dir("C:\\docs\\.").with_file("a.adoc", "a");
+    let target = IncludeTarget::FilePath("C:\\docs\\sub\\..\\a.adoc".into());
+    let mut buffer = Vec::new();
+    assert_eq!(resolver.resolve(target, &mut buffer), Ok(1));
+    assert!(resolver.contains("C:\\docs\\a.adoc"));
+    assert_eq!(resolver.get_base_dir(), Some("C:\\docs".to_string()));
+  }
+}
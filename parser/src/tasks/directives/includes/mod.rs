@@ -1,7 +1,9 @@
 mod include_resolver;
+mod memory_resolver;
 mod normalize_includes;
 mod process_includes;
 mod tags;
 mod target;
 
 pub use include_resolver::*;
+pub use memory_resolver::*;
@@ -51,6 +51,14 @@ impl<'arena> Parser<'arena> {
       return Ok(DirectiveAction::Passthrough);
     }
 
+    if self.lexer.num_sources() >= MAX_SOURCES {
+      self.err_line_starting(
+        "Maximum number of included files exceeded",
+        directive.first_token.loc.start,
+      )?;
+      return Ok(DirectiveAction::Passthrough);
+    }
+
     let Some(resolver) = self.include_resolver.as_mut() else {
       self.err_token_full(
         "No resolver supplied for include directive",
@@ -65,8 +73,15 @@ impl<'arena> Parser<'arena> {
       self.lexer.source_file(),
       self.lexer.source_is_primary(),
       resolver.get_base_dir().map(Path::new),
+      self.document.meta.safe_mode >= SafeMode::Safe,
     ) {
       Ok(target) => target,
+      Err(err @ ResolveError::OutsideBaseDir { .. }) => {
+        self.target_err(format!("Include blocked by safe mode: {}", err), &directive)?;
+        return Ok(DirectiveAction::SubstituteLine(
+          self.substitute_link_for_include(&directive),
+        ));
+      }
       Err(err) => {
         self.target_err(format!("Error preparing target: {}", err), &directive)?;
         return Ok(DirectiveAction::SubstituteLine(
@@ -76,9 +91,23 @@ impl<'arena> Parser<'arena> {
     };
 
     let target_abspath = target.path();
+    let _span = span!(DEBUG, "include", target = %target_abspath);
     let mut buffer = BumpVec::new_in(self.bump);
     match resolver.resolve(target, &mut buffer) {
+      Ok(len) if len.max(buffer.len()) > MAX_SOURCE_LEN => {
+        self.target_err(
+          "Included file exceeds maximum supported size of 4GiB",
+          &directive,
+        )?;
+        Ok(DirectiveAction::SubstituteLine(
+          self.substitute_link_for_include(&directive),
+        ))
+      }
       Ok(_) => {
+        self.includes.push(IncludeFingerprint {
+          path: target_abspath.to_string(),
+          hash: Some(content_hash(&buffer)),
+        });
         if let Err(msg) =
           self.normalize_include_bytes(&target_abspath, &directive.attrs, &mut buffer)
         {
@@ -89,20 +118,17 @@ impl<'arena> Parser<'arena> {
         }
         self.select_lines(&directive.attrs, &target_abspath, &mut buffer)?;
         self.set_include_indentation(&directive.attrs, &mut buffer);
-        let mut leveloffset = 0;
-        if let Some(offset_attr) = directive
+        let leveloffset = directive
           .attrs
           .named("leveloffset")
-          .map(|s| AttrValue::String(s.to_string()))
-        {
-          Parser::adjust_leveloffset(&mut leveloffset, &offset_attr);
-        }
+          .map(|s| AttrValue::String(s.to_string()));
         let include_depth = directive
           .attrs
           .named("depth")
           .and_then(|s| s.parse::<u16>().ok());
+        self.report_enter_include(&target_abspath, buffer.len());
         self.lexer.push_source(
-          SourceFile::Path(target_abspath),
+          SourceFile::from(target_abspath),
           leveloffset,
           include_depth,
           buffer,
@@ -115,10 +141,14 @@ impl<'arena> Parser<'arena> {
         Ok(DirectiveAction::ReadNextLine)
       }
       Err(ResolveError::NotFound) if directive.attrs.has_option("optional") => {
+        self.push_missing_include(&target_abspath);
         // TODO: when we have info/trace logging, emit a log
         Ok(DirectiveAction::ReadNextLine)
       }
       Err(err @ ResolveError::NotFound | err @ ResolveError::Io(..)) => {
+        if matches!(err, ResolveError::NotFound) {
+          self.push_missing_include(&target_abspath);
+        }
         self.target_err(format!("Include resolver error: {}", err), &directive)?;
         let mut msg = self.string("+++Unresolved directive in ");
         msg.push_str(self.lexer.source_file().file_name());
@@ -136,6 +166,14 @@ impl<'arena> Parser<'arena> {
     }
   }
 
+  // fingerprinted too, so creating the file invalidates a cached conversion
+  fn push_missing_include(&mut self, target_abspath: &Path) {
+    self.includes.push(IncludeFingerprint {
+      path: target_abspath.to_string(),
+      hash: None,
+    });
+  }
+
   fn valid_include_directive(
     &mut self,
     line: &mut Line<'arena>,
@@ -208,6 +208,7 @@ impl<'arena> Parser<'arena> {
             let line = nth_line(src, idx).unwrap().to_string();
             let underline_start = line.find(&tag).unwrap_or(0) as u32;
             self.err(Diagnostic {
+              severity: Severity::Error,
               line_num: (idx as u32) + 1,
               line,
               message: tag_stack.last().map_or_else(
@@ -238,6 +239,7 @@ impl<'arena> Parser<'arena> {
         let line = nth_line(src, *line_idx).unwrap().to_string();
         let underline_start = line.find(tag).unwrap_or(0) as u32;
         self.err(Diagnostic {
+          severity: Severity::Error,
           line_num: (*line_idx as u32) + 1,
           line,
           message: format!("Tag `{}` was not closed", tag),
@@ -3,36 +3,84 @@ use IncludeTarget as Target;
 use ResolveError as Err;
 use SourceFile as Src;
 
+/// Resolves an include target to an absolute, normalized path (or URI).
+/// When `jailed`, paths escaping `base_dir` are rejected, so resolvers
+/// never see a path outside of the document root.
 pub fn prepare(
   target_str: &str,
   target_is_uri: bool,
   src_file: &SourceFile,
   src_is_primary: bool,
   base_dir: Option<Path>,
+  jailed: bool,
 ) -> std::result::Result<IncludeTarget, ResolveError> {
   let target = Path::new(target_str);
   if target_is_uri {
     return Ok(Target::Uri(target_str.to_string()));
   }
-  if src_is_primary && target.is_relative() {
-    let Some(base_dir) = base_dir else {
+  let abspath = if src_is_primary && target.is_relative() {
+    let Some(base_dir) = base_dir.as_ref() else {
       return Err(Err::BaseDirRequired);
     };
-    let abspath = base_dir.join(target);
-    return Ok(Target::FilePath(abspath.to_string()));
+    base_dir.join(target)
+  } else {
+    match src_file {
+      Src::Path(src) if target.is_relative() => Path::new(src.dirname()).join(target),
+      Src::Uri(uri) if target.is_relative() => Path::new(Path::new(uri).dirname()).join(target),
+      // a virtual source has no directory of its own to resolve against
+      Src::Virtual { .. } if target.is_relative() => return Err(Err::BaseDirRequired),
+      Src::Path(_) | Src::Uri(_) | Src::Virtual { .. } => target,
+      Src::Stdin { .. } => unimplemented!("include from stdin not implemented yet"),
+      Src::Tmp => unreachable!(),
+    }
+  };
+  if abspath.is_uri() {
+    return Ok(abspath.into());
+  }
+  let abspath = abspath.normalize();
+  match base_dir {
+    Some(base_dir) if jailed && !abspath.starts_with(&base_dir) => Err(Err::OutsideBaseDir {
+      path: abspath.to_string(),
+      base_dir: base_dir.normalize().to_string(),
+    }),
+    _ => Ok(abspath.into()),
   }
-  match src_file {
-    Src::Path(src) => {
-      let abspath = if target.is_relative() {
-        let dir = Path::new(src.dirname());
-        dir.join(target)
-      } else {
-        target
-      };
-      Ok(abspath.into())
+}
+
+impl Parser<'_> {
+  /// Image targets get the same normalize-and-jail check as includes,
+  /// resolved from `imagesdir` against the document root.
+  pub(crate) fn check_image_target(&self, target: &SourceString) -> Result<()> {
+    if self.document.meta.safe_mode < SafeMode::Safe {
+      return Ok(());
+    }
+    let Some(base_dir) = self
+      .include_resolver
+      .as_ref()
+      .and_then(|r| r.get_base_dir())
+    else {
+      return Ok(());
+    };
+    let path = match self.document.meta.str("imagesdir") {
+      Some(imagesdir) => Path::new(imagesdir).join(&**target),
+      None => Path::new(&**target),
+    };
+    let prepared = prepare(
+      &path.to_string(),
+      path.is_uri(),
+      self.lexer.source_file(),
+      true,
+      Some(Path::new(base_dir)),
+      true,
+    );
+    match prepared {
+      Err(err @ Err::OutsideBaseDir { .. }) => self.err_at(
+        format!("Image target blocked by safe mode: {}", err),
+        target.loc.start,
+        target.loc.end,
+      ),
+      _ => Ok(()),
     }
-    Src::Stdin { .. } => unimplemented!("include from stdin not implemented yet"),
-    Src::Tmp => unreachable!(),
   }
 }
 
@@ -48,6 +96,7 @@ mod tests {
     src_file: SourceFile,
     src_is_primary: bool,
     base_dir: Option<Path>,
+    jailed: bool,
     expected: std::result::Result<IncludeTarget, ResolveError>,
   }
 
@@ -60,6 +109,7 @@ mod tests {
         src_file: SourceFile::Tmp,
         src_is_primary: true,
         base_dir: Some(Path::new("/basedir")),
+        jailed: false,
         expected: Err(ResolveError::NotFound),
       }
     }
@@ -103,7 +153,64 @@ mod tests {
         src_is_primary: false,
         target_str: "../other.adoc",
         src_file: SourceFile::Path(Path::new("/d1/d2/src.adoc")),
-        expected: Ok(FilePath("/d1/d2/../other.adoc".to_string())),
+        expected: Ok(FilePath("/d1/other.adoc".to_string())),
+        ..TestCase::default()
+      },
+      TestCase {
+        name: "jailed relative include within basedir",
+        jailed: true,
+        target_str: "sub/../other.adoc",
+        src_file: SourceFile::Path(Path::new("/basedir/src.adoc")),
+        expected: Ok(FilePath("/basedir/other.adoc".to_string())),
+        ..TestCase::default()
+      },
+      TestCase {
+        name: "jailed relative include escaping basedir",
+        jailed: true,
+        target_str: "../../etc/passwd",
+        src_file: SourceFile::Path(Path::new("/basedir/src.adoc")),
+        expected: Err(ResolveError::OutsideBaseDir {
+          path: "/etc/passwd".to_string(),
+          base_dir: "/basedir".to_string(),
+        }),
+        ..TestCase::default()
+      },
+      TestCase {
+        name: "jailed nested include escaping basedir",
+        src_is_primary: false,
+        jailed: true,
+        target_str: "../../../secret.adoc",
+        src_file: SourceFile::Path(Path::new("/basedir/a/b/src.adoc")),
+        expected: Err(ResolveError::OutsideBaseDir {
+          path: "/secret.adoc".to_string(),
+          base_dir: "/basedir".to_string(),
+        }),
+        ..TestCase::default()
+      },
+      TestCase {
+        name: "jailed absolute include outside basedir",
+        jailed: true,
+        target_str: "/etc/passwd",
+        src_file: SourceFile::Path(Path::new("/basedir/src.adoc")),
+        expected: Err(ResolveError::OutsideBaseDir {
+          path: "/etc/passwd".to_string(),
+          base_dir: "/basedir".to_string(),
+        }),
+        ..TestCase::default()
+      },
+      TestCase {
+        name: "absolute include from virtual doc",
+        target_str: "/abs/a.adoc",
+        src_file: SourceFile::Virtual { name: "x.adoc".to_string() },
+        expected: Ok(FilePath("/abs/a.adoc".to_string())),
+        ..TestCase::default()
+      },
+      TestCase {
+        name: "relative include from virtual doc needs a basedir",
+        src_is_primary: false,
+        target_str: "a.adoc",
+        src_file: SourceFile::Virtual { name: "x.adoc".to_string() },
+        expected: Err(ResolveError::BaseDirRequired),
         ..TestCase::default()
       },
     ];
@@ -115,6 +222,7 @@ mod tests {
         &case.src_file,
         case.src_is_primary,
         case.base_dir,
+        case.jailed,
       );
       assert_eq!(actual, case.expected, "TestCase.name: {:?}", case.name);
     }
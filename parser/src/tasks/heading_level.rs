@@ -3,10 +3,37 @@ use crate::internal::*;
 impl<'arena> Parser<'arena> {
   pub fn line_heading_level(&self, line: &Line) -> Option<u8> {
     let unadjusted = line.unadjusted_heading_level()?;
-    Some(adjusted_leveloffset(
-      self.lexer.leveloffset(line.loc().unwrap().include_depth),
-      adjusted_leveloffset(self.ctx.leveloffset, unadjusted),
-    ))
+    let leveloffset = self.leveloffset(line.loc().unwrap().include_depth);
+    Some(adjusted_leveloffset(leveloffset, unadjusted))
+  }
+
+  /// the leveloffset in effect for lines from the source at `source_idx`,
+  /// an include with a `leveloffset` attr starts a new scope, adjusting
+  /// the offset of its parent, so that any attr entries within it are
+  /// discarded (restoring the parent's offset) once the include ends
+  pub(crate) fn leveloffset(&self, source_idx: u16) -> i8 {
+    let scope_idx = self.lexer.leveloffset_scope(source_idx);
+    if let Some(leveloffset) = self.ctx.leveloffsets.get(&scope_idx) {
+      return *leveloffset;
+    }
+    match (
+      self.lexer.source_parent_idx(scope_idx),
+      self.lexer.leveloffset(scope_idx),
+    ) {
+      (Some(parent_idx), Some(include_attr)) => {
+        let mut leveloffset = self.leveloffset(parent_idx);
+        Parser::adjust_leveloffset(&mut leveloffset, include_attr);
+        leveloffset
+      }
+      _ => 0,
+    }
+  }
+
+  pub(crate) fn set_leveloffset(&mut self, source_idx: u16, value: &AttrValue) {
+    let mut leveloffset = self.leveloffset(source_idx);
+    Parser::adjust_leveloffset(&mut leveloffset, value);
+    let scope_idx = self.lexer.leveloffset_scope(source_idx);
+    self.ctx.leveloffsets.insert(scope_idx, leveloffset);
   }
 
   pub fn section_start_level(
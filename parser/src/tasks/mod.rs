@@ -14,6 +14,8 @@ mod parse_inlines;
 mod parse_list;
 mod parse_revision_line;
 pub mod parse_section;
+mod progress;
 mod section_id;
 mod table;
 mod time;
+mod trivia;
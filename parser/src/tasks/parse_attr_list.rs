@@ -214,7 +214,8 @@ impl<'arena> Parser<'arena> {
           groups.last().unwrap().last().unwrap().loc.end,
         )?;
       }
-      AttrIr::Named(name, tokens) if formatted_text => {
+      // custom data attrs are allowed, for backends that pass them through
+      AttrIr::Named(name, tokens) if formatted_text && !name.src.starts_with("data-") => {
         self.err_at(
           ONLY_SHORTHAND_ERR,
           name.loc.start,
@@ -1439,14 +1440,25 @@ mod tests {
 
   #[test]
   fn test_parse_formatted_text_attr_list() {
-    let cases = vec![(
-      "[#tigers]#a text span#",
-      AttrList {
-        positional: vecb![],
-        id: Some(src!("tigers", 2..8)),
-        ..attr_list!(0..9)
-      },
-    )];
+    let cases = vec![
+      (
+        "[#tigers]#a text span#",
+        AttrList {
+          positional: vecb![],
+          id: Some(src!("tigers", 2..8)),
+          ..attr_list!(0..9)
+        },
+      ),
+      (
+        "[.big,data-test-id=x]#a text span#",
+        AttrList {
+          positional: vecb![None],
+          roles: vecb![src!("big", 2..5)],
+          named: Named::from(vecb![(src!("data-test-id", 6..18), just!("x", 19..20))]),
+          ..attr_list!(0..21)
+        },
+      ),
+    ];
     for (input, expected) in cases {
       let mut parser = test_parser!(input);
       let mut line = parser.read_line().unwrap().unwrap();
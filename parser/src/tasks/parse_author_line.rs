@@ -37,13 +37,12 @@ impl<'arena> Parser<'arena> {
       self.err_token("invalid author line", line.current_token())
     } else if first_start > 0 {
       let start = line.current_token().unwrap().loc.start;
-      self.err_at("invalid author line", start, start + first_start as u32)
+      self.err_at("invalid author line", start, line.src_loc_at(first_start))
     } else if last_end < num_bytes {
-      let start = line.current_token().unwrap().loc.start;
       self.err_at(
         "invalid author line",
-        start + last_end as u32,
-        start + num_bytes as u32,
+        line.src_loc_at(last_end),
+        line.src_loc_at(num_bytes),
       )
     } else {
       Ok(())
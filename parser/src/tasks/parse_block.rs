@@ -4,22 +4,46 @@ use ast::short::block::*;
 
 impl<'arena> Parser<'arena> {
   pub(crate) fn parse_block(&mut self) -> Result<Option<Block<'arena>>> {
+    let block = self.parse_next_block()?;
+    if block.is_some() {
+      self.report_block_parsed();
+    }
+    Ok(block)
+  }
+
+  fn parse_next_block(&mut self) -> Result<Option<Block<'arena>>> {
     let Some(mut lines) = self.read_lines()? else {
       return Ok(None);
     };
 
+    // metadata above a comment block carries over to the next block
     if let Some(comment_block) = self.parse_comment_block(&mut lines) {
-      if let Some(meta) = self.peeked_meta.take() {
-        assert!(meta.is_empty());
-      }
+      self.peeked_meta = self.peeked_meta.take().filter(|meta| !meta.is_empty());
       self.restore_lines(lines);
       return Ok(Some(comment_block));
     }
+    let carried_meta = lines
+      .current()
+      .is_some_and(|line| line.is_delimiter(Delimiter::Comment))
+      .then(|| self.peeked_meta.take())
+      .flatten();
 
     let meta = self.parse_chunk_meta(&mut lines)?;
     if lines.is_empty() {
+      // metadata separated from more metadata by a blank line is merged,
+      // and metadata above a comment carries over past it
+      if let Some(next) = self.read_lines()? {
+        let merges = next.current().is_some_and(|line| {
+          line.is_block_meta() || line.is_comment() || line.is_delimiter(Delimiter::Comment)
+        });
+        self.restore_lines(next);
+        if merges {
+          self.restore_peeked_meta(meta);
+          return self.parse_next_block();
+        }
+      }
       self.err_line_starting("Unattached block metadata", meta.start)?;
-      return self.parse_block();
+      return self.parse_next_block();
     }
 
     match self.section_start_level(&lines, &meta) {
@@ -64,7 +88,11 @@ impl<'arena> Parser<'arena> {
       }
       DelimiterLine => {
         let delimiter = first_token.to_delimeter().unwrap();
-        return self.parse_delimited_block(delimiter, lines, meta);
+        let block = self.parse_delimited_block(delimiter, lines, meta);
+        if let Some(meta) = carried_meta {
+          self.restore_peeked_meta(meta);
+        }
+        return block;
       }
       Pipe | Colon | Bang | Comma
         if lines.nth_token(1).is_kind_len(EqualSigns, 3) && lines.nth_token(2).is_none() =>
@@ -72,10 +100,11 @@ impl<'arena> Parser<'arena> {
         return Ok(Some(self.parse_table(lines, meta)?));
       }
       Colon => {
-        if let Some((key, value, end)) = self.parse_doc_attr(&mut lines)? {
+        if let Some((key, value, loc)) = self.parse_doc_attr(&mut lines)? {
           self.restore_lines(lines);
+          self.record_attr_decl(&key, &value, loc, false);
           if let Err(err) = self.document.meta.insert_doc_attr(&key, value.clone()) {
-            self.err_at(err, meta.start, end)?;
+            self.err_at(err, meta.start, loc.end)?;
           }
           return Ok(Some(Block {
             meta,
@@ -105,6 +134,8 @@ impl<'arena> Parser<'arena> {
 
     if lines.is_quoted_paragraph() {
       self.parse_quoted_paragraph(lines, meta)
+    } else if self.document.meta.is_set("compat-mode") && lines.is_markdown_quote() {
+      self.parse_markdown_quote(lines, meta).map(Some)
     } else {
       self.parse_paragraph(lines, meta)
     }
@@ -121,6 +152,13 @@ impl<'arena> Parser<'arena> {
     line.discard_assert(TokenKind::Whitespace);
     let id = self.section_id(&line, &meta.attrs);
     let content = self.parse_inlines(&mut line.into_lines())?;
+    // `[discrete%toc]` opts the heading into the toc, and xrefs
+    if meta.attrs.has_option("toc") {
+      self.push_toc_node(level, &content, id.as_ref());
+      if let Some(id) = &id {
+        self.push_heading_anchor(id, &content, &meta);
+      }
+    }
     self.restore_lines(lines);
     Ok(Block {
       meta,
@@ -156,6 +194,8 @@ impl<'arena> Parser<'arena> {
     let prev = self.ctx.delimiter;
     self.ctx.delimiter = Some(delimiter);
     let delimiter_token = lines.consume_current_token().unwrap();
+    let _span = span!(DEBUG, "delimited_block", ?delimiter, start = meta.start);
+    self.classify_token_trivia(TriviaKind::Delimiter, &delimiter_token);
     self.restore_lines(lines);
     let context = meta.block_style_or(Context::from(delimiter));
     let restore_subs = self.ctx.set_subs_for(context, &meta);
@@ -170,18 +210,28 @@ impl<'arena> Parser<'arena> {
         | Context::Comment
         | Context::Verse
     ) {
-      let mut lines = self
-        .read_lines_until(delimiter)?
-        .unwrap_or_else(|| ContiguousLines::new(Deq::new(self.bump)));
+      if self.ctx.subs.verbatim_only() {
+        self.lex_verbatim_until(Some(delimiter));
+      }
+      let lines = self.read_lines_until(delimiter);
+      self.lex_verbatim_until(None);
+      let mut lines = lines?.unwrap_or_else(|| ContiguousLines::new(Deq::new(self.bump)));
 
       if let Some(indent) = meta
         .attrs
         .named("indent")
         .and_then(|s| s.parse::<usize>().ok())
       {
-        let delimiter = lines.pop();
+        // the closing delimiter was only read along with the lines
+        // if they weren't broken up by an empty line or an include
+        let closing = match lines.last() {
+          Some(line) if line.is_delimiter(delimiter) => lines.pop(),
+          _ => None,
+        };
         lines.set_indentation(indent);
-        delimiter.map(|d| lines.push(d));
+        if let Some(line) = closing {
+          lines.push(line);
+        }
       }
 
       if context == Context::Listing || context == Context::Literal {
@@ -211,12 +261,18 @@ impl<'arena> Parser<'arena> {
     };
 
     self.ctx.subs = restore_subs;
-    if let Some(mut block) = self.read_lines()? {
-      let token = block.consume_current_token().unwrap();
-      debug_assert!(token.kind(DelimiterLine));
-      self.restore_lines(block);
-    } else {
-      self.err_token_full("This delimiter was never closed", &delimiter_token)?;
+    match self.read_lines()? {
+      Some(mut block) if block.current_token().is_some_and(|t| t.kind(DelimiterLine)) => {
+        let token = block.consume_current_token().unwrap();
+        self.classify_token_trivia(TriviaKind::Delimiter, &token);
+        self.restore_lines(block);
+      }
+      unclosed => {
+        if let Some(block) = unclosed {
+          self.restore_lines(block);
+        }
+        self.err_token_full("This delimiter was never closed", &delimiter_token)?;
+      }
     };
     self.ctx.delimiter = prev;
     Ok(Some(Block { meta, content, context }))
@@ -231,6 +287,7 @@ impl<'arena> Parser<'arena> {
     line.discard_assert(MacroName);
     line.discard_assert(Colon);
     let target = line.consume_macro_target(self.bump);
+    self.check_image_target(&target)?;
     let attrs = self.parse_block_attr_list(&mut line)?;
     Ok(Block {
       meta,
@@ -301,6 +358,85 @@ impl<'arena> Parser<'arena> {
     }))
   }
 
+  // https://docs.asciidoctor.org/asciidoc/latest/blocks/blockquotes/#markdown-style-blockquotes
+  fn parse_markdown_quote(
+    &mut self,
+    mut lines: ContiguousLines<'arena>,
+    mut meta: ChunkMeta<'arena>,
+  ) -> Result<Block<'arena>> {
+    let mut stripped = ContiguousLines::empty(self.bump);
+    while let Some(mut line) = lines.consume_current() {
+      line.discard_assert(TokenKind::GreaterThan);
+      if line.current_is(TokenKind::Whitespace) {
+        line.discard(1);
+      }
+      stripped.push(line);
+    }
+
+    if stripped.len() > 1
+      && stripped.last().is_some_and(|line| {
+        line.starts_with_seq(&[Kind(Dashes), Kind(Whitespace)])
+          && line.num_tokens() > 2
+          && line.current_is_len(Dashes, 2)
+      })
+    {
+      let mut attr_line = stripped.pop().unwrap();
+      attr_line.discard_assert(TokenKind::Dashes); // `--`
+      attr_line.discard_assert(TokenKind::Whitespace);
+      let src = attr_line.consume_to_string(self.bump);
+      let mut attrs = AttrList::new(src.loc, self.bump);
+      let (attr, cite) = src.split_once(", ", self.bump);
+      attrs.positional.push(None);
+      attrs.positional.push(Some(self.text_nodes(attr)));
+      attrs
+        .positional
+        .push(cite.map(|cite| self.text_nodes(cite)));
+      meta.attrs.push(attrs);
+    }
+
+    // the stripped lines were lexed with their `> ` prefix, so delimiters
+    // etc. aren't recognized until lexed again on their own. a lone `>`
+    // becomes a blank line, separating blocks, and `> >` lines nest
+    let offset = stripped.loc().map_or(0, |loc| loc.start);
+    let mut src = BumpVec::new_in(self.bump);
+    while let Some(line) = stripped.consume_current() {
+      src.extend_from_slice(line.reassemble_src().as_bytes());
+      src.push(b'\n');
+    }
+    let mut parser = self.nested_parser(src, offset);
+    let mut blocks = BumpVec::new_in(self.bump);
+    loop {
+      match parser.parse_block() {
+        Ok(Some(block)) => blocks.push(block),
+        Ok(None) => break,
+        Err(diagnostic) => {
+          self.notify_diagnostic_sink(&diagnostic);
+          return Err(diagnostic);
+        }
+      }
+    }
+    parser
+      .errors
+      .take()
+      .into_iter()
+      .for_each(|d| self.push_diagnostic(d));
+    self.includes.append(&mut parser.includes);
+
+    self.restore_lines(lines);
+    Ok(Block {
+      meta,
+      context: Context::BlockQuote,
+      content: Content::Compound(blocks),
+    })
+  }
+
+  fn text_nodes(&self, text: SourceString<'arena>) -> InlineNodes<'arena> {
+    let mut nodes = InlineNodes::new(self.bump);
+    let loc = text.loc;
+    nodes.push(InlineNode::new(Inline::Text(text.src), loc));
+    nodes
+  }
+
   fn parse_break(
     &mut self,
     context: BlockContext,
@@ -1,11 +1,15 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
+
+use asciidork_core::JobAttrs;
 
 use crate::internal::*;
 
 impl<'arena> Parser<'arena> {
   pub(super) fn parse_doc_attrs(&mut self, lines: &mut ContiguousLines<'arena>) -> Result<()> {
     lines.discard_leading_comment_lines();
-    while let Some((key, value, _)) = self.parse_doc_attr(lines)? {
+    while let Some((key, value, loc)) = self.parse_doc_attr(lines)? {
+      self.record_attr_decl(&key, &value, loc, true);
       if key == "doctype" {
         if let AttrValue::String(s) = &value {
           match s.as_str().parse::<DocType>() {
@@ -26,7 +30,7 @@ impl<'arena> Parser<'arena> {
   pub(super) fn parse_doc_attr(
     &mut self,
     lines: &mut ContiguousLines<'arena>,
-  ) -> Result<Option<(String, AttrValue, u32)>> {
+  ) -> Result<Option<(String, AttrValue, SourceLocation)>> {
     let Some(line) = lines.current() else {
       return Ok(None);
     };
@@ -67,14 +71,27 @@ impl<'arena> Parser<'arena> {
     };
 
     if key == "leveloffset" {
-      Parser::adjust_leveloffset(&mut self.ctx.leveloffset, &attr);
+      self.set_leveloffset(line.loc().unwrap().include_depth, &attr);
     }
 
-    Ok(Some((
-      key.to_string(),
-      attr,
-      line.last_location().unwrap().end,
-    )))
+    let mut loc = line.loc().unwrap();
+    loc.end = line.last_location().unwrap().end;
+    Ok(Some((key.to_string(), attr, loc)))
+  }
+
+  pub(crate) fn record_attr_decl(
+    &mut self,
+    name: &str,
+    value: &AttrValue,
+    loc: SourceLocation,
+    in_header: bool,
+  ) {
+    self.document.attr_decls.push(AttrDecl {
+      name: name.to_string(),
+      value: value.clone(),
+      loc,
+      in_header,
+    });
   }
 
   pub(crate) fn replace_attr_vals<'h>(&self, haystack: &'h str) -> Cow<'h, str> {
@@ -89,6 +106,77 @@ impl<'arena> Parser<'arena> {
     })
   }
 
+  /// Resolves refs between job attrs, like `-a docs={host}/docs`, before
+  /// parsing starts, reporting circular references instead of expanding
+  /// them forever. Refs to attrs the job doesn't set are left in place for
+  /// the document to resolve.
+  pub(crate) fn resolve_job_attr_refs(&self, job_attrs: &mut JobAttrs) {
+    let mut names = job_attrs
+      .as_ref()
+      .iter()
+      .filter(|(_, attr)| matches!(&attr.value, AttrValue::String(s) if s.contains('{')))
+      .map(|(name, _)| name.clone())
+      .collect::<Vec<_>>();
+    // sorted, so which ref of a cycle gets reported doesn't vary
+    names.sort();
+    let mut resolved = HashMap::new();
+    for name in &names {
+      self.resolve_job_attr(name, job_attrs, &mut resolved, &mut Vec::new());
+    }
+    for (name, value) in resolved {
+      let readonly = job_attrs.get(&name).is_some_and(|attr| attr.readonly);
+      job_attrs.insert_unchecked(
+        name,
+        JobAttr {
+          readonly,
+          value: AttrValue::String(value),
+        },
+      );
+    }
+  }
+
+  fn resolve_job_attr(
+    &self,
+    name: &str,
+    job_attrs: &JobAttrs,
+    resolved: &mut HashMap<String, String>,
+    stack: &mut Vec<String>,
+  ) -> Option<String> {
+    if let Some(value) = resolved.get(name) {
+      return Some(value.clone());
+    }
+    let Some(AttrValue::String(raw)) = job_attrs.get(name).map(|attr| &attr.value) else {
+      return None;
+    };
+    stack.push(name.to_string());
+    let value = regx::ATTR_VAL_REPLACE.replace_all(raw, |caps: &regex::Captures| {
+      let ref_name = caps.get(1).unwrap().as_str();
+      if stack.iter().any(|n| n == ref_name) {
+        // job attrs come from the settings, not the document, so
+        // there's no source line to point at
+        self.push_diagnostic(Diagnostic {
+          severity: Severity::Error,
+          line_num: 0,
+          line: String::new(),
+          message: format!(
+            "Circular reference to attribute `{ref_name}` in job attribute `{name}={raw}`"
+          ),
+          underline_start: 0,
+          underline_width: 0,
+          source_file: self.lexer.source_file().clone(),
+        });
+        return String::new();
+      }
+      self
+        .resolve_job_attr(ref_name, job_attrs, resolved, stack)
+        .unwrap_or_else(|| caps.get(0).unwrap().as_str().to_string())
+    });
+    let value = value.into_owned();
+    stack.pop();
+    resolved.insert(name.to_string(), value.clone());
+    Some(value)
+  }
+
   fn join_wrapped_value(
     &self,
     mut first_line_src: &str,
@@ -180,6 +268,120 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_attr_decls_recorded_in_order() {
+    let input = adoc! {"
+      = Title
+      :foo: bar
+      :foo: baz
+      :!toc:
+
+      para
+
+      :foo: {foo}-qux
+    "};
+    let parser = test_parser!(input);
+    let document = parser.parse().unwrap().document;
+    let decls = document
+      .attr_decls
+      .iter()
+      .map(|decl| (decl.name.as_str(), decl.value.clone(), decl.in_header))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      decls,
+      vec![
+        ("foo", "bar".into(), true),
+        ("foo", "baz".into(), true),
+        ("toc", false.into(), true),
+        ("foo", "baz-qux".into(), false),
+      ]
+    );
+    assert_eq!(document.attr_decls[1].loc, SourceLocation::new(18, 27));
+  }
+
+  #[test]
+  fn test_job_attr_refs_resolved() {
+    let mut parser = test_parser!(":docs: {site}/docs\n\n{docs}");
+    let mut settings = JobSettings::default();
+    settings
+      .job_attrs
+      .insert_unchecked("site", JobAttr::modifiable("{host}:80"));
+    settings
+      .job_attrs
+      .insert_unchecked("host", JobAttr::readonly("example.com"));
+    settings
+      .job_attrs
+      .insert_unchecked("path", JobAttr::modifiable("{unknown}/x"));
+    parser.apply_job_settings(settings);
+    let result = parser.parse().unwrap();
+    let meta = &result.document.meta;
+    assert_eq!(meta.str("site"), Some("example.com:80"));
+    assert_eq!(meta.str("path"), Some("{unknown}/x"));
+    assert_eq!(
+      result.document.attr_decls[0].value,
+      "example.com:80/docs".into()
+    );
+    assert!(result.warnings.is_empty());
+  }
+
+  #[test]
+  fn test_job_attr_circular_ref() {
+    let mut parser = test_parser!("{a}");
+    let mut settings = JobSettings {
+      strict: false,
+      ..JobSettings::default()
+    };
+    settings
+      .job_attrs
+      .insert_unchecked("a", JobAttr::modifiable("a{b}"));
+    settings
+      .job_attrs
+      .insert_unchecked("b", JobAttr::modifiable("b{a}"));
+    settings
+      .job_attrs
+      .insert_unchecked("c", JobAttr::modifiable("loop {c}"));
+    parser.apply_job_settings(settings);
+    let result = parser.parse().unwrap();
+    let meta = &result.document.meta;
+    assert_eq!(meta.str("a"), Some("ab"));
+    assert_eq!(meta.str("b"), Some("b"));
+    assert_eq!(meta.str("c"), Some("loop "));
+    let messages = result
+      .warnings
+      .iter()
+      .map(|d| (d.line_num, d.message.as_str()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      messages,
+      vec![
+        (
+          0,
+          "Circular reference to attribute `a` in job attribute `b=b{a}`"
+        ),
+        (
+          0,
+          "Circular reference to attribute `c` in job attribute `c=loop {c}`"
+        ),
+      ]
+    );
+    assert_eq!(
+      result.warnings[0].plain_text(),
+      " --> test.adoc\n  = Circular reference to attribute `a` in job attribute `b=b{a}`\n"
+    );
+
+    let mut parser = test_parser!("{c}");
+    let mut settings = JobSettings::default();
+    settings
+      .job_attrs
+      .insert_unchecked("c", JobAttr::modifiable("loop {c}"));
+    parser.apply_job_settings(settings);
+    let errors = parser.parse().err().unwrap();
+    assert_eq!(
+      errors[0].message,
+      "Circular reference to attribute `c` in job attribute `c=loop {c}`"
+    );
+  }
+
   assert_error!(
     test_parse_doc_attr_error_str,
     adoc! {"
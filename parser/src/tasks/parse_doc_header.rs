@@ -13,12 +13,55 @@ impl<'arena> Parser<'arena> {
     }
 
     self.parse_doc_attrs(&mut block)?;
-    self.parse_doc_title_author_revision(&mut block)?;
+    let unresolved = self.parse_doc_title_author_revision(&mut block)?;
     self.parse_doc_attrs(&mut block)?;
+    self.diagnose_header_forward_attr_refs(unresolved)?;
     self.setup_toc();
     Ok(())
   }
 
+  /// An explicit `doctitle`, from the job attrs or an attribute entry, takes
+  /// the place of the title line, so `{doctitle}` and the rendered title agree.
+  pub(crate) fn apply_doctitle_attr(&mut self) -> Result<()> {
+    if self.ctx.table_cell_ctx == TableCellContext::AsciiDocCell {
+      return Ok(());
+    }
+    let Some(doctitle) = self.document.meta.str("doctitle") else {
+      return Ok(());
+    };
+    if self.ctx.doctitle_src.as_deref() == Some(doctitle) {
+      return Ok(());
+    }
+    let mut src = BumpString::with_capacity_in(doctitle.len() + 1, self.bump);
+    src.push_str(doctitle);
+    src.push('\n');
+    let offset = self
+      .document
+      .attr_decls
+      .iter()
+      .rev()
+      .find(|decl| decl.name == "doctitle")
+      .map_or(0, |decl| decl.loc.start);
+    self.lexer.set_tmp_buf(&src, BufLoc::Offset(offset));
+    src.pop();
+    self.ctx.doctitle_src = Some(src);
+    let Some(line) = self.read_line()? else {
+      return Ok(());
+    };
+    let main = self.parse_inlines(&mut line.into_lines())?;
+    match self.document.title.as_mut() {
+      Some(title) => title.main = main,
+      None => {
+        self.document.title = Some(DocTitle {
+          attrs: MultiAttrList::new_in(self.bump),
+          main,
+          subtitle: None,
+        })
+      }
+    }
+    Ok(())
+  }
+
   fn setup_toc(&mut self) {
     let Some(toc_attr) = self.document.meta.get("toc") else {
       return;
@@ -41,9 +84,13 @@ impl<'arena> Parser<'arena> {
     self.document.toc = Some(TableOfContents { title, nodes, position })
   }
 
-  fn parse_doc_title_author_revision(&mut self, lines: &mut ContiguousLines<'arena>) -> Result<()> {
+  fn parse_doc_title_author_revision(
+    &mut self,
+    lines: &mut ContiguousLines<'arena>,
+  ) -> Result<BumpVec<'arena, Token<'arena>>> {
+    let mut unresolved = BumpVec::new_in(self.bump);
     if lines.is_empty() {
-      return Ok(());
+      return Ok(unresolved);
     }
     let meta = self.parse_chunk_meta(lines)?;
     if lines
@@ -52,17 +99,19 @@ impl<'arena> Parser<'arena> {
     {
       // author and revision must follow doc title, so if non title, skip
       self.restore_peeked_meta(meta);
-      return Ok(());
+      return Ok(unresolved);
     }
 
     let mut header_line = lines.consume_current().unwrap();
     debug_assert!(header_line.starts_with_seq(&[Kind(EqualSigns), Kind(Whitespace)]));
     header_line.discard(2); // equals, whitespace
+    let title_src = header_line.reassemble_src();
     self
       .document
       .meta
-      .insert_header_attr("doctitle", header_line.reassemble_src().as_str())
+      .insert_header_attr("doctitle", title_src.as_str())
       .unwrap();
+    self.ctx.doctitle_src = Some(title_src);
 
     self.document.title = Some(DocTitle {
       attrs: meta.attrs,
@@ -71,13 +120,60 @@ impl<'arena> Parser<'arena> {
     });
 
     if lines.starts(Word) {
-      self.parse_author_line(lines.consume_current().unwrap())?;
+      let mut author_line = lines.consume_current().unwrap();
+      self.resolve_header_line_attr_refs(&mut author_line, &mut unresolved);
+      self.parse_author_line(author_line)?;
       // revision line can only follow an author line (and requires a doc header)
       if self.document.meta.is_set("author") {
+        if let Some(revision_line) = lines.current_mut() {
+          self.resolve_header_line_attr_refs(revision_line, &mut unresolved);
+        }
         self.parse_revision_line(lines);
       }
     }
 
+    Ok(unresolved)
+  }
+
+  // the header is lexed as a single block before any of its attribute entries
+  // are processed, so refs in the author and revision lines are resolved here,
+  // against the attributes declared above them
+  fn resolve_header_line_attr_refs(
+    &self,
+    line: &mut Line<'arena>,
+    unresolved: &mut BumpVec<'arena, Token<'arena>>,
+  ) {
+    for token in line.iter_mut() {
+      if !token.kind(Word) || token.len() < 3 {
+        continue;
+      }
+      let Some(name) = token
+        .lexeme
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+      else {
+        continue;
+      };
+      match self.document.meta.get(name) {
+        Some(AttrValue::String(value)) => token.lexeme = self.string(value),
+        _ => unresolved.push(token.clone()),
+      }
+    }
+  }
+
+  fn diagnose_header_forward_attr_refs(
+    &self,
+    unresolved: BumpVec<'arena, Token<'arena>>,
+  ) -> Result<()> {
+    for token in unresolved {
+      let name = &token.lexeme[1..token.lexeme.len() - 1];
+      if self.document.meta.get(name).is_some() {
+        self.err_token_full(
+          format!("Attribute `{name}` referenced before it was defined"),
+          &token,
+        )?;
+      }
+    }
     Ok(())
   }
 
@@ -148,4 +244,95 @@ mod tests {
       );
     }
   }
+
+  #[test]
+  fn test_author_revision_attr_refs() {
+    let input = adoc! {"
+      :author-first: Bob
+      :version: 1.2
+      :released: 2024-01-01
+      = Title
+      {author-first} Smith
+      v{version}, {released}: {missing}
+    "};
+    let mut parser = test_parser!(input);
+    parser.parse_document_header().unwrap();
+    let meta = &parser.document.meta;
+    expect_eq!(meta.str("author"), Some("Bob Smith"));
+    expect_eq!(meta.str("firstname"), Some("Bob"));
+    expect_eq!(meta.str("revnumber"), Some("1.2"));
+    expect_eq!(meta.str("revdate"), Some("2024-01-01"));
+    expect_eq!(meta.str("revremark"), Some("{missing}"));
+  }
+
+  #[test]
+  fn test_doctitle_attr_overrides_title() {
+    let cases = [
+      ("= Real\n\npara", None, "Real", "Real"),
+      ("= Real\n:doctitle: _Foo_\n\npara", None, "_Foo_", "Foo"),
+      (":doctitle: Foo\n\npara", None, "Foo", "Foo"),
+      (":doctitle: Foo\n= Real\n\npara", None, "Real", "Real"),
+      ("= Real\n\npara", Some("Job(R)"), "Job(R)", "Job®"),
+      ("= Real\n:doctitle: Foo\n\npara", Some("Job"), "Job", "Job"),
+    ];
+    for (input, job_doctitle, attr, plain) in cases {
+      let mut parser = test_parser!(input);
+      if let Some(doctitle) = job_doctitle {
+        let mut settings = JobSettings::embedded();
+        settings
+          .job_attrs
+          .insert_unchecked("doctitle", JobAttr::readonly(doctitle));
+        parser.apply_job_settings(settings);
+      }
+      let document = parser.parse().unwrap().document;
+      expect_eq!(document.meta.str("doctitle"), Some(attr), from: input);
+      expect_eq!(document.plain_title().as_deref(), Some(plain), from: input);
+    }
+  }
+
+  assert_error!(
+    author_line_error_after_attr_ref,
+    adoc! {"
+      :first: Bartholomew
+      = Title
+      {first} Smith <bob@example.com> <
+    "},
+    error! {"
+       --> test.adoc:3:32
+        |
+      3 | {first} Smith <bob@example.com> <
+        |                                ^^ invalid author line
+    "}
+  );
+
+  assert_error!(
+    author_line_forward_attr_ref,
+    adoc! {"
+      = Title
+      Bob {last}
+      :last: Smith
+    "},
+    error! {"
+       --> test.adoc:2:5
+        |
+      2 | Bob {last}
+        |     ^^^^^^ Attribute `last` referenced before it was defined
+    "}
+  );
+
+  assert_error!(
+    revision_line_forward_attr_ref,
+    adoc! {"
+      = Title
+      Bob Smith
+      v1.2, {released}
+      :released: 2024-01-01
+    "},
+    error! {"
+       --> test.adoc:3:7
+        |
+      3 | v1.2, {released}
+        |       ^^^^^^^^^^ Attribute `released` referenced before it was defined
+    "}
+  );
 }
@@ -21,6 +21,22 @@ impl<'arena> Parser<'arena> {
             replaced.push_nonpass(token);
           };
         }
+        TokenKind::MacroName
+          if matches!(token.lexeme.as_str(), "stem:" | "latexmath:" | "asciimath:")
+            && line.current_is(TokenKind::OpenBracket) =>
+        {
+          // stem content is raw, so a `+` inside it can't start a passthrough
+          replaced.push_nonpass(token);
+          let mut last = None;
+          while let Some(token) = line.consume_current() {
+            let done = token.kind(TokenKind::CloseBracket) && last != Some(TokenKind::Backslash);
+            last = Some(token.kind);
+            replaced.push_nonpass(token);
+            if done {
+              break;
+            }
+          }
+        }
         TokenKind::Plus if could_be_plus_passthru(prev_kind, token.len()) => {
           if let Some(n) = terminates_plus(token.len() as u8, line, lines) {
             let subs = Substitutions::from_pass_plus_token(&token);
@@ -183,6 +199,10 @@ fn terminates_plus(len: u8, line: &Line, lines: &ContiguousLines) -> Option<usiz
 }
 
 fn terminates_constrained_plus(line: &Line, lines: &ContiguousLines) -> Option<usize> {
+  // constrained passthru content can't start with whitespace (or a newline)
+  if line.current_token().is_none_or(Token::is_whitespaceish) {
+    return None;
+  }
   let stop = &[TokenSpec::Len(1, TokenKind::Plus)];
   if let Some(n) = line.terminates_constrained_in(stop, &InlineCtx::None) {
     return Some(n);
@@ -17,9 +17,7 @@ impl<'arena> Parser<'arena> {
       let src_len = placeholder.loc.size();
       let mut restored = BumpString::with_capacity_in(src_len as usize, self.bump);
       let content = self.ctx.passthrus[index].take().unwrap();
-      for text in content.plain_text().iter() {
-        restored.push_str(text);
-      }
+      restored.push_str(&content.to_plain_text());
       Some(SourceString::new(restored, placeholder.loc))
     } else {
       None
@@ -96,10 +94,12 @@ impl<'arena> Accum<'arena> {
           newline = AdjacentNewline::Leading;
           loc.start -= 1;
         }
-        self.push_node(Symbol(SymbolKind::SpacedEmDash(newline)), loc.incr_end());
+        // a trailing space is absorbed, a trailing newline is added when joining lines
         if let Some(next_token) = next_token {
           next_token.drop_leading_bytes(1);
+          loc = loc.incr_end();
         }
+        self.push_node(Symbol(SymbolKind::SpacedEmDash(newline)), loc);
       }
       _ => self.push_text_token(&token),
     }
@@ -122,6 +122,7 @@ impl<'arena> Accum<'arena> {
         let emdash = self.inlines.last_mut().unwrap();
         emdash.loc.end += 1;
         emdash.content = Symbol(SymbolKind::SpacedEmDash(AdjacentNewline::Trailing));
+        self.text.loc = emdash.loc.clamp_end();
       } else {
         self.push_node(Inline::Newline, self.text.loc);
       }
@@ -140,6 +141,16 @@ impl<'arena> Accum<'arena> {
     }
   }
 
+  /// pushes text standing in for a token, keeping the token's location
+  pub fn push_text_for_token(&mut self, text: &str, token: &Token) {
+    if self.text.loc.end != token.loc.start {
+      self.text.commit_inlines(&mut self.inlines);
+      self.text.loc = token.loc.clamp_start();
+    }
+    self.text.push_str(text);
+    self.text.loc.end = token.loc.end;
+  }
+
   #[inline(always)]
   pub fn push_text_token(&mut self, token: &Token) {
     if self.text.loc.end == token.loc.start {
@@ -205,7 +216,8 @@ pub fn finish_macro<'arena>(
   if let Some(cur_location) = line.loc() {
     loc.extend(cur_location);
     text.loc = loc.clamp_end();
-    loc.end -= 1; // parsing attr list moves us one past end of macro
+    // parsing attr list moves us one token past end of macro
+    loc.end = cur_location.start;
   } else {
     loc.extend(line_end);
     text.loc = loc.clamp_end();
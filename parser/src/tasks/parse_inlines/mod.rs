@@ -19,9 +19,20 @@ impl<'arena> Parser<'arena> {
     lines: &mut ContiguousLines<'arena>,
     stop_tokens: &[TokenSpec],
   ) -> Result<InlineNodes<'arena>> {
+    Ok(self.parse_inlines_until_stop(lines, stop_tokens)?.0)
+  }
+
+  /// parses inlines up to and including the stop tokens, returning whether
+  /// they were found, since a lookahead can't see into passthroughs that
+  /// haven't been replaced yet
+  fn parse_inlines_until_stop(
+    &mut self,
+    lines: &mut ContiguousLines<'arena>,
+    stop_tokens: &[TokenSpec],
+  ) -> Result<(InlineNodes<'arena>, bool)> {
     let inlines = BumpVec::new_in(self.bump).into();
     if lines.is_empty() {
-      return Ok(inlines);
+      return Ok((inlines, false));
     }
 
     let span_loc = lines.loc().unwrap().clamp_start();
@@ -33,7 +44,7 @@ impl<'arena> Parser<'arena> {
       if self.should_stop_at(&line) {
         acc.inlines.remove_trailing_newline();
         lines.restore_if_nonempty(line);
-        return Ok(acc.trimmed_inlines());
+        return Ok((acc.trimmed_inlines(), false));
       }
 
       if line.is_comment() && !subs.callouts() {
@@ -41,7 +52,11 @@ impl<'arena> Parser<'arena> {
           acc.inlines.discard_trailing_newline();
         }
         let token = line.consume_current().unwrap();
-        let comment = line.consume_to_string(self.bump);
+        let comment = if line.is_empty() {
+          SourceString::new(BumpString::new_in(self.bump), token.loc.clamp_end())
+        } else {
+          line.consume_to_string(self.bump)
+        };
         let loc = SourceLocation::new(token.loc.start, comment.loc.end + 1);
         acc.push_node(LineComment(comment.src), loc);
         continue;
@@ -52,10 +67,10 @@ impl<'arena> Parser<'arena> {
           line.discard(stop_tokens.len());
           acc.commit();
           lines.restore_if_nonempty(line);
-          return Ok(acc.trimmed_inlines());
+          return Ok((acc.trimmed_inlines(), true));
         }
 
-        if line.may_contain_inline_pass() {
+        if subs.macros() && line.may_contain_inline_pass() {
           self.replace_inline_pass(&mut line, lines)?;
           break;
         }
@@ -116,6 +131,16 @@ impl<'arena> Parser<'arena> {
             line.discard(1);
             acc.push_node(Symbol(SymbolKind::DoubleLeftArrow), token.loc.incr_end());
           }
+          MacroName
+            if subs.macros()
+              && token.is_ui_macro()
+              && line.continues_inline_macro(&token)
+              && !self.document.meta.is_true("experimental") =>
+          {
+            self.warn_experimental_macro(&token, &line);
+            acc.push_text_token(&token);
+          }
+
           MacroName if subs.macros() && line.continues_inline_macro(&token) => {
             let mut macro_loc = token.loc;
             let line_end = line.last_location().unwrap();
@@ -123,6 +148,7 @@ impl<'arena> Parser<'arena> {
             match token.lexeme.as_str() {
               "image:" => {
                 let target = line.consume_macro_target(self.bump);
+                self.check_image_target(&target)?;
                 let attrs = self.parse_inline_attr_list(&mut line)?;
                 finish_macro(&line, &mut macro_loc, line_end, &mut acc.text);
                 acc.push_node(
@@ -145,33 +171,74 @@ impl<'arena> Parser<'arena> {
               "footnote:" => {
                 let id = line.consume_optional_macro_target(self.bump);
                 lines.restore_if_nonempty(line);
-                let note = self.parse_inlines_until(lines, &[Kind(CloseBracket)])?;
-                extend(&mut macro_loc, &note, 1);
-                let note = if note.is_empty() { None } else { Some(note) };
-                acc.push_node(Macro(Footnote { id, text: note }), macro_loc);
+                let footnote = self.parse_footnote(id, lines, &mut macro_loc)?;
+                acc.push_node(footnote, macro_loc);
                 break;
               }
+              "footnoteref:" => {
+                self.warn_at(
+                  "Deprecated `footnoteref` macro, use `footnote:id[text]` instead",
+                  token.loc.start,
+                  inline_macro_end(&token, &line),
+                );
+                line.discard_assert(OpenBracket);
+                let id = (!line.current_is(CloseBracket)).then(|| {
+                  line.consume_to_string_until_one_of(&[Kind(Comma), Kind(CloseBracket)], self.bump)
+                });
+                if line.current_is(Comma) {
+                  line.discard(1);
+                  line.discard_leading_whitespace();
+                  lines.restore_if_nonempty(line);
+                  let footnote = self.parse_footnote(id, lines, &mut macro_loc)?;
+                  acc.push_node(footnote, macro_loc);
+                  break;
+                }
+                line.discard_assert(CloseBracket);
+                finish_macro(&line, &mut macro_loc, line_end, &mut acc.text);
+                acc.push_node(Macro(Footnote { id, text: None }), macro_loc);
+              }
               "xref:" => {
                 let target = line.consume_macro_target(self.bump);
                 self.push_xref(&target);
-                lines.restore_if_nonempty(line);
-                let nodes = self.parse_inlines_until(lines, &[Kind(CloseBracket)])?;
-                let linktext = if nodes.is_empty() {
-                  macro_loc.end = target.loc.end + 2;
-                  None
+                if line.index_of_kind(CloseBracket).is_some_and(|end| {
+                  line
+                    .iter()
+                    .take(end)
+                    .any(|t| t.kind(EqualSigns) && t.len() == 1)
+                }) {
+                  let mut attrs = self.parse_link_macro_attr_list(&mut line)?;
+                  let linktext = attrs.take_positional(0).filter(|text| !text.is_empty());
+                  finish_macro(&line, &mut macro_loc, line_end, &mut acc.text);
+                  acc.push_node(
+                    Macro(Xref {
+                      target,
+                      linktext,
+                      kind: XrefKind::Macro,
+                      attrs: Some(attrs),
+                    }),
+                    macro_loc,
+                  );
                 } else {
-                  extend(&mut macro_loc, &nodes, 1);
-                  Some(nodes)
-                };
-                acc.push_node(
-                  Macro(Xref {
-                    target,
-                    linktext,
-                    kind: XrefKind::Macro,
-                  }),
-                  macro_loc,
-                );
-                break;
+                  lines.restore_if_nonempty(line);
+                  let nodes = self.parse_inlines_until(lines, &[Kind(CloseBracket)])?;
+                  let linktext = if nodes.is_empty() {
+                    macro_loc.end = target.loc.end + 2;
+                    None
+                  } else {
+                    extend(&mut macro_loc, &nodes, 1);
+                    Some(nodes)
+                  };
+                  acc.push_node(
+                    Macro(Xref {
+                      target,
+                      linktext,
+                      kind: XrefKind::Macro,
+                      attrs: None,
+                    }),
+                    macro_loc,
+                  );
+                  break;
+                }
               }
               "link:" => {
                 if !line.no_whitespace_until(OpenBracket) {
@@ -215,6 +282,18 @@ impl<'arena> Parser<'arena> {
                 finish_macro(&line, &mut macro_loc, line_end, &mut acc.text);
                 acc.push_node(Macro(Button(btn)), macro_loc);
               }
+              "stem:" | "latexmath:" | "asciimath:" => {
+                let notation = match token.lexeme.as_str() {
+                  "latexmath:" => StemNotation::Latexmath,
+                  "asciimath:" => StemNotation::Asciimath,
+                  _ => StemNotation::from_stem_attr(self.document.meta.str("stem")),
+                };
+                line.discard_assert(OpenBracket);
+                let text = line.consume_to_string_until_unescaped(CloseBracket, self.bump);
+                line.discard_assert(CloseBracket);
+                finish_macro(&line, &mut macro_loc, line_end, &mut acc.text);
+                acc.push_node(Macro(Stem { notation, text }), macro_loc);
+              }
               "menu:" => {
                 let first = line.consume_macro_target(self.bump);
                 let mut items = bvec![in self.bump; first];
@@ -248,6 +327,7 @@ impl<'arena> Parser<'arena> {
                     source_loc: Some(id.loc),
                     source_idx: self.lexer.source_idx(),
                     is_biblio: false,
+                    is_footnote: false,
                   },
                 )?;
                 acc.push_node(InlineAnchor(id.src), id.loc);
@@ -358,6 +438,7 @@ impl<'arena> Parser<'arena> {
                 target,
                 linktext,
                 kind: XrefKind::Shorthand,
+                attrs: None,
               }),
               loc,
             );
@@ -448,7 +529,10 @@ impl<'arena> Parser<'arena> {
           }
 
           OpenBracket
-            if subs.inline_formatting() && line.contains_seq(&[Kind(CloseBracket), Kind(Hash)]) =>
+            if subs.inline_formatting()
+              && line.index_of_kind(CloseBracket).is_some_and(|idx| {
+                line.has_seq_at(&[Kind(CloseBracket), Kind(Hash)], idx as u32)
+              }) =>
           {
             let mut parse_token = token.clone();
             let attr_list = self.parse_formatted_text_attr_list(&mut line)?;
@@ -467,6 +551,7 @@ impl<'arena> Parser<'arena> {
                     source_idx: self.lexer.source_idx(),
                     source_loc: Some(id.loc),
                     is_biblio: false,
+                    is_footnote: false,
                   },
                 )?;
               }
@@ -519,17 +604,29 @@ impl<'arena> Parser<'arena> {
           Backtick
             if subs.inline_formatting()
               && line.current_is(Plus)
+              && !line.nth_token(1).kind(Backtick)
               && contains_seq(&[Len(1, Plus), Kind(Backtick)], &line, lines) =>
           {
-            self.ctx.subs.remove(Subs::InlineFormatting);
-            self.ctx.subs.remove(Subs::AttrRefs);
+            // literal monospace content is taken verbatim, and may span lines
+            self.ctx.subs = Substitutions::none();
+            let bump = self.bump;
             self.parse_node(
-              |mut inner| {
-                assert!(inner.len() == 1, "invalid lit mono");
-                match inner.pop().unwrap() {
-                  InlineNode { content: Text(lit), loc } => LitMono(SourceString::new(lit, loc)),
-                  _ => panic!("invalid lit mono"),
+              |inner| {
+                let mut loc = inner.first().unwrap().loc;
+                loc.end = inner.last().unwrap().loc.end;
+                let mut lit = BumpString::new_in(bump);
+                for node in inner.iter() {
+                  match &node.content {
+                    Text(text) => lit.push_str(text),
+                    Inline::Newline => lit.push('\n'),
+                    LineComment(comment) => {
+                      lit.push_str("//");
+                      lit.push_str(comment);
+                    }
+                    _ => {}
+                  }
                 }
+                LitMono(SourceString::new(lit, loc))
               },
               [Len(1, Plus), Kind(Backtick)],
               &token,
@@ -557,8 +654,9 @@ impl<'arena> Parser<'arena> {
 
           Backtick
             if subs.inline_formatting()
-              && self.starts_unconstrained(&[Kind(Backtick)], &token, &line, lines) =>
+              && self.starts_unconstrained(&[Kind(Backtick); 2], &token, &line, lines) =>
           {
+            self.ctx.inline_ctx = InlineCtx::Double([Kind(Backtick); 2]);
             self.parse_node(Mono, [Kind(Backtick); 2], &token, &mut acc, line, lines)?;
             break;
           }
@@ -656,6 +754,31 @@ impl<'arena> Parser<'arena> {
             break;
           }
 
+          Entity if subs.special_chars() => {
+            let name = &token.lexeme[1..token.lexeme.len() - 1];
+            if !name.starts_with('#') && lookup_named_entity(name) == EntityLookup::Unknown {
+              self.warn_token_full(format!("Unknown named entity `{}`", token.lexeme), &token);
+              let amp_loc = SourceLocation {
+                end: token.loc.start + 1,
+                ..token.loc
+              };
+              acc.push_node(SpecialChar(SpecialCharKind::Ampersand), amp_loc);
+              acc.text.push_str(&token.lexeme[1..]);
+            } else if let Some(chars) = self
+              .document
+              .meta
+              .is_true("decode-entities")
+              .then(|| decode_entity(&token.lexeme))
+              .flatten()
+              // markup-significant chars stay encoded, so escaped html can't become live
+              .filter(|chars| !chars.contains(['<', '>', '&', '"', '\'']))
+            {
+              acc.push_text_for_token(&chars, &token);
+            } else {
+              acc.push_text_token(&token);
+            }
+          }
+
           // already encoded entities, eg: &#8212;
           Ampersand if line.starts_with_seq(&[Kind(Hash), Kind(Digits), Kind(SemiColon)]) => {
             acc.push_text_token(&token);
@@ -685,19 +808,35 @@ impl<'arena> Parser<'arena> {
             acc.push_node(MultiCharWhitespace(token.lexeme), token.loc);
           }
 
-          Whitespace if line.current_is(Plus) && line.num_tokens() == 1 => {
+          Whitespace
+            if subs.post_replacement() && line.current_is(Plus) && line.num_tokens() == 1 =>
+          {
             let mut loc = token.loc;
             line.discard_assert(Plus);
-            loc.end += 2; // plus and newline
+            // plus, and the newline unless this is the last line
+            loc.end += if lines.is_empty() { 1 } else { 2 };
             acc.push_node(LineBreak, loc);
             break;
           }
 
+          // table cells are parsed as a single line with embedded newlines
+          Whitespace
+            if subs.post_replacement()
+              && line.current_is(Plus)
+              && line.nth_token(1).kind(TokenKind::Newline) =>
+          {
+            let mut loc = token.loc;
+            line.discard_assert(Plus);
+            line.discard_assert(TokenKind::Newline);
+            loc.end += 2; // plus and newline
+            acc.push_node(LineBreak, loc);
+          }
+
           TokenKind::Newline => acc.push_node(Inline::Newline, token.loc),
 
           Discard | AttrRef => acc.text.loc = token.loc.clamp_end(),
 
-          Backslash => {
+          Backslash if subs.escapable() => {
             match line.current_token().map(|t| t.kind) {
               Some(Word) | None => acc.push_text_token(&token),
               _ => {
@@ -742,7 +881,41 @@ impl<'arena> Parser<'arena> {
     }
 
     acc.commit();
-    Ok(acc.trimmed_inlines())
+    Ok((acc.trimmed_inlines(), false))
+  }
+
+  // UI macros are rendered as their original source text when
+  // `experimental` is not set, which we flag, since it's easy to forget
+  fn warn_experimental_macro(&self, token: &Token<'arena>, line: &Line<'arena>) {
+    let end = inline_macro_end(token, line);
+    self.warn_at(
+      format!(
+        "Experimental macros disabled, `{}[]` rendered as text (set `:experimental:` to enable)",
+        token.lexeme
+      ),
+      token.loc.start,
+      end,
+    );
+  }
+
+  /// parses the text of a footnote up to its closing bracket,
+  /// registering an anchor for it when it has an id
+  fn parse_footnote(
+    &mut self,
+    id: Option<SourceString<'arena>>,
+    lines: &mut ContiguousLines<'arena>,
+    macro_loc: &mut SourceLocation,
+  ) -> Result<Inline<'arena>> {
+    let note = self.parse_inlines_until(lines, &[Kind(CloseBracket)])?;
+    extend(macro_loc, &note, 1);
+    let note = (!note.is_empty()).then_some(note);
+    if let (Some(id), Some(note)) = (&id, &note) {
+      let mut anchor = self.anchor_from(None, Some(id.loc), false);
+      anchor.title = self.auto_reftext(note);
+      anchor.is_footnote = true;
+      self.insert_anchor(id, anchor)?;
+    }
+    Ok(Macro(Footnote { id, text: note }))
   }
 
   fn push_xref(&mut self, target: &SourceString<'arena>) {
@@ -752,7 +925,8 @@ impl<'arena> Parser<'arena> {
       ref_id.drain(..1);
       ref_loc.start += 1;
     }
-    self.ctx.xrefs.borrow_mut().insert(ref_id, ref_loc);
+    let key = (ref_id, ref_loc.include_depth);
+    self.ctx.xrefs.borrow_mut().insert(key, ref_loc);
   }
 
   fn parse_uri_scheme_macro(
@@ -804,8 +978,8 @@ impl<'arena> Parser<'arena> {
       stop_len += tok.len();
     });
     lines.restore_if_nonempty(line);
-    let inner = self.parse_inlines_until(lines, &stop_tokens)?;
-    extend(&mut loc, &inner, stop_len);
+    let (inner, closed) = self.parse_inlines_until_stop(lines, &stop_tokens)?;
+    extend(&mut loc, &inner, if closed { stop_len } else { 0 });
     state.push_node(wrap(inner), loc);
     push_newline_if_needed(state, lines);
     self.ctx.inline_ctx = InlineCtx::None;
@@ -816,8 +990,9 @@ impl<'arena> Parser<'arena> {
     // delimiter
     (line.current_is(DelimiterLine) && self.ctx.can_nest_blocks)
 
-    // new block from attr list-ish
-    || line.is_block_attr_list() || line.is_block_anchor()
+    // new block from attr list-ish, but not the remainder of a partially parsed
+    // line, as in `*bold*[[anchor]]`
+    || (line.is_fully_unconsumed() && (line.is_block_attr_list() || line.is_block_anchor()))
 
     // description list
     || (
@@ -856,6 +1031,14 @@ impl<'arena> Parser<'arena> {
   }
 }
 
+// end of the closing bracket of the inline macro started by `token`
+fn inline_macro_end(token: &Token, line: &Line) -> u32 {
+  line
+    .index_of_seq(&[Not(Backslash), Kind(CloseBracket)])
+    .and_then(|idx| line.nth_token(idx + 1))
+    .map_or(token.loc.end, |close| close.loc.end)
+}
+
 fn link_macro_blank_window_shorthand(attr_list: &mut AttrList) -> bool {
   let Some(mut nodes) = attr_list.take_positional(0) else {
     return false;
@@ -27,9 +27,17 @@ impl<'arena> Parser<'arena> {
       self.ctx.bibliography_ctx = BiblioContext::List;
     }
 
+    // a `subs` attr on the list applies to the principal text of its items,
+    // as it would to a paragraph, but not to the blocks attached to them
+    let item_subs = meta.as_ref().map(|meta| {
+      let restore = self.ctx.set_subs_for(variant.to_context(), meta);
+      std::mem::replace(&mut self.ctx.subs, restore)
+    });
+    let prev_item_subs = std::mem::replace(&mut self.ctx.list.item_subs, item_subs);
     while let Some(item) = self.parse_list_item(variant, &mut auto_conum)? {
       items.push(item);
     }
+    self.ctx.list.item_subs = prev_item_subs;
 
     if self.ctx.bibliography_ctx == BiblioContext::List {
       self.ctx.bibliography_ctx = BiblioContext::Section;
@@ -112,7 +120,8 @@ impl<'arena> Parser<'arena> {
     }
 
     let mut item_lines = ContiguousLines::new(item_lines);
-    let principle = self.parse_inlines(&mut item_lines)?;
+    let principle = self.parse_item_principle(&mut item_lines)?;
+    self.set_leading_anchor_title(&principle);
     let blocks = self.parse_list_item_blocks(lines, BumpVec::new_in(self.bump))?;
 
     Ok(Some(ListItem {
@@ -124,6 +133,17 @@ impl<'arena> Parser<'arena> {
     }))
   }
 
+  fn parse_item_principle(
+    &mut self,
+    lines: &mut ContiguousLines<'arena>,
+  ) -> Result<InlineNodes<'arena>> {
+    let restore_subs = self.ctx.subs;
+    self.ctx.subs = self.ctx.list.item_subs.unwrap_or(restore_subs);
+    let principle = self.parse_inlines(lines);
+    self.ctx.subs = restore_subs;
+    principle
+  }
+
   fn parse_list_item_blocks(
     &mut self,
     lines: ContiguousLines<'arena>,
@@ -213,9 +233,11 @@ impl<'arena> Parser<'arena> {
       self.restore_lines(lines);
     }
 
-    let description = self
-      .parse_block()?
-      .filter(|block| block.context != BlockContext::Comment);
+    let restore_subs = self.ctx.subs;
+    self.ctx.subs = self.ctx.list.item_subs.unwrap_or(restore_subs);
+    let description = self.parse_block();
+    self.ctx.subs = restore_subs;
+    let description = description?.filter(|block| block.context != BlockContext::Comment);
 
     Ok(Some(ListItem {
       blocks: self.parse_description_list_item_blocks()?,
@@ -232,8 +254,9 @@ impl<'arena> Parser<'arena> {
   ) -> Result<(InlineNodes<'arena>, SourceString<'arena>)> {
     let principle = {
       let before_delim = line.extract_line_before(&[Kind(TermDelimiter)]);
-      self.parse_inlines(&mut before_delim.into_lines())?
+      self.parse_item_principle(&mut before_delim.into_lines())?
     };
+    self.set_leading_anchor_title(&principle);
 
     let marker_token = line.consume_current().unwrap();
     let marker_src = marker_token.into_source_string();
@@ -30,24 +30,31 @@ impl<'arena> Parser<'arena> {
       return Ok(None);
     }
 
+    let mut level = level;
     let last_level = self.ctx.section_level;
-    self.ctx.section_level = level;
     let mut heading_line = lines.consume_current().unwrap();
     let equals = heading_line.consume_current().unwrap();
     heading_line.discard_assert(TokenKind::Whitespace);
     let id = self.section_id(&heading_line, &meta.attrs);
 
-    let out_of_sequence = level > last_level && level - last_level > 1;
+    let mut out_of_sequence = level > last_level && level - last_level > 1;
     if out_of_sequence {
-      self.err_token_full(
-        format!(
-          "Section title out of sequence: expected level {} `{}`",
-          last_level + 1,
-          "=".repeat((last_level + 2) as usize)
-        ),
-        &equals,
-      )?;
+      let message = format!(
+        "Section title out of sequence: expected level {} `{}`",
+        last_level + 1,
+        "=".repeat((last_level + 2) as usize)
+      );
+      match self.ctx.section_level_policy {
+        SectionLevelPolicy::Strict => self.err_token_full(message, &equals)?,
+        SectionLevelPolicy::AutoAdjust => {
+          self.warn_token_full(format!("{message}, adjusted"), &equals);
+          level = last_level + 1;
+          out_of_sequence = false;
+        }
+        SectionLevelPolicy::Ignore => {}
+      }
     }
+    self.ctx.section_level = level;
 
     let heading = self.parse_inlines(&mut heading_line.into_lines())?;
     if !out_of_sequence {
@@ -55,21 +62,7 @@ impl<'arena> Parser<'arena> {
     }
 
     if let Some(id) = &id {
-      let reftext = meta
-        .attrs
-        .iter()
-        .find_map(|a| a.named.get("reftext"))
-        .cloned();
-      self.document.anchors.borrow_mut().insert(
-        id.clone(),
-        Anchor {
-          reftext,
-          title: heading.clone(),
-          source_loc: None,
-          source_idx: self.lexer.source_idx(),
-          is_biblio: false,
-        },
-      );
+      self.push_heading_anchor(id, &heading, &meta);
     }
 
     if meta.attrs.str_positional_at(0) == Some("bibliography") {
@@ -87,6 +80,30 @@ impl<'arena> Parser<'arena> {
     Ok(Some(Section { meta, level, id, heading, blocks }))
   }
 
+  pub(crate) fn push_heading_anchor(
+    &mut self,
+    id: &BumpString<'arena>,
+    heading: &InlineNodes<'arena>,
+    meta: &ChunkMeta<'arena>,
+  ) {
+    let reftext = meta
+      .attrs
+      .iter()
+      .find_map(|a| a.named.get("reftext"))
+      .cloned();
+    self.document.anchors.borrow_mut().insert(
+      id.clone(),
+      Anchor {
+        reftext,
+        title: self.auto_reftext(heading),
+        source_loc: None,
+        source_idx: self.lexer.source_idx(),
+        is_biblio: false,
+        is_footnote: false,
+      },
+    );
+  }
+
   pub fn push_toc_node(
     &mut self,
     level: u8,
@@ -99,15 +116,16 @@ impl<'arena> Parser<'arena> {
     if level > self.document.meta.u8_or("toclevels", 2) {
       return;
     }
-    let mut depth = level;
+    let mut depth = level.min(1);
     let mut nodes: &mut BumpVec<'_, TocNode<'_>> = toc.nodes.as_mut();
-    while depth > 1 {
-      // we don't push out of sequence sections, shouldn't panic
+    // out of sequence sections aren't pushed, but discrete headings
+    // can be deeper than their parent, so they nest as deep as they can
+    while depth < level && !nodes.is_empty() {
       nodes = nodes.last_mut().unwrap().children.as_mut();
-      depth -= 1;
+      depth += 1;
     }
     nodes.push(TocNode {
-      level,
+      level: depth,
       title: heading.clone(),
       id: as_ref.cloned(),
       children: BumpVec::new_in(self.bump),
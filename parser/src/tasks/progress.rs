@@ -0,0 +1,29 @@
+use crate::internal::*;
+
+impl Parser<'_> {
+  pub(crate) fn report_block_parsed(&mut self) {
+    if let Some(handler) = &self.progress {
+      self.blocks_parsed += 1;
+      handler.emit(&ProgressEvent::BlockParsed {
+        bytes_consumed: self.lexer.primary_source_pos(),
+        total_bytes: self.lexer.primary_source_len(),
+        blocks_parsed: self.blocks_parsed,
+      });
+    }
+  }
+
+  pub(crate) fn report_enter_include(&self, file: &Path, bytes: usize) {
+    if let Some(handler) = &self.progress {
+      handler.emit(&ProgressEvent::EnterInclude { file: file.to_string(), bytes });
+    }
+  }
+
+  pub(crate) fn report_finished(&self) {
+    if let Some(handler) = &self.progress {
+      handler.emit(&ProgressEvent::Finished {
+        total_bytes: self.lexer.primary_source_len(),
+        blocks_parsed: self.blocks_parsed,
+      });
+    }
+  }
+}
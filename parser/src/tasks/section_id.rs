@@ -17,6 +17,7 @@ impl<'arena> Parser<'arena> {
       self.ctx.anchor_ids.borrow_mut().insert(custom_id.clone());
       return Some(custom_id);
     }
+    let namespace = self.section_id_namespace();
     let id_sep = match self.document.meta.get("idseparator") {
       Some(AttrValue::Bool(true)) => None,
       Some(AttrValue::String(s)) => s.chars().next(),
@@ -27,11 +28,59 @@ impl<'arena> Parser<'arena> {
       Some(AttrValue::String(s)) => s,
       _ => "_",
     };
+    let namespaced_prefix = namespace.map(|ns| file::namespaced_id(&ns, id_prefix));
+    let id_prefix = namespaced_prefix.as_deref().unwrap_or(id_prefix);
     let auto_gen_id = self.autogen_sect_id(&line.reassemble_src(), id_prefix, id_sep, false);
     self.ctx.anchor_ids.borrow_mut().insert(auto_gen_id.clone());
+    if namespaced_prefix.is_some() {
+      self
+        .document
+        .meta
+        .namespaced_ids
+        .insert(auto_gen_id.to_string());
+    }
     Some(auto_gen_id)
   }
 
+  /// the namespace for ids generated in included files when the
+  /// `sectid-namespace` attribute is set, from the file's path relative to
+  /// the docdir, so same-named files in different dirs don't collide
+  fn section_id_namespace(&mut self) -> Option<String> {
+    if self.lexer.source_is_primary() || !self.document.meta.is_true("sectid-namespace") {
+      return None;
+    }
+    let source_idx = self.lexer.source_idx();
+    if let Some(namespace) = self.document.meta.id_namespaces.get(&source_idx) {
+      return Some(namespace.clone());
+    }
+    let namespace = file::id_namespace(&self.docdir_relative_path());
+    self
+      .document
+      .meta
+      .id_namespaces
+      .insert(source_idx, namespace.clone());
+    Some(namespace)
+  }
+
+  fn docdir_relative_path(&self) -> String {
+    let source_file = self.lexer.source_file();
+    let SourceFile::Path(path) = source_file else {
+      return source_file.display_name().to_string();
+    };
+    let docdir = match self.document.meta.str("docdir") {
+      Some(docdir) if !docdir.is_empty() => Some(docdir.to_string()),
+      // docdir is hidden in the stricter safe modes, and unset in memory
+      _ => self
+        .include_resolver
+        .as_ref()
+        .and_then(|r| r.get_base_dir()),
+    };
+    docdir
+      .and_then(|docdir| path.relative_to(&Path::new(docdir)))
+      .unwrap_or_else(|| path.clone())
+      .to_string()
+  }
+
   /// @see https://docs.asciidoctor.org/asciidoc/latest/sections/auto-ids/#how-a-section-id-is-computed
   fn autogen_sect_id(
     &self,
@@ -83,6 +132,19 @@ impl<'arena> Parser<'arena> {
       id.pop();
     }
 
+    if let Some(max) = self
+      .document
+      .meta
+      .isize("idmaxlength")
+      .filter(|max| *max > 0)
+    {
+      let truncated = truncate_graphemes(&id, max as usize);
+      if truncated.len() < id.len() {
+        let truncated = separator.map_or(truncated, |c| truncated.trim_end_matches(c));
+        id = self.string(truncated);
+      }
+    }
+
     if separator.is_some() && id.is_empty() {
       return self.sequence_sectid(&id, separator);
     }
@@ -177,4 +239,25 @@ mod tests {
       assert_eq!(id, *expected);
     }
   }
+
+  #[test]
+  fn test_autogen_sect_id_maxlength() {
+    let cases = &[
+      ("Short", "_short"),
+      ("A very long section title", "_a_very_lo"),
+      ("Abcdefgh ij", "_abcdefgh"),
+      ("Übergrößenträger", "_übergröße"),
+      ("किताबें किताबें किताबें", "_किताबें_किताबें_कि"),
+    ];
+    for (input, expected) in cases {
+      let mut parser = test_parser!("");
+      parser
+        .document
+        .meta
+        .insert_doc_attr("idmaxlength", "10")
+        .unwrap();
+      let id = parser.autogen_sect_id(input, "_", Some('_'), false);
+      assert_eq!(id, *expected, "input: {input}");
+    }
+  }
 }
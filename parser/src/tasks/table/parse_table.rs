@@ -14,6 +14,7 @@ impl<'arena> Parser<'arena> {
     meta: ChunkMeta<'arena>,
   ) -> Result<Block<'arena>> {
     let delim_line = lines.consume_current().unwrap();
+    let _span = span!(DEBUG, "table", start = meta.start);
     let first_token = delim_line.current_token().unwrap();
     let delim_ch = first_token.lexeme.as_bytes()[0];
     debug_assert!(first_token.lexeme.len() == 1);
@@ -212,10 +213,12 @@ impl<'arena> Parser<'arena> {
       cell_tokens.remove_resolved_attr_refs();
       let cell_parser = self.cell_parser(cell_tokens.into_bytes(), loc.start);
       return match cell_parser.parse() {
-        Ok(ParseResult { document, warnings }) => {
-          if !warnings.is_empty() {
-            self.errors.borrow_mut().extend(warnings);
-          }
+        Ok(ParseResult { mut document, warnings, includes }) => {
+          // the cell parser lexed a rewritten copy of the cell, but its
+          // locations are offset to point into the enclosing document's source
+          document.sources.clear();
+          warnings.into_iter().for_each(|w| self.push_diagnostic(w));
+          self.includes.extend(includes);
           let content = CellContent::AsciiDoc(document);
           let cell = Cell::new(content, cell_spec, col_spec.cloned());
           Ok(Some((cell, repeat)))
@@ -224,7 +227,9 @@ impl<'arena> Parser<'arena> {
           if !diagnostics.is_empty() && self.strict {
             Err(diagnostics.remove(0))
           } else {
-            self.errors.borrow_mut().extend(diagnostics);
+            diagnostics
+              .into_iter()
+              .for_each(|d| self.push_diagnostic(d));
             Ok(None)
           }
         }
@@ -264,6 +269,7 @@ impl<'arena> Parser<'arena> {
         CellContent::AsciiDoc(_) | CellContent::Literal(_) => {
           let data = ctx.header_reparse_cells.remove(0);
           let cell = self.parse_non_asciidoc_cell(data, CellContentStyle::Default)?;
+          row.cells[idx].id = cell.id;
           cell.content
         }
         CellContent::Emphasis(paras) => CellContent::Default(paras),
@@ -281,6 +287,7 @@ impl<'arena> Parser<'arena> {
     mut data: ParseCellData<'arena>,
     cell_style: CellContentStyle,
   ) -> Result<Cell<'arena>> {
+    let mut id = None;
     let nodes = if data.cell_tokens.is_empty() {
       InlineNodes::new(self.bump)
     } else {
@@ -289,9 +296,11 @@ impl<'arena> Parser<'arena> {
       self.ctx.table_cell_ctx = TableCellContext::Cell;
       let prev_subs = self.ctx.subs;
       self.ctx.subs = cell_style.into();
-      let inlines = self.parse_inlines(&mut data.cell_tokens.into_lines())?;
+      let mut inlines = self.parse_inlines(&mut data.cell_tokens.into_lines())?;
       self.ctx.subs = prev_subs;
       self.ctx.table_cell_ctx = prev_tbl_ctx;
+      self.set_leading_anchor_title(&inlines);
+      id = take_leading_anchor(&mut inlines);
       inlines
     };
 
@@ -304,7 +313,9 @@ impl<'arena> Parser<'arena> {
       CellContentStyle::Literal => CellContent::Literal(nodes),
       CellContentStyle::AsciiDoc => unreachable!("Parser::finish_cell() asciidoc"),
     };
-    Ok(Cell::new(content, data.cell_spec, data.col_spec))
+    let mut cell = Cell::new(content, data.cell_spec, data.col_spec);
+    cell.id = id;
+    Ok(cell)
   }
 
   fn split_paragraphs(&self, nodes: InlineNodes<'arena>) -> BumpVec<'arena, InlineNodes<'arena>> {
@@ -380,6 +391,28 @@ fn newline_token(start: u32, bump: &Bump) -> Token {
   }
 }
 
+// an anchor starting the cell, e.g. `|[[total]]42`, identifies the cell
+// element itself, so it moves from the content onto the `<td>`
+fn take_leading_anchor<'arena>(nodes: &mut InlineNodes<'arena>) -> Option<BumpString<'arena>> {
+  let Some(Inline::InlineAnchor(_)) = nodes.first().map(|node| &node.content) else {
+    return None;
+  };
+  let Inline::InlineAnchor(id) = nodes.remove(0).content else {
+    unreachable!("take_leading_anchor");
+  };
+  match nodes.first_mut().map(|node| &mut node.content) {
+    Some(Inline::Newline) => {
+      nodes.remove(0);
+    }
+    Some(Inline::Text(text)) if text.starts_with(char::is_whitespace) => {
+      let trimmed = text.trim_start().len();
+      text.replace_range(..text.len() - trimmed, "");
+    }
+    _ => {}
+  }
+  Some(id)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
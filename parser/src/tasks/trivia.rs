@@ -0,0 +1,14 @@
+use crate::internal::*;
+
+impl<'arena> Parser<'arena> {
+  pub(crate) fn classify_line_trivia(&mut self, kind: TriviaKind, line: &Line<'arena>) {
+    if let (Some(mut loc), Some(last)) = (line.loc(), line.last_location()) {
+      loc.end = last.end;
+      self.lexer.classify_trivia(kind, loc);
+    }
+  }
+
+  pub(crate) fn classify_token_trivia(&mut self, kind: TriviaKind, token: &Token<'arena>) {
+    self.lexer.classify_trivia(kind, token.loc);
+  }
+}
@@ -111,6 +111,11 @@ impl<'arena> Token<'arena> {
     self.lexeme.len()
   }
 
+  /// UI macros (`kbd:`, `btn:`, `menu:`) require the `experimental` attr
+  pub fn is_ui_macro(&self) -> bool {
+    self.kind == TokenKind::MacroName && matches!(self.lexeme.as_str(), "kbd:" | "btn:" | "menu:")
+  }
+
   pub fn parse_callout_num(&self) -> Option<u8> {
     let ascii_digits = self
       .lexeme
@@ -151,6 +156,17 @@ impl<'arena> Token<'arena> {
     }
   }
 
+  /// `Word` tokens also hold non-ASCII punctuation (`。`, `»`, etc.), so
+  /// boundary checks need to look at the first char, not just the kind
+  pub fn starts_with_word_char(&self) -> bool {
+    self.kind == TokenKind::Word
+      && self
+        .lexeme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+  }
+
   pub fn attr_name(&self) -> &str {
     assert_eq!(self.kind, TokenKind::AttrRef);
     &self.lexeme[1..self.lexeme.len() - 1]
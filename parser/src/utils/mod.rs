@@ -4,3 +4,5 @@ pub mod bump {
   pub use bumpalo::vec as bvec;
   pub use bumpalo::Bump;
 }
+
+pub mod truncate;
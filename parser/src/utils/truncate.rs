@@ -0,0 +1,37 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The longest prefix of `s` holding at most `max` grapheme clusters, so a
+/// truncated id or reftext never splits a combining sequence or an emoji,
+/// let alone a multi-byte char.
+pub fn truncate_graphemes(s: &str, max: usize) -> &str {
+  match s.grapheme_indices(true).nth(max) {
+    Some((idx, _)) => &s[..idx],
+    None => s,
+  }
+}
+
+pub fn grapheme_len(s: &str) -> usize {
+  s.graphemes(true).count()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_truncate_graphemes() {
+    let cases = [
+      ("hello", 10, "hello"),
+      ("hello", 5, "hello"),
+      ("hello", 3, "hel"),
+      ("hello", 0, ""),
+      ("naïve", 3, "naï"),
+      ("cafe\u{301}s", 4, "cafe\u{301}"),
+      ("日本語の見出し", 3, "日本語"),
+      ("👨‍👩‍👧‍👦 family", 2, "👨‍👩‍👧‍👦 "),
+    ];
+    for (input, max, expected) in cases {
+      assert_eq!(truncate_graphemes(input, max), expected, "input: {input}");
+    }
+  }
+}
@@ -1,6 +1,7 @@
 mod attrs;
 mod parse_blocks;
 mod parse_breaks;
+mod parse_builder;
 mod parse_callout_lists;
 mod parse_callouts;
 mod parse_description_lists;
@@ -9,10 +10,14 @@ mod parse_ifdefs;
 mod parse_ifevals;
 mod parse_includes;
 mod parse_inlines;
+mod parse_invariants;
 mod parse_macros;
 mod parse_ordered_lists;
+mod parse_progress;
 mod parse_quote_blocks;
 mod parse_sections;
 mod parse_toc;
+mod parse_trivia;
 mod parse_unordered_lists;
 mod parse_verbatim_blocks;
+mod split_document;
@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+
+use asciidork_ast::prelude::*;
+use asciidork_core::JobSettings;
+use asciidork_parser::includes::*;
+use asciidork_parser::prelude::*;
+use test_utils::*;
+
+#[test]
+fn builder_configures_parser() {
+  let bump = &Bump::new();
+  let parser = Parser::builder(bump)
+    .source_str("include::bar.adoc[]\n")
+    .file(SourceFile::Path(Path::new("/docs/foo.adoc")))
+    .settings(JobSettings::r#unsafe())
+    .resolver(Box::new(
+      MemoryResolver::new().with_file("bar.adoc", "bar\n"),
+    ))
+    .build();
+  let result = parser.parse().unwrap();
+  expect_eq!(
+    result
+      .includes
+      .iter()
+      .map(|i| i.path.as_str())
+      .collect::<Vec<_>>(),
+    vec!["/bar.adoc"]
+  );
+  expect_eq!(
+    result.document.content,
+    DocContent::Blocks(vecb![Block {
+      context: BlockContext::Paragraph,
+      content: BlockContent::Simple(nodes![node!("bar"; 0..3, depth: 1)]),
+      ..empty_block!(0)
+    }])
+  );
+}
+
+#[test]
+fn diagnostic_sink_receives_recoverable_diagnostics() {
+  let bump = &Bump::new();
+  let seen = RefCell::new(Vec::new());
+  let parser = Parser::builder(bump)
+    .source_str("foo &bogus; bar\n\n____\nbaz\n")
+    .settings(JobSettings {
+      strict: false,
+      ..JobSettings::default()
+    })
+    .diagnostic_sink(|diagnostic| seen.borrow_mut().push(diagnostic.message.clone()))
+    .build();
+  let result = parser.parse().unwrap();
+  let warnings = result
+    .warnings
+    .iter()
+    .map(|diagnostic| diagnostic.message.clone())
+    .collect::<Vec<_>>();
+  expect_eq!(
+    warnings,
+    vec![
+      "Unknown named entity `&bogus;`".to_string(),
+      "This delimiter was never closed".to_string(),
+    ]
+  );
+  expect_eq!(*seen.borrow(), warnings);
+}
+
+#[test]
+fn diagnostic_sink_receives_strict_mode_error() {
+  let bump = &Bump::new();
+  let seen = RefCell::new(Vec::new());
+  let parser = Parser::builder(bump)
+    .source_str("____\nbaz\n")
+    .diagnostic_sink(|diagnostic| seen.borrow_mut().push(diagnostic.message.clone()))
+    .build();
+  let errors = parser.parse().err().unwrap();
+  expect_eq!(errors.len(), 1);
+  expect_eq!(*seen.borrow(), vec![errors[0].message.clone()]);
+}
@@ -1,4 +1,5 @@
 use asciidork_ast::{prelude::*, AttrValue};
+use asciidork_core::{JobSettings, SectionLevelPolicy};
 use asciidork_parser::prelude::*;
 use test_utils::*;
 
@@ -178,3 +179,73 @@ assert_error!(
       | ^^^^ Section title out of sequence: expected level 2 `===`
   "}
 );
+
+#[test]
+fn section_level_policy_auto_adjust() {
+  let input = adoc! {"
+    == ch 1
+
+    ==== ch 2
+  "};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings {
+    section_level_policy: SectionLevelPolicy::AutoAdjust,
+    ..JobSettings::default()
+  });
+  let result = parser.parse().unwrap();
+  let expected_warning = error! {"
+     --> test.adoc:3:1
+      |
+    3 | ==== ch 2
+      | ^^^^ Section title out of sequence: expected level 2 `===`, adjusted
+  "};
+  expect_eq!(result.warnings.len(), 1);
+  expect_eq!(result.warnings[0].plain_text(), expected_warning, from: input);
+  expect_eq!(
+    result.document.content,
+    DocContent::Sectioned {
+      preamble: None,
+      sections: vecb![Section {
+        meta: chunk_meta!(0),
+        level: 1,
+        id: Some(bstr!("_ch_1")),
+        heading: just!("ch 1", 3..7),
+        blocks: vecb![Block {
+          meta: chunk_meta!(9),
+          context: BlockContext::Section,
+          content: BlockContent::Section(Section {
+            meta: chunk_meta!(9),
+            level: 2,
+            id: Some(bstr!("_ch_2")),
+            heading: just!("ch 2", 14..18),
+            blocks: vecb![]
+          }),
+        }]
+      }]
+    },
+    from: input
+  );
+}
+
+#[test]
+fn section_level_policy_ignore() {
+  let input = adoc! {"
+    == ch 1
+
+    ==== ch 2
+  "};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings {
+    section_level_policy: SectionLevelPolicy::Ignore,
+    ..JobSettings::default()
+  });
+  let result = parser.parse().unwrap();
+  assert!(result.warnings.is_empty());
+  let DocContent::Sectioned { sections, .. } = result.document.content else {
+    panic!("expected sectioned content");
+  };
+  let BlockContent::Section(nested) = &sections[0].blocks[0].content else {
+    panic!("expected nested section");
+  };
+  expect_eq!(nested.level, 3);
+}
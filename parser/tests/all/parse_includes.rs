@@ -1,5 +1,5 @@
 use asciidork_ast::prelude::*;
-use asciidork_core::{JobAttr, JobSettings};
+use asciidork_core::{content_hash, IncludeFingerprint, JobAttr, JobSettings};
 use asciidork_parser::includes::*;
 use asciidork_parser::prelude::*;
 use test_utils::*;
@@ -363,6 +363,147 @@ fn include_resolver_error_uri_read_not_supported() {
   expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected_err);
 }
 
+#[test]
+fn include_exceeding_max_source_len() {
+  let mut parser = test_parser!("include::huge.log[]");
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(OversizedResolver));
+  let expected_err = error! {"
+     --> test.adoc:1:10
+      |
+    1 | include::huge.log[]
+      |          ^^^^^^^^ Included file exceeds maximum supported size of 4GiB
+  "};
+  expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected_err);
+}
+
+#[test]
+fn include_outside_base_dir_in_safe_mode() {
+  let input = "include::../../etc/passwd[]";
+  let resolver =
+    || MemoryResolver::with_base_dir("/docs/guide").with_file("/docs/etc/passwd", "not so secret");
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::safe());
+  parser.set_resolver(Box::new(resolver()));
+  let expected = error! {"
+     --> test.adoc:1:10
+      |
+    1 | include::../../etc/passwd[]
+      |          ^^^^^^^^^^^^^^^^ Include blocked by safe mode: Path `/etc/passwd` is outside of base directory `/docs/guide`
+  "};
+  expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected, from: input);
+
+  // unsafe mode resolves the normalized path
+  let mut parser = test_parser!("include::../etc/passwd[]");
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(resolver()));
+  assert!(parser.parse().is_ok());
+}
+
+#[test]
+fn include_from_virtual_source() {
+  let parser = Parser::builder(leaked_bump())
+    .source_str("include::/abs/a.adoc[]")
+    .file(SourceFile::Virtual { name: "x.adoc".to_string() })
+    .settings(JobSettings {
+      strict: false,
+      ..JobSettings::r#unsafe()
+    })
+    .resolver(Box::new(
+      MemoryResolver::new().with_file("/abs/a.adoc", "foo"),
+    ))
+    .build();
+  let result = parser.parse().unwrap();
+  assert!(result.warnings.is_empty());
+  assert_eq!(result.document.sources[1], "foo\n");
+}
+
+#[test]
+fn invalid_xref_in_include_reported_at_include() {
+  let mut parser = test_parser!("foo\n\ninclude::a.adoc[]");
+  parser.apply_job_settings(JobSettings {
+    strict: false,
+    ..JobSettings::r#unsafe()
+  });
+  parser.set_resolver(Box::new(
+    MemoryResolver::new().with_file("a.adoc", "bar\n\nsee <<nope>>"),
+  ));
+  let warnings = parser.parse().unwrap().warnings;
+  let expected = error! {"
+     --> a.adoc:3:7
+      |
+    3 | see <<nope>>
+      |       ^^^^ Invalid cross reference, no anchor found for `nope`
+  "};
+  expect_eq!(warnings[0].plain_text(), expected);
+}
+
+#[test]
+fn include_fingerprints() {
+  let resolver = MemoryResolver::with_base_dir("/docs")
+    .with_file("/docs/a.adoc", "include::b.adoc[lines=1]")
+    .with_file("/docs/b.adoc", "foo\nbar");
+  let mut parser = test_parser!("include::a.adoc[]\n\ninclude::c.adoc[opts=optional]");
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(resolver));
+  let includes = parser.parse().unwrap().includes;
+  // hashes are of the whole file, not the selected lines
+  assert_eq!(
+    includes,
+    vec![
+      IncludeFingerprint {
+        path: "/docs/a.adoc".to_string(),
+        hash: Some(content_hash(b"include::b.adoc[lines=1]")),
+      },
+      IncludeFingerprint {
+        path: "/docs/b.adoc".to_string(),
+        hash: Some(content_hash(b"foo\nbar")),
+      },
+      // missing files are fingerprinted too, to notice them being created
+      IncludeFingerprint {
+        path: "/docs/c.adoc".to_string(),
+        hash: None,
+      },
+    ]
+  );
+}
+
+#[test]
+fn image_outside_base_dir_in_safe_mode() {
+  let input = "image::../../etc/passwd[]";
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::safe());
+  parser.set_resolver(Box::new(MemoryResolver::with_base_dir("/docs/guide")));
+  let expected = error! {"
+     --> test.adoc:1:8
+      |
+    1 | image::../../etc/passwd[]
+      |        ^^^^^^^^^^^^^^^^ Image target blocked by safe mode: Path `/etc/passwd` is outside of base directory `/docs/guide`
+  "};
+  expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected, from: input);
+
+  let input = ":imagesdir: ../..\n\nSee image:etc/passwd[] here.";
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::safe());
+  parser.set_resolver(Box::new(MemoryResolver::with_base_dir("/docs/guide")));
+  let err = parser.parse().err().unwrap()[0].message.clone();
+  expect_eq!(
+    err,
+    "Image target blocked by safe mode: Path `/etc/passwd` is outside of base directory `/docs/guide`"
+  );
+
+  // targets within the base dir, and any target in unsafe mode, are fine
+  for (input, settings) in [
+    ("image::img/../a.png[]", JobSettings::safe()),
+    ("image::../../etc/passwd[]", JobSettings::r#unsafe()),
+  ] {
+    let mut parser = test_parser!(input);
+    parser.apply_job_settings(settings);
+    parser.set_resolver(Box::new(MemoryResolver::with_base_dir("/docs/guide")));
+    assert!(parser.parse().is_ok(), "{input}");
+  }
+}
+
 #[test]
 fn uri_read_not_allowed_include() {
   // strict mode error
@@ -508,6 +649,97 @@ fn include_resolution_para_newlines_edge_case() {
   expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected, from: input);
 }
 
+#[test]
+fn include_leveloffset_scope_restored() {
+  let input = adoc! {"
+    == Sect 1
+
+    include::a.adoc[leveloffset=+1]
+
+    == Sect 4
+  "};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(NestedResolver(vec![
+    "= Sect 2\n\n:leveloffset: +1\n\n= Sect 3\n",
+  ])));
+  let document = parser.parse().unwrap().document;
+  expect_eq!(
+    section_levels(&document),
+    vec![("_sect_1", 1), ("_sect_2", 1), ("_sect_3", 2), ("_sect_4", 1)],
+    from: input
+  );
+}
+
+#[test]
+fn nested_include_leveloffsets_accumulate() {
+  let input = adoc! {"
+    == Sect 1
+
+    :leveloffset: +1
+
+    include::a.adoc[leveloffset=+1]
+
+    :leveloffset!:
+
+    == Sect 4
+  "};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(NestedResolver(vec![
+    "= Sect 2\n\ninclude::b.adoc[leveloffset=+1]\n",
+    "= Sect 3\n",
+  ])));
+  let document = parser.parse().unwrap().document;
+  expect_eq!(
+    section_levels(&document),
+    vec![("_sect_1", 1), ("_sect_2", 2), ("_sect_3", 3), ("_sect_4", 1)],
+    from: input
+  );
+}
+
+#[test]
+fn include_leveloffset_reset_within_include() {
+  let input = adoc! {"
+    :leveloffset: +1
+
+    = Sect 1
+
+    include::a.adoc[leveloffset=+1]
+
+    = Sect 4
+  "};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(NestedResolver(vec![
+    "= Sect 2\n\n:leveloffset!:\n\n== Sect 3\n",
+  ])));
+  let document = parser.parse().unwrap().document;
+  expect_eq!(
+    section_levels(&document),
+    vec![("_sect_1", 1), ("_sect_2", 2), ("_sect_3", 1), ("_sect_4", 1)],
+    from: input
+  );
+}
+
+fn section_levels<'a>(document: &'a Document) -> Vec<(&'a str, u8)> {
+  fn collect<'a>(section: &'a Section, levels: &mut Vec<(&'a str, u8)>) {
+    levels.push((section.id.as_ref().unwrap().as_str(), section.level));
+    for block in section.blocks.iter() {
+      if let BlockContent::Section(nested) = &block.content {
+        collect(nested, levels);
+      }
+    }
+  }
+  let mut levels = vec![];
+  if let DocContent::Sectioned { sections, .. } = &document.content {
+    sections
+      .iter()
+      .for_each(|section| collect(section, &mut levels));
+  }
+  levels
+}
+
 // test resolvers
 
 struct AssertResolver {
@@ -590,3 +822,45 @@ impl IncludeResolver for NestedResolver {
     Some(String::new())
   }
 }
+
+// reports a length beyond u32 offsets, without allocating it
+struct OversizedResolver;
+
+impl IncludeResolver for OversizedResolver {
+  fn resolve(
+    &mut self,
+    _: IncludeTarget,
+    _: &mut dyn IncludeBuffer,
+  ) -> std::result::Result<usize, ResolveError> {
+    Ok(u32::MAX as usize + 1)
+  }
+  fn get_base_dir(&self) -> Option<String> {
+    Some(String::new())
+  }
+}
+
+#[test]
+fn source_of_included_nodes() {
+  let input = adoc! {"
+    Line-1 _here_
+    include::some_file.adoc[]
+  "};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(const_resolver!(b"Included *bold*"));
+  let document = parser.parse().unwrap().document;
+  let DocContent::Blocks(blocks) = &document.content else {
+    panic!("expected blocks");
+  };
+  let BlockContent::Simple(nodes) = &blocks[0].content else {
+    panic!("expected simple content");
+  };
+  let italic = nodes
+    .iter()
+    .find(|n| matches!(n.content, Inline::Italic(_)));
+  assert_eq!(document.source_of(italic.unwrap().loc), "_here_");
+  let bold = nodes.iter().find(|n| matches!(n.content, Inline::Bold(_)));
+  assert_eq!(bold.unwrap().loc.include_depth, 1);
+  assert_eq!(document.source_of(bold.unwrap().loc), "*bold*");
+  assert_eq!(document.source_of(SourceLocation::new_depth(0, 4, 9)), "");
+}
@@ -1,5 +1,6 @@
 use asciidork_ast::variants::{inline::*, r#macro::*};
 use asciidork_ast::{prelude::*, AdjacentNewline, InlineNodes};
+use asciidork_core::{JobAttr, JobSettings};
 use asciidork_parser::prelude::*;
 use test_utils::*;
 
@@ -96,7 +97,7 @@ fn test_emdashes() {
         node!("foo"; 0..3),
         node!(
           Symbol(SymbolKind::SpacedEmDash(AdjacentNewline::None)),
-          3..7
+          3..6
         ),
       ],
     ),
@@ -127,7 +128,7 @@ fn test_emdashes() {
         node!("foo"; 0..3),
         node!(
           Symbol(SymbolKind::SpacedEmDash(AdjacentNewline::Trailing)),
-          3..8
+          3..7
         ),
         node!("bar"; 7..10),
       ],
@@ -149,7 +150,7 @@ fn test_emdashes() {
 
 #[test]
 fn test_button_menu_macro() {
-  run(vec![
+  run_experimental(vec![
     (
       "press the btn:[OK] button",
       nodes![
@@ -187,6 +188,102 @@ fn test_button_menu_macro() {
   ]);
 }
 
+#[test]
+fn test_stem_macros() {
+  run(vec![
+    (
+      "stem:[x^2]",
+      nodes![node!(
+        Macro(Stem {
+          notation: StemNotation::Asciimath,
+          text: src!("x^2", 6..9),
+        }),
+        0..10,
+      )],
+    ),
+    (
+      "a latexmath:[\\frac{+a+}{[b\\]}] b",
+      nodes![
+        node!("a "; 0..2),
+        node!(
+          Macro(Stem {
+            notation: StemNotation::Latexmath,
+            text: src!("\\frac{+a+}{[b]}", 13..29),
+          }),
+          2..30,
+        ),
+        node!(" b"; 30..32),
+      ],
+    ),
+  ]);
+}
+
+#[test]
+fn test_unknown_named_entity() {
+  let input = "a &copy; &bogus; &#169;";
+  let result = test_parser!(input).parse().unwrap();
+  let expected_warning = error! {"
+     --> test.adoc:1:10
+      |
+    1 | a &copy; &bogus; &#169;
+      |          ^^^^^^^ Unknown named entity `&bogus;`
+  "};
+  expect_eq!(result.warnings.len(), 1);
+  expect_eq!(result.warnings[0].plain_text(), expected_warning, from: input);
+
+  run(vec![(
+    input,
+    nodes![
+      node!("a &copy; "; 0..9),
+      node!(SpecialChar(SpecialCharKind::Ampersand), 9..10),
+      node!("bogus; &#169;"; 10..23),
+    ],
+  )]);
+}
+
+#[test]
+fn test_legacy_footnoteref() {
+  let input = "foo footnoteref:[note,bar] baz";
+  let result = test_parser!(input).parse().unwrap();
+  let expected_warning = error! {"
+     --> test.adoc:1:5
+      |
+    1 | foo footnoteref:[note,bar] baz
+      |     ^^^^^^^^^^^^^^^^^^^^^^ Deprecated `footnoteref` macro, use `footnote:id[text]` instead
+  "};
+  expect_eq!(result.warnings.len(), 1);
+  expect_eq!(result.warnings[0].plain_text(), expected_warning, from: input);
+  expect_eq!(
+    parse_inlines!("footnoteref:[note]"),
+    nodes![node!(
+      Macro(Footnote {
+        id: Some(src!("note", 13..17)),
+        text: None,
+      }),
+      0..18,
+    )],
+  );
+}
+
+#[test]
+fn test_ui_macros_not_experimental() {
+  let input = "press the btn:[OK] button";
+  let result = test_parser!(input).parse().unwrap();
+  let expected_warning = error! {"
+     --> test.adoc:1:11
+      |
+    1 | press the btn:[OK] button
+      |           ^^^^^^^^ Experimental macros disabled, `btn:[]` rendered as text (set `:experimental:` to enable)
+  "};
+  expect_eq!(result.warnings.len(), 1);
+  expect_eq!(result.warnings[0].plain_text(), expected_warning, from: input);
+  expect_eq!(
+    parse_inlines!(input),
+    nodes![node!("press the btn:[OK] button"; 0..25)],
+    from: input
+  );
+}
+
 #[test]
 fn test_inline_passthrus() {
   run(vec![
@@ -464,6 +561,13 @@ fn test_inline_anchors() {
       "bar[[foo]]",
       nodes![node!("bar"; 0..3), node!(InlineAnchor(bstr!("foo")), 3..10)],
     ),
+    (
+      "*bar*[[foo]]",
+      nodes![
+        node!(Bold(just!("bar", 1..4)), 0..5),
+        node!(InlineAnchor(bstr!("foo")), 5..12),
+      ],
+    ),
   ]);
 }
 
@@ -633,6 +737,20 @@ fn test_constrained_pairs() {
       "_bar_?",
       nodes![node!(Italic(just!("bar", 1..4)), 0..5), node!("?"; 5..6)],
     ),
+    // non-ascii punctuation
+    (
+      "*bar*。",
+      nodes![node!(Bold(just!("bar", 1..4)), 0..5), node!("。"; 5..8)],
+    ),
+    (
+      "«_bar_»",
+      nodes![
+        node!("«"; 0..2),
+        node!(Italic(just!("bar", 3..6)), 2..7),
+        node!("»"; 7..9),
+      ],
+    ),
+    ("_bar_ü", just!("_bar_ü", 0..7)),
   ]);
 }
 
@@ -843,6 +961,10 @@ fn test_parse_inlines() {
       "`+_foo_+`",
       nodes![node!(LitMono(src!("_foo_", 2..7)), 0..9)],
     ),
+    (
+      "`+a <b>\nc+`",
+      nodes![node!(LitMono(src!("a <b>\nc", 2..9)), 0..11)],
+    ),
     (
       "foo <bar> & lol",
       nodes![
@@ -896,3 +1018,13 @@ fn run(cases: Vec<(&str, InlineNodes)>) {
     expect_eq!(parse_inlines!(input), expected, from: input);
   }
 }
+
+fn run_experimental(cases: Vec<(&str, InlineNodes)>) {
+  for (input, expected) in cases {
+    let mut settings = JobSettings::default();
+    settings
+      .job_attrs
+      .insert_unchecked("experimental", JobAttr::readonly(true));
+    expect_eq!(parse_inlines!(input, settings: Some(settings)), expected, from: input);
+  }
+}
@@ -0,0 +1,82 @@
+use asciidork_ast::prelude::*;
+use asciidork_parser::prelude::*;
+use proptest::prelude::*;
+use test_utils::strategies::quasi_asciidoc;
+use test_utils::*;
+
+proptest! {
+  #[test]
+  fn parsing_never_panics(input in quasi_asciidoc()) {
+    let parser = test_parser!(&input);
+    _ = parser.parse();
+  }
+
+  #[test]
+  fn block_starts_in_order_and_in_bounds(input in quasi_asciidoc()) {
+    let parser = test_parser!(&input);
+    let Ok(result) = parser.parse() else {
+      return Ok(());
+    };
+    let mut prev_start = 0;
+    let mut check_start = |start: u32| {
+      prop_assert!(start >= prev_start, "block at {start} precedes {prev_start}");
+      prop_assert!(start as usize <= input.len(), "block start {start} out of bounds");
+      prev_start = start;
+      Ok(())
+    };
+    match &result.document.content {
+      DocContent::Blocks(blocks) => {
+        for block in blocks.iter() {
+          check_start(block.meta.start)?;
+        }
+      }
+      DocContent::Sectioned { preamble, sections } => {
+        for block in preamble.iter().flat_map(|blocks| blocks.iter()) {
+          check_start(block.meta.start)?;
+        }
+        for section in sections.iter() {
+          check_start(section.meta.start)?;
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn inline_locs_in_order_and_in_bounds(input in quasi_asciidoc()) {
+    let parser = test_parser!(&input);
+    let Ok(result) = parser.parse() else {
+      return Ok(());
+    };
+    for locs in paragraph_inline_locs(&result.document.content) {
+      let mut prev_end = 0;
+      for loc in locs {
+        prop_assert!(loc.start <= loc.end, "inverted {:?}", loc);
+        prop_assert!(loc.end as usize <= input.len(), "{:?} out of bounds", loc);
+        prop_assert!(loc.start >= prev_end, "{:?} overlaps previous node", loc);
+        prev_end = loc.end;
+      }
+    }
+  }
+}
+
+/// locations of the inlines of top-level paragraphs, which are never
+/// produced by attribute substitution or includes
+fn paragraph_inline_locs(content: &DocContent) -> Vec<Vec<SourceLocation>> {
+  let blocks: Vec<&Block> = match content {
+    DocContent::Blocks(blocks) => blocks.iter().collect(),
+    DocContent::Sectioned { preamble, sections } => preamble
+      .iter()
+      .flat_map(|blocks| blocks.iter())
+      .chain(sections.iter().flat_map(|section| section.blocks.iter()))
+      .collect(),
+  };
+  blocks
+    .into_iter()
+    .filter_map(|block| match (block.context, &block.content) {
+      (BlockContext::Paragraph, BlockContent::Simple(inlines)) => {
+        Some(inlines.iter().map(|node| node.loc).collect())
+      }
+      _ => None,
+    })
+    .collect()
+}
@@ -52,12 +52,31 @@ test_inlines_loose!(
     Macro(Xref {
       target: src!("foo", 5..8),
       linktext: None,
-      kind: XrefKind::Macro
+      kind: XrefKind::Macro,
+      attrs: None,
     }),
     0..10
   )]
 );
 
+test_inlines_loose!(
+  xref_macro_w_attrs,
+  "xref:foo[bar,role=ref]",
+  nodes![node!(
+    Macro(Xref {
+      target: src!("foo", 5..8),
+      linktext: Some(just!("bar", 9..12)),
+      kind: XrefKind::Macro,
+      attrs: Some(AttrList {
+        positional: vecb![None],
+        roles: vecb![src!("ref", 18..21)],
+        ..attr_list!(8..22)
+      }),
+    }),
+    0..22
+  )]
+);
+
 test_inlines_loose!(
   xref_macro_target_w_colon,
   "xref::/c[] foo xref::/d[]",
@@ -66,7 +85,8 @@ test_inlines_loose!(
       Macro(Xref {
         target: src!(":/c", 5..8),
         linktext: None,
-        kind: XrefKind::Macro
+        kind: XrefKind::Macro,
+        attrs: None,
       }),
       0..10
     ),
@@ -75,7 +95,8 @@ test_inlines_loose!(
       Macro(Xref {
         target: src!(":/d", 20..23),
         linktext: None,
-        kind: XrefKind::Macro
+        kind: XrefKind::Macro,
+        attrs: None,
       }),
       15..25
     )
@@ -143,7 +164,8 @@ test_inlines_loose!(
         node!("bar "; 9..13),
         node!(Inline::Italic(just!("baz", 14..17)), 13..18)
       ]),
-      kind: XrefKind::Macro
+      kind: XrefKind::Macro,
+      attrs: None,
     }),
     0..19
   )]
@@ -156,7 +178,8 @@ test_inlines_loose!(
     Macro(Xref {
       target: src!("f-o", 5..8),
       linktext: Some(just!(" ", 9..10)),
-      kind: XrefKind::Macro
+      kind: XrefKind::Macro,
+      attrs: None,
     }),
     0..11
   )]
@@ -171,7 +194,8 @@ test_inlines_loose!(
       Macro(Xref {
         target: src!("bar", 9..12),
         linktext: None,
-        kind: XrefKind::Macro
+        kind: XrefKind::Macro,
+        attrs: None,
       }),
       4..14
     ),
@@ -186,7 +210,8 @@ test_inlines_loose!(
     Macro(Xref {
       target: src!("foo", 2..5),
       linktext: None,
-      kind: XrefKind::Shorthand
+      kind: XrefKind::Shorthand,
+      attrs: None,
     }),
     0..7
   )]
@@ -199,7 +224,8 @@ test_inlines_loose!(
     Macro(Xref {
       target: src!("#foo", 2..6),
       linktext: None,
-      kind: XrefKind::Shorthand
+      kind: XrefKind::Shorthand,
+      attrs: None,
     }),
     0..8
   )]
@@ -214,7 +240,8 @@ test_inlines_loose!(
       Macro(Xref {
         target: src!("foo", 3..6),
         linktext: None,
-        kind: XrefKind::Shorthand
+        kind: XrefKind::Shorthand,
+        attrs: None,
       }),
       1..8
     )
@@ -234,7 +261,8 @@ test_inlines_loose!(
           node!(Inline::Italic(just!("cool", 19..23)), 18..24),
           node!(" wow"; 24..28)
         ]),
-        kind: XrefKind::Shorthand
+        kind: XrefKind::Shorthand,
+        attrs: None,
       }),
       4..30
     ),
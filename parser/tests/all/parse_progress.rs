@@ -0,0 +1,57 @@
+use std::sync::{Arc, Mutex};
+
+use asciidork_core::{JobSettings, ProgressEvent, ProgressHandler};
+use asciidork_parser::includes::*;
+use asciidork_parser::prelude::*;
+use test_utils::*;
+
+#[test]
+fn progress_events() {
+  let input = adoc! {"
+    foo
+
+    include::bar.adoc[]
+
+    == Section
+
+    baz
+  "};
+  let events = Arc::new(Mutex::new(Vec::new()));
+  let collected = Arc::clone(&events);
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings {
+    on_progress: Some(ProgressHandler::new(move |event| {
+      collected.lock().unwrap().push(event.clone());
+    })),
+    ..JobSettings::r#unsafe()
+  });
+  parser.set_resolver(Box::new(
+    MemoryResolver::new().with_file("bar.adoc", "bar\n"),
+  ));
+  parser.parse().unwrap();
+  expect_eq!(
+    *events.lock().unwrap(),
+    vec![
+      ProgressEvent::BlockParsed {
+        bytes_consumed: 4,
+        total_bytes: 42,
+        blocks_parsed: 1,
+      },
+      ProgressEvent::EnterInclude {
+        file: "/bar.adoc".to_string(),
+        bytes: 4
+      },
+      ProgressEvent::BlockParsed {
+        bytes_consumed: 25,
+        total_bytes: 42,
+        blocks_parsed: 2,
+      },
+      ProgressEvent::BlockParsed {
+        bytes_consumed: 42,
+        total_bytes: 42,
+        blocks_parsed: 3,
+      },
+      ProgressEvent::Finished { total_bytes: 42, blocks_parsed: 3 },
+    ]
+  );
+}
@@ -0,0 +1,184 @@
+use std::path::PathBuf;
+
+use asciidork_ast::prelude::*;
+use asciidork_core::{DocType, JobSettings};
+use asciidork_parser::includes::*;
+use asciidork_parser::prelude::*;
+use test_utils::*;
+
+#[test]
+fn full_fidelity_records_trivia() {
+  let input = adoc! {"
+    = Doc
+
+    [[ex-1]]
+    .The  Title
+    [example,  role=x]
+    ====
+    foo
+
+
+    bar
+    ====
+  "};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings {
+    full_fidelity: true,
+    ..JobSettings::default()
+  });
+  let document = parser.parse().unwrap().document;
+  let trivia = document.trivia.unwrap();
+  for t in trivia.iter() {
+    // raw trivia always matches the source it was recorded from
+    assert_eq!(&input[t.loc.start as usize..t.loc.end as usize], t.raw);
+  }
+  assert_eq!(
+    trivia
+      .iter()
+      .map(|t| (t.kind, t.raw.as_str()))
+      .collect::<Vec<_>>(),
+    vec![
+      (TriviaKind::Tokens, "= Doc"),
+      (TriviaKind::Newline, "\n"),
+      (TriviaKind::BlankLines, "\n"),
+      (TriviaKind::BlockAnchor, "[[ex-1]]"),
+      (TriviaKind::Newline, "\n"),
+      (TriviaKind::BlockTitle, ".The  Title"),
+      (TriviaKind::Newline, "\n"),
+      (TriviaKind::AttrList, "[example,  role=x]"),
+      (TriviaKind::Newline, "\n"),
+      (TriviaKind::Delimiter, "===="),
+      (TriviaKind::Newline, "\n"),
+      (TriviaKind::Tokens, "foo"),
+      (TriviaKind::Newline, "\n"),
+      (TriviaKind::BlankLines, "\n\n"),
+      (TriviaKind::Tokens, "bar"),
+      (TriviaKind::Newline, "\n"),
+      (TriviaKind::Delimiter, "===="),
+      (TriviaKind::Newline, "\n"),
+    ]
+  );
+}
+
+#[test]
+fn trivia_keeps_raw_attr_refs_and_line_endings() {
+  let input = "= Doc\r\n:role: x\r\n\r\n.Title {role}\r\n[{role}]\r\ninclude::a.adoc[]\r\n\r\n\r\n";
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings {
+    full_fidelity: true,
+    ..JobSettings::r#unsafe()
+  });
+  parser.set_resolver(Box::new(
+    MemoryResolver::new().with_file("a.adoc", "foo {role}\n\nbar"),
+  ));
+  let document = parser.parse().unwrap().document;
+  assert_round_trips(&document);
+  let trivia = document.trivia.unwrap();
+  let classified = trivia
+    .iter()
+    .filter(|t| !matches!(t.kind, TriviaKind::Tokens | TriviaKind::Newline))
+    .map(|t| (t.kind, t.raw.as_str(), t.loc.include_depth))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    classified,
+    vec![
+      (TriviaKind::BlankLines, "\r\n", 0),
+      (TriviaKind::BlockTitle, ".Title {role}", 0),
+      (TriviaKind::AttrList, "[{role}]", 0),
+      (TriviaKind::BlankLines, "\r\n\r\n", 0),
+      (TriviaKind::BlankLines, "\n", 1),
+    ]
+  );
+}
+
+#[test]
+fn inline_doctype_records_unparsed_source() {
+  let mut parser = test_parser!("foo\n\nbar\n");
+  parser.apply_job_settings(JobSettings {
+    full_fidelity: true,
+    doctype: Some(DocType::Inline),
+    ..JobSettings::default()
+  });
+  let document = parser.parse().unwrap().document;
+  assert_round_trips(&document);
+  let trivia = document.trivia.unwrap();
+  let last = trivia.last().unwrap();
+  assert_eq!(
+    (last.kind, last.raw.as_str()),
+    (TriviaKind::Unparsed, "\nbar\n")
+  );
+}
+
+#[test]
+fn fixture_corpus_round_trips() {
+  let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+  let mut files = vec![root.join("kitchen-sink.adoc")];
+  collect_adoc_files(&root.join("cli/tests/all/fixtures"), &mut files);
+  assert!(files.len() > 1);
+
+  let files = files
+    .iter()
+    .map(|file| std::fs::canonicalize(file).unwrap())
+    .map(|path| (std::fs::read_to_string(&path).unwrap(), path))
+    .collect::<Vec<_>>();
+  for (src, path) in &files {
+    // relative includes in a primary doc resolve against the base dir
+    let mut resolver = MemoryResolver::with_base_dir(path.parent().unwrap().to_str().unwrap());
+    for (src, path) in &files {
+      resolver.insert(path.to_str().unwrap(), src.as_str());
+    }
+    let parser = Parser::builder(leaked_bump())
+      .source_str(src)
+      .file(SourceFile::Path(path.clone().into()))
+      .settings(JobSettings {
+        full_fidelity: true,
+        strict: false,
+        ..JobSettings::r#unsafe()
+      })
+      .resolver(Box::new(resolver))
+      .build();
+    let document = parser.parse().unwrap().document;
+    assert_eq!(document.sources[0], src, "{}", path.display());
+    if src.contains("include::b.adoc[]") {
+      assert!(document.sources.len() > 1, "{}", path.display());
+    }
+    assert_round_trips(&document);
+  }
+}
+
+#[test]
+fn trivia_not_recorded_by_default() {
+  let parser = test_parser!("[[foo]]\nbar\n\nbaz");
+  assert!(parser.parse().unwrap().document.trivia.is_none());
+}
+
+/// every source is exactly the concatenation of its trivia
+fn assert_round_trips(document: &Document) {
+  let trivia = document.trivia.as_ref().unwrap();
+  for (depth, source) in document.sources.iter().enumerate() {
+    let mut reassembled = String::with_capacity(source.len());
+    for t in trivia
+      .iter()
+      .filter(|t| t.loc.include_depth as usize == depth)
+    {
+      assert_eq!(
+        t.loc.start as usize,
+        reassembled.len(),
+        "gap or overlap before {t:?}"
+      );
+      reassembled.push_str(&t.raw);
+    }
+    assert_eq!(&reassembled, source);
+  }
+}
+
+fn collect_adoc_files(dir: &std::path::Path, files: &mut Vec<PathBuf>) {
+  for entry in std::fs::read_dir(dir).unwrap() {
+    let path = entry.unwrap().path();
+    if path.is_dir() {
+      collect_adoc_files(&path, files);
+    } else if path.extension().is_some_and(|ext| ext == "adoc") {
+      files.push(path);
+    }
+  }
+}
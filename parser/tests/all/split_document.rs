@@ -0,0 +1,88 @@
+use asciidork_ast::prelude::*;
+use asciidork_core::ReadAttr;
+use asciidork_parser::prelude::*;
+use test_utils::*;
+
+#[test]
+fn test_split_at_level() {
+  let input = adoc! {"
+    = Title
+    :foo: bar
+
+    preamble
+
+    == One
+
+    one
+
+    === One A
+
+    one a [[para-a]]
+
+    ==== One A i
+
+    deep
+
+    == Two
+
+    two [[inline-two]]
+  "};
+  let parser = test_parser!(input);
+  let document = parser.parse().unwrap().document;
+  let chunks = document.split_at_level(2);
+
+  let summary = chunks
+    .iter()
+    .map(|chunk| {
+      (
+        chunk.title.is_some(),
+        chunk.meta.str("foo").map(str::to_string),
+      )
+    })
+    .collect::<Vec<_>>();
+  let foo = || Some("bar".to_string());
+  assert_eq!(
+    summary,
+    vec![
+      (true, foo()),
+      (false, foo()),
+      (false, foo()),
+      (false, foo())
+    ]
+  );
+
+  // every chunk keeps the full anchor table, indexed by defining chunk
+  assert!(chunks.iter().all(|chunk| chunk.anchors.borrow().len() == 6));
+  let mut anchor_chunks = Document::anchor_chunks(&chunks)
+    .into_iter()
+    .collect::<Vec<_>>();
+  anchor_chunks.sort();
+  assert_eq!(
+    anchor_chunks,
+    vec![
+      ("_one".to_string(), 1),
+      ("_one_a".to_string(), 2),
+      ("_one_a_i".to_string(), 2),
+      ("_two".to_string(), 3),
+      ("inline-two".to_string(), 3),
+      ("para-a".to_string(), 2),
+    ]
+  );
+
+  // the parent chunk keeps its own content, but not its subsections
+  let DocContent::Sectioned { sections, .. } = &chunks[1].content else {
+    panic!("expected sectioned content");
+  };
+  assert_eq!(sections.len(), 1);
+  assert_eq!(sections[0].blocks.len(), 1);
+  assert_eq!(sections[0].blocks[0].context, BlockContext::Paragraph);
+}
+
+#[test]
+fn test_split_unsectioned() {
+  let parser = test_parser!("just a paragraph");
+  let document = parser.parse().unwrap().document;
+  let chunks = document.split_at_level(1);
+  assert_eq!(chunks.len(), 1);
+  assert_eq!(chunks[0].content, document.content);
+}
@@ -0,0 +1,27 @@
+use std::error::Error;
+
+extern crate asciidork_ast as ast;
+extern crate asciidork_backend as backend;
+extern crate asciidork_eval as eval;
+
+mod stats;
+
+pub use backend::Backend;
+pub use stats::*;
+
+/// Included in conversion cache keys, so upgrading invalidates old output
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub fn convert(document: ast::Document) -> Result<String, Box<dyn Error>> {
+  Ok(eval::eval(&document, DocumentStats::default())?)
+}
+
+mod internal {
+  pub use std::convert::Infallible;
+
+  pub use asciidork_core::*;
+  pub use ast::prelude::*;
+  pub use backend::prelude::*;
+  pub use backend::utils;
+  pub use serde::Serialize;
+}
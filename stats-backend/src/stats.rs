@@ -0,0 +1,323 @@
+use crate::internal::*;
+
+/// A backend that, instead of rendering, summarizes a document as JSON:
+/// its section tree, the xrefs between anchors, external links, images
+/// and footnotes, e.g. for a docs health dashboard.
+#[derive(Debug, Default, Serialize)]
+pub struct DocumentStats {
+  pub title: Option<String>,
+  pub sections: Vec<SectionStats>,
+  pub xrefs: Vec<XrefStats>,
+  pub links: Vec<LinkStats>,
+  pub images: Vec<ImageStats>,
+  pub footnotes: usize,
+  #[serde(skip)]
+  doc_meta: DocumentMeta,
+  /// sections entered but not yet exited, innermost last
+  #[serde(skip)]
+  open_sections: Vec<SectionStats>,
+  /// the innermost section of the parent, for nested content
+  #[serde(skip)]
+  parent_section_id: Option<String>,
+  #[serde(skip)]
+  json: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SectionStats {
+  pub id: Option<String>,
+  pub level: u8,
+  pub title: String,
+  pub sections: Vec<SectionStats>,
+}
+
+/// an edge of the internal link graph
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct XrefStats {
+  /// id of the section containing the xref, `None` outside any section
+  pub from: Option<String>,
+  pub target: String,
+  /// whether the target names an anchor in this document
+  pub resolved: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LinkStats {
+  pub from: Option<String>,
+  pub target: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImageStats {
+  pub from: Option<String>,
+  pub target: String,
+  pub block: bool,
+}
+
+impl DocumentStats {
+  fn current_section_id(&self) -> Option<String> {
+    match self.open_sections.last() {
+      Some(section) => section.id.clone(),
+      None => self.parent_section_id.clone(),
+    }
+  }
+
+  pub fn to_json(&self) -> String {
+    serde_json::to_string(self).expect("stats contain only strings, bools and numbers")
+  }
+}
+
+impl Backend for DocumentStats {
+  type Output = String;
+  type Error = Infallible;
+  const OUTFILESUFFIX: &'static str = ".json";
+
+  fn set_job_attrs(_attrs: &mut asciidork_core::JobAttrs) {}
+
+  fn enter_document(&mut self, document: &Document) {
+    self.doc_meta = document.meta.clone();
+    self.title = document
+      .title
+      .as_ref()
+      .map(|title| title.main.to_plain_text());
+  }
+
+  fn exit_document(&mut self, _document: &Document) {
+    self.json = self.to_json();
+  }
+
+  fn enter_section(&mut self, section: &Section) {
+    self.open_sections.push(SectionStats {
+      id: section.id.as_ref().map(|id| id.to_string()),
+      level: section.level,
+      title: section.heading.to_plain_text(),
+      sections: Vec::new(),
+    });
+  }
+
+  fn exit_section(&mut self, _section: &Section) {
+    let Some(section) = self.open_sections.pop() else {
+      return;
+    };
+    match self.open_sections.last_mut() {
+      Some(parent) => parent.sections.push(section),
+      None => self.sections.push(section),
+    }
+  }
+
+  fn nested_backend(&mut self, _context: NestedContext) -> Self {
+    Self {
+      parent_section_id: self.current_section_id(),
+      ..Self::default()
+    }
+  }
+
+  fn visit_nested_result(&mut self, nested: Self, context: NestedContext) {
+    // toc entries repeat section headings, counting them would double up
+    if context == NestedContext::TocEntry {
+      return;
+    }
+    self.xrefs.extend(nested.xrefs);
+    self.links.extend(nested.links);
+    self.images.extend(nested.images);
+    self.footnotes += nested.footnotes;
+  }
+
+  fn enter_image_block(&mut self, img_target: &str, _img_attrs: &AttrList, _block: &Block) {
+    self.images.push(ImageStats {
+      from: self.current_section_id(),
+      target: img_target.to_string(),
+      block: true,
+    });
+  }
+
+  fn visit_image_macro(&mut self, target: &str, _attrs: &AttrList) {
+    self.images.push(ImageStats {
+      from: self.current_section_id(),
+      target: target.to_string(),
+      block: false,
+    });
+  }
+
+  fn visit_keyboard_macro(&mut self, _keys: &[&str]) {}
+
+  fn visit_stem_macro(&mut self, _text: &str, _notation: StemNotation) {}
+
+  fn enter_link_macro(
+    &mut self,
+    target: &str,
+    _attrs: Option<&AttrList>,
+    scheme: Option<UrlScheme>,
+    resolving_xref: bool,
+    _has_link_text: bool,
+    _blank_window_shorthand: bool,
+  ) {
+    // links inside reftext are counted where the anchor is defined
+    if scheme.is_none() || resolving_xref {
+      return;
+    }
+    self.links.push(LinkStats {
+      from: self.current_section_id(),
+      target: target.to_string(),
+    });
+  }
+
+  fn exit_link_macro(
+    &mut self,
+    _target: &str,
+    _attrs: Option<&AttrList>,
+    _scheme: Option<UrlScheme>,
+    _resolving_xref: bool,
+    _has_link_text: bool,
+  ) {
+  }
+
+  fn enter_footnote(&mut self, _id: Option<&str>, content: Option<&[InlineNode]>) {
+    // a named footnote without text refers back to one already counted
+    if content.is_some() {
+      self.footnotes += 1;
+    }
+  }
+
+  fn enter_xref(
+    &mut self,
+    target: &str,
+    _reftext: Option<&[InlineNode]>,
+    kind: XrefKind,
+    _attrs: Option<&AttrList>,
+    anchor: Option<&Anchor>,
+  ) {
+    let target = if utils::xref::is_interdoc(target, kind) {
+      target.to_string()
+    } else {
+      utils::xref::resolve_id(target, &self.doc_meta).into_owned()
+    };
+    self.xrefs.push(XrefStats {
+      from: self.current_section_id(),
+      target,
+      resolved: anchor.is_some(),
+    });
+  }
+
+  fn enter_header(&mut self) {}
+  fn exit_header(&mut self) {}
+  fn enter_content(&mut self) {}
+  fn exit_content(&mut self) {}
+  fn enter_footer(&mut self) {}
+  fn exit_footer(&mut self) {}
+  fn visit_document_attribute_decl(&mut self, _name: &str, _value: &AttrValue) {}
+  fn enter_preamble(&mut self, _blocks: &[Block]) {}
+  fn exit_preamble(&mut self, _blocks: &[Block]) {}
+  fn enter_document_title(&mut self, _nodes: &[InlineNode]) {}
+  fn exit_document_title(&mut self, _nodes: &[InlineNode]) {}
+  fn enter_section_heading(&mut self, _section: &Section) {}
+  fn exit_section_heading(&mut self, _section: &Section) {}
+  fn enter_paragraph_block(&mut self, _block: &Block) {}
+  fn exit_paragraph_block(&mut self, _block: &Block) {}
+  fn enter_sidebar_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_sidebar_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_open_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_open_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_example_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_example_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_quote_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_quote_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_verse_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_verse_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_listing_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_listing_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_literal_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_literal_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_image_block(&mut self, _block: &Block) {}
+  fn enter_admonition_block(&mut self, _kind: AdmonitionKind, _block: &Block) {}
+  fn exit_admonition_block(&mut self, _kind: AdmonitionKind, _block: &Block) {}
+  fn enter_quoted_paragraph(&mut self, _block: &Block, _attr: &str, _cite: Option<&str>) {}
+  fn exit_quoted_paragraph(&mut self, _block: &Block, _attr: &str, _cite: Option<&str>) {}
+  fn enter_discrete_heading(&mut self, _level: u8, _id: Option<&str>, _block: &Block) {}
+  fn exit_discrete_heading(&mut self, _level: u8, _id: Option<&str>, _block: &Block) {}
+  fn enter_unordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {}
+  fn exit_unordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {}
+  fn enter_ordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {}
+  fn exit_ordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {}
+  fn enter_callout_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {}
+  fn exit_callout_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {}
+  fn enter_description_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {}
+  fn exit_description_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {}
+  fn enter_description_list_term(&mut self, _term: &[InlineNode], _item: &ListItem) {}
+  fn exit_description_list_term(&mut self, _term: &[InlineNode], _item: &ListItem) {}
+  fn enter_description_list_description(&mut self, _item: &ListItem) {}
+  fn exit_description_list_description(&mut self, _item: &ListItem) {}
+  fn enter_description_list_description_text(&mut self, _text: &Block, _item: &ListItem) {}
+  fn exit_description_list_description_text(&mut self, _text: &Block, _item: &ListItem) {}
+  fn enter_description_list_description_block(&mut self, _block: &Block, _item: &ListItem) {}
+  fn exit_description_list_description_block(&mut self, _block: &Block, _item: &ListItem) {}
+  fn enter_list_item_principal(&mut self, _item: &ListItem, _variant: ListVariant) {}
+  fn exit_list_item_principal(&mut self, _item: &ListItem, _variant: ListVariant) {}
+  fn enter_list_item_blocks(&mut self, _blocks: &[Block], _item: &ListItem, _variant: ListVariant) {
+  }
+  fn exit_list_item_blocks(&mut self, _blocks: &[Block], _item: &ListItem, _variant: ListVariant) {}
+  fn enter_table(&mut self, _table: &Table, _block: &Block) {}
+  fn exit_table(&mut self, _table: &Table, _block: &Block) {}
+  fn enter_table_section(&mut self, _section: TableSection) {}
+  fn exit_table_section(&mut self, _section: TableSection) {}
+  fn enter_table_row(&mut self, _row: &Row, _section: TableSection) {}
+  fn exit_table_row(&mut self, _row: &Row, _section: TableSection) {}
+  fn enter_table_cell(&mut self, _cell: &Cell, _section: TableSection) {}
+  fn exit_table_cell(&mut self, _cell: &Cell, _section: TableSection) {}
+  fn enter_cell_paragraph(&mut self, _cell: &Cell, _section: TableSection) {}
+  fn exit_cell_paragraph(&mut self, _cell: &Cell, _section: TableSection) {}
+  fn enter_block_title(&mut self, _title: &[InlineNode], _block: &Block) {}
+  fn exit_block_title(&mut self, _title: &[InlineNode], _block: &Block) {}
+  fn enter_simple_block_content(&mut self, _children: &[InlineNode], _block: &Block) {}
+  fn exit_simple_block_content(&mut self, _children: &[InlineNode], _block: &Block) {}
+  fn enter_compound_block_content(&mut self, _children: &[Block], _block: &Block) {}
+  fn exit_compound_block_content(&mut self, _children: &[Block], _block: &Block) {}
+  fn visit_thematic_break(&mut self, _block: &Block) {}
+  fn visit_page_break(&mut self, _block: &Block) {}
+  fn visit_inline_text(&mut self, _text: &str) {}
+  fn visit_inline_lit_mono(&mut self, _text: &str) {}
+  fn visit_joining_newline(&mut self) {}
+  fn visit_curly_quote(&mut self, _kind: CurlyKind) {}
+  fn visit_multichar_whitespace(&mut self, _whitespace: &str) {}
+  fn visit_button_macro(&mut self, _text: &str) {}
+  fn visit_menu_macro(&mut self, _items: &[&str]) {}
+  fn visit_icon_macro(&mut self, _target: &str, _attrs: &AttrList) {}
+  fn visit_callout(&mut self, _callout: Callout) {}
+  fn visit_callout_tuck(&mut self, _comment: &str) {}
+  fn enter_inline_italic(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_italic(&mut self, _children: &[InlineNode]) {}
+  fn enter_inline_mono(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_mono(&mut self, _children: &[InlineNode]) {}
+  fn enter_inline_bold(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_bold(&mut self, _children: &[InlineNode]) {}
+  fn visit_inline_specialchar(&mut self, _char: &SpecialCharKind) {}
+  fn enter_inline_passthrough(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_passthrough(&mut self, _children: &[InlineNode]) {}
+  fn enter_inline_highlight(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_highlight(&mut self, _children: &[InlineNode]) {}
+  fn enter_inline_subscript(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_subscript(&mut self, _children: &[InlineNode]) {}
+  fn enter_inline_superscript(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_superscript(&mut self, _children: &[InlineNode]) {}
+  fn enter_inline_quote(&mut self, _kind: QuoteKind, _children: &[InlineNode]) {}
+  fn exit_inline_quote(&mut self, _kind: QuoteKind, _children: &[InlineNode]) {}
+  fn exit_footnote(&mut self, _id: Option<&str>, _content: Option<&[InlineNode]>) {}
+  fn enter_text_span(&mut self, _attrs: &AttrList, _children: &[InlineNode]) {}
+  fn exit_text_span(&mut self, _attrs: &AttrList, _children: &[InlineNode]) {}
+  fn exit_xref(&mut self, _target: &str, _reftext: Option<&[InlineNode]>, _kind: XrefKind) {}
+  fn visit_missing_xref(&mut self, _target: &str, _kind: XrefKind, _doc_title: Option<&DocTitle>) {}
+  fn visit_inline_anchor(&mut self, _id: &str) {}
+  fn visit_biblio_anchor(&mut self, _id: &str, _reftext: Option<&str>) {}
+  fn visit_symbol(&mut self, _kind: SymbolKind) {}
+  fn visit_linebreak(&mut self) {}
+
+  fn into_result(self) -> Result<Self::Output, Self::Error> {
+    Ok(self.json)
+  }
+
+  fn result(&self) -> Result<&Self::Output, Self::Error> {
+    Ok(&self.json)
+  }
+}
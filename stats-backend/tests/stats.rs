@@ -0,0 +1,126 @@
+use asciidork_core::JobSettings;
+use asciidork_parser::prelude::*;
+use asciidork_stats_backend::*;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+fn stats(input: &str) -> (DocumentStats, String) {
+  let bump = &Bump::new();
+  let mut parser = Parser::from_str(input, SourceFile::Path(Path::new("test.adoc")), bump);
+  // keep going past unresolved xrefs, they show up in the link graph
+  parser.apply_job_settings(JobSettings {
+    strict: false,
+    ..JobSettings::default()
+  });
+  let document = parser.parse().unwrap().document;
+  let mut stats = DocumentStats::default();
+  asciidork_eval::visit(&document, &mut stats);
+  let json = stats.to_json();
+  (stats, json)
+}
+
+#[test]
+fn section_tree_and_link_graph() {
+  let (stats, _) = stats(indoc! {"
+    = Guide
+
+    See <<_install>>.footnote:[A note with https://example.com/note[a link].]
+
+    == Install
+
+    image::install.png[]
+
+    === From source
+
+    Go to https://example.com[example] or xref:usage.adoc#run[usage], not <<nope>>.
+
+    == Usage
+
+    Back to <<_install,installing>>, icon:tip[] image:inline.png[].
+    Again.footnote:[Another.]
+  "});
+  assert_eq!(stats.title.as_deref(), Some("Guide"));
+  assert_eq!(
+    stats.sections,
+    vec![
+      SectionStats {
+        id: Some("_install".into()),
+        level: 1,
+        title: "Install".into(),
+        sections: vec![SectionStats {
+          id: Some("_from_source".into()),
+          level: 2,
+          title: "From source".into(),
+          sections: vec![],
+        }],
+      },
+      SectionStats {
+        id: Some("_usage".into()),
+        level: 1,
+        title: "Usage".into(),
+        sections: vec![],
+      },
+    ]
+  );
+  let xrefs = stats
+    .xrefs
+    .iter()
+    .map(|x| (x.from.as_deref(), x.target.as_str(), x.resolved))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    xrefs,
+    vec![
+      (None, "_install", true),
+      (Some("_from_source"), "usage.adoc#run", false),
+      (Some("_from_source"), "nope", false),
+      (Some("_usage"), "_install", true),
+    ]
+  );
+  let links = stats
+    .links
+    .iter()
+    .map(|l| (l.from.as_deref(), l.target.as_str()))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    links,
+    vec![
+      (None, "https://example.com/note"),
+      (Some("_from_source"), "https://example.com"),
+    ]
+  );
+  let images = stats
+    .images
+    .iter()
+    .map(|i| (i.from.as_deref(), i.target.as_str(), i.block))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    images,
+    vec![
+      (Some("_install"), "install.png", true),
+      (Some("_usage"), "inline.png", false),
+    ]
+  );
+  assert_eq!(stats.footnotes, 2);
+}
+
+#[test]
+fn json_output() {
+  let (_, json) = stats(indoc! {r#"
+    == A "quoted" title
+
+    <<b>>
+
+    [[b]]
+    == B
+  "#});
+  assert_eq!(
+    json,
+    concat!(
+      r#"{"title":null,"sections":["#,
+      r#"{"id":"_a_quoted_title","level":1,"title":"A \"quoted\" title","sections":[]},"#,
+      r#"{"id":"b","level":1,"title":"B","sections":[]}],"#,
+      r#""xrefs":[{"from":"_a_quoted_title","target":"b","resolved":true}],"#,
+      r#""links":[],"images":[],"footnotes":0}"#,
+    )
+  );
+}
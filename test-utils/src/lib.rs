@@ -2,6 +2,10 @@ use bumpalo::Bump;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+mod normalize_html;
+pub mod strategies;
+pub use normalize_html::normalize_html;
+
 lazy_static! {
   pub static ref NEWLINES_RE: Regex = Regex::new(r"(?m)\n\s*").unwrap();
 }
@@ -217,6 +221,7 @@ macro_rules! empty_cell {
       row_span: 1,
       h_align: HorizontalAlignment::Left,
       v_align: VerticalAlignment::Top,
+      id: None,
     }
   };
 }
@@ -512,6 +517,25 @@ macro_rules! expect_eq {
   }};
 }
 
+/// Like `expect_eq!`, but ignores insignificant HTML formatting differences,
+/// see [`normalize_html`]
+#[macro_export]
+macro_rules! expect_html_eq {
+  ($left:expr, $right:expr$(,)?) => {{
+    ::pretty_assertions::assert_eq!(
+      test_utils::normalize_html(&$left),
+      test_utils::normalize_html(&$right)
+    );
+  }};
+  ($left:expr, $right:expr, from: $adoc:expr) => {{
+    ::test_utils::expect_eq!(
+      test_utils::normalize_html(&$left),
+      test_utils::normalize_html(&$right),
+      from: $adoc
+    );
+  }};
+}
+
 #[macro_export]
 macro_rules! assert_html_contains {
   ($html:expr, $needle:expr, from: $adoc:expr$(,)?) => {{
@@ -0,0 +1,271 @@
+/// Normalizes insignificant differences between two renderings of the same
+/// HTML, so output can be compared against Asciidoctor's golden files:
+///
+/// - whitespace-only text between tags is dropped next to a block-level
+///   tag, elsewhere it collapses to a single space
+/// - other runs of whitespace collapse to a single space (except in `<pre>`)
+/// - attributes are sorted by name, and always double-quoted
+/// - tag and attribute names are lowercased, and `<br/>` becomes `<br>`
+#[must_use]
+pub fn normalize_html(html: &str) -> String {
+  let mut out = String::with_capacity(html.len());
+  let mut rest = html;
+  let mut pre_depth = 0_usize;
+  let mut prev_block = true;
+  while !rest.is_empty() {
+    if rest.starts_with("<!--") {
+      let end = rest.find("-->").map_or(rest.len(), |idx| idx + 3);
+      out.push_str(&rest[..end]);
+      rest = &rest[end..];
+    } else if rest.starts_with('<') {
+      let end = tag_end(rest);
+      let tag = Tag::parse(&rest[..end]);
+      match (tag.name.as_str(), tag.closing) {
+        ("pre", false) => pre_depth += 1,
+        ("pre", true) => pre_depth = pre_depth.saturating_sub(1),
+        _ => {}
+      }
+      prev_block = tag.is_block();
+      tag.push_to(&mut out);
+      rest = &rest[end..];
+    } else {
+      let end = rest.find('<').unwrap_or(rest.len());
+      let text = &rest[..end];
+      if pre_depth > 0 {
+        out.push_str(text);
+      } else if !text.trim().is_empty() {
+        push_collapsed(&mut out, text);
+      } else if !prev_block && !next_is_block(&rest[end..]) {
+        out.push(' ');
+      }
+      rest = &rest[end..];
+    }
+  }
+  out
+}
+
+// same as the `BLOCK` list used by the dr-html backend's minifier
+const BLOCK: [&str; 48] = [
+  "address",
+  "article",
+  "aside",
+  "blockquote",
+  "body",
+  "br",
+  "caption",
+  "col",
+  "colgroup",
+  "dd",
+  "details",
+  "div",
+  "dl",
+  "dt",
+  "figcaption",
+  "figure",
+  "footer",
+  "h1",
+  "h2",
+  "h3",
+  "h4",
+  "h5",
+  "h6",
+  "head",
+  "header",
+  "hr",
+  "html",
+  "li",
+  "link",
+  "main",
+  "meta",
+  "nav",
+  "ol",
+  "p",
+  "pre",
+  "script",
+  "section",
+  "style",
+  "summary",
+  "table",
+  "tbody",
+  "td",
+  "tfoot",
+  "th",
+  "thead",
+  "title",
+  "tr",
+  "ul",
+];
+
+// whether the html starts with a block-level tag (or a comment, doctype, etc.)
+fn next_is_block(html: &str) -> bool {
+  html.is_empty() || Tag::parse(&html[..tag_end(html)]).is_block()
+}
+
+fn push_collapsed(out: &mut String, text: &str) {
+  if text.starts_with(char::is_whitespace) {
+    out.push(' ');
+  }
+  let mut words = text.split_whitespace();
+  if let Some(first) = words.next() {
+    out.push_str(first);
+  }
+  for word in words {
+    out.push(' ');
+    out.push_str(word);
+  }
+  if text.ends_with(char::is_whitespace) {
+    out.push(' ');
+  }
+}
+
+// index just past the `>` closing the tag, ignoring any inside quotes
+fn tag_end(html: &str) -> usize {
+  let mut quote = None;
+  for (idx, c) in html.char_indices() {
+    match (quote, c) {
+      (None, '"' | '\'') => quote = Some(c),
+      (Some(q), c) if q == c => quote = None,
+      (None, '>') => return idx + 1,
+      _ => {}
+    }
+  }
+  html.len()
+}
+
+#[derive(Debug)]
+struct Tag<'a> {
+  raw: &'a str,
+  name: String,
+  closing: bool,
+  attrs: Vec<(String, Option<String>)>,
+}
+
+impl<'a> Tag<'a> {
+  fn parse(raw: &'a str) -> Self {
+    let inner = raw
+      .trim_start_matches('<')
+      .trim_end_matches('>')
+      .trim_end_matches('/');
+    let closing = inner.starts_with('/');
+    let inner = inner.trim_start_matches('/');
+    let name_end = inner
+      .find(|c: char| c.is_whitespace())
+      .unwrap_or(inner.len());
+    let mut tag = Tag {
+      raw,
+      name: inner[..name_end].to_lowercase(),
+      closing,
+      attrs: Vec::new(),
+    };
+    let mut rest = inner[name_end..].trim_start();
+    while !rest.is_empty() {
+      let name_end = rest
+        .find(|c: char| c.is_whitespace() || c == '=')
+        .unwrap_or(rest.len());
+      let name = rest[..name_end].to_lowercase();
+      rest = rest[name_end..].trim_start();
+      let value = if let Some(after_eq) = rest.strip_prefix('=') {
+        let after_eq = after_eq.trim_start();
+        let (value, remaining) = match after_eq.chars().next() {
+          Some(q @ ('"' | '\'')) => {
+            let len = after_eq[1..].find(q).unwrap_or(after_eq.len() - 1);
+            (&after_eq[1..=len], after_eq.get(len + 2..).unwrap_or(""))
+          }
+          _ => {
+            let len = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+            (&after_eq[..len], &after_eq[len..])
+          }
+        };
+        rest = remaining.trim_start();
+        Some(value.replace('"', "&quot;"))
+      } else {
+        None
+      };
+      tag.attrs.push((name, value));
+    }
+    tag.attrs.sort_by(|a, b| a.0.cmp(&b.0));
+    tag
+  }
+
+  fn is_block(&self) -> bool {
+    self.name.is_empty() || self.name.starts_with(['!', '?']) || BLOCK.contains(&self.name.as_str())
+  }
+
+  fn push_to(&self, out: &mut String) {
+    // doctypes, processing instructions, etc.
+    if self.name.starts_with(['!', '?']) {
+      out.push_str(self.raw);
+      return;
+    }
+    out.push('<');
+    if self.closing {
+      out.push('/');
+    }
+    out.push_str(&self.name);
+    for (name, value) in &self.attrs {
+      out.push(' ');
+      out.push_str(name);
+      if let Some(value) = value {
+        out.push_str("=\"");
+        out.push_str(value);
+        out.push('"');
+      }
+    }
+    out.push('>');
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_normalize_html() {
+    let cases = [
+      (
+        "<div class=\"paragraph\">\n<p>foo\nbar</p>\n</div>",
+        "<div class=\"paragraph\"><p>foo bar</p></div>",
+      ),
+      (
+        "<a href='#x' class=\"link\"  id=y>x</a>",
+        "<a class=\"link\" href=\"#x\" id=\"y\">x</a>",
+      ),
+      ("<BR/>foo <b>bar</b>", "<br>foo <b>bar</b>"),
+      (
+        "<pre>  keep\n  this </pre>\n<input checked  type=\"checkbox\" />",
+        "<pre>  keep\n  this </pre><input checked type=\"checkbox\">",
+      ),
+      (
+        "<!DOCTYPE html>\n<!-- a  b -->",
+        "<!DOCTYPE html><!-- a  b -->",
+      ),
+      (
+        "<td title='say \"hi\"'>x</td>",
+        "<td title=\"say &quot;hi&quot;\">x</td>",
+      ),
+      ("<a title=\"a > b\">x</a>", "<a title=\"a > b\">x</a>"),
+      (
+        "<p><b>a</b>\n  <i>b</i>\n</p>\n<p>c</p>",
+        "<p><b>a</b> <i>b</i></p><p>c</p>",
+      ),
+    ];
+    for (input, expected) in cases {
+      assert_eq!(normalize_html(input), expected, "input: {input:?}");
+    }
+  }
+
+  #[test]
+  fn test_normalized_equality() {
+    let asciidoctor = indoc::indoc! {r#"
+      <div class="ulist">
+      <ul>
+      <li>
+      <p>foo</p>
+      </li>
+      </ul>
+      </div>
+    "#};
+    let asciidork = r#"<div class="ulist"><ul><li><p>foo</p></li></ul></div>"#;
+    assert_eq!(normalize_html(asciidoctor), normalize_html(asciidork));
+  }
+}
@@ -0,0 +1,130 @@
+use proptest::prelude::*;
+
+/// Lines that exercise most block-level syntax: headings, lists,
+/// delimiters, directives, attribute entries, and block metadata
+const LINES: &[&str] = &[
+  "= Document Title",
+  "== Section",
+  "=== Subsection",
+  "====== Too deep",
+  "Author Name <author@example.com>",
+  "v1.0, 2024-01-01: remark",
+  ":attr: value",
+  ":attr!:",
+  ":toc:",
+  ":sectnums:",
+  ":leveloffset: +1",
+  "* item",
+  "** nested item",
+  "- dash item",
+  ". ordered",
+  ".. nested ordered",
+  "* [x] checked",
+  "term:: description",
+  "term::",
+  "term;; other",
+  "<1> callout",
+  "+",
+  "--",
+  "----",
+  "....",
+  "====",
+  "____",
+  "******",
+  "++++",
+  "////",
+  "|===",
+  ",===",
+  "a|b|c",
+  "| cell",
+  "> quoted",
+  "'''",
+  "<<<",
+  "[source,rust]",
+  "[quote, Author, Cite]",
+  "[[anchor]]",
+  "[#id.role%option]",
+  "[cols=\"1,2\"]",
+  ".Block Title",
+  "// comment",
+  "NOTE: admonition",
+  "include::other.adoc[]",
+  "include::other.adoc[lines=1..2]",
+  "ifdef::attr[]",
+  "ifndef::attr[]",
+  "ifeval::[1 > 0]",
+  "endif::[]",
+  "image::a.png[Alt,100]",
+  "toc::[]",
+  "  indented literal",
+];
+
+/// Inline fragments, joined with spaces into paragraph text
+const INLINES: &[&str] = &[
+  "word",
+  "*bold*",
+  "_emphasis_",
+  "`mono`",
+  "#mark#",
+  "**un**constrained",
+  "^super^",
+  "~sub~",
+  "{attr}",
+  "{undefined}",
+  "<<anchor>>",
+  "<<anchor,text>>",
+  "xref:other.adoc#id[text]",
+  "link:https://example.com[text]",
+  "https://example.com[]",
+  "mailto:a@b.com[]",
+  "image:a.png[]",
+  "footnote:[note]",
+  "footnote:id[note]",
+  "kbd:[Ctrl+T]",
+  "btn:[OK]",
+  "menu:File[Save]",
+  "pass:[<b>]",
+  "+++raw+++",
+  "stem:[x^2]",
+  "\\*escaped*",
+  "&amp;",
+  "&#169;",
+  "(C)",
+  "...",
+  "--",
+  "->",
+  "'quoted'",
+  "\"`curved`\"",
+  "[[inline]]",
+  "[.role]#text#",
+  "<1>",
+  " +",
+  "é",
+  "日本語",
+  "👋",
+];
+
+fn inline_line() -> impl Strategy<Value = String> {
+  prop::collection::vec(prop::sample::select(INLINES), 1..8).prop_map(|parts| parts.join(" "))
+}
+
+fn line() -> impl Strategy<Value = String> {
+  prop_oneof![
+    2 => Just(String::new()),
+    4 => prop::sample::select(LINES).prop_map(String::from),
+    4 => inline_line(),
+    1 => "[ -~]{0,40}",
+    1 => "\\PC{0,20}",
+  ]
+}
+
+/// Generates documents that are mostly (but not always) valid AsciiDoc,
+/// by stitching together common block and inline syntax at random.
+pub fn quasi_asciidoc() -> impl Strategy<Value = String> {
+  prop::collection::vec(line(), 0..40).prop_map(|lines| lines.join("\n"))
+}
+
+/// Generates arbitrary input for the lexer: quasi-AsciiDoc, or any text
+pub fn lexer_input() -> impl Strategy<Value = String> {
+  prop_oneof![quasi_asciidoc(), "[ -~\n]{0,200}", "\\PC{0,100}",]
+}